@@ -0,0 +1,129 @@
+use criterion::measurement::WallTime;
+use criterion::{
+	black_box, criterion_group, criterion_main, BenchmarkGroup, Criterion, Throughput,
+};
+use nanorand::rand::RNG;
+
+/// Benchmark every core operation for one generator, each in its own group, so generators can be compared per operation.
+fn bench_rng<R: RNG>(c: &mut Criterion, name: &str, mut new: impl FnMut() -> R) {
+	fn group<'a>(
+		c: &'a mut Criterion,
+		operation: &str,
+		throughput: Throughput,
+	) -> BenchmarkGroup<'a, WallTime> {
+		let mut group = c.benchmark_group(operation);
+		group.throughput(throughput);
+		group
+	}
+
+	let mut rand = group(c, "rand", Throughput::Bytes(R::OUTPUT_SIZE as u64 * 1024));
+	rand.bench_function(name, |b| {
+		let mut rng = new();
+		b.iter(|| {
+			for _ in 0..1024 {
+				black_box(rng.rand());
+			}
+		})
+	});
+	rand.finish();
+
+	let mut generate = group(c, "generate u64", Throughput::Elements(1024));
+	generate.bench_function(name, |b| {
+		let mut rng = new();
+		b.iter(|| {
+			for _ in 0..1024 {
+				black_box(rng.generate::<u64>());
+			}
+		})
+	});
+	generate.finish();
+
+	let mut range = group(c, "random_range", Throughput::Elements(1024));
+	range.bench_function(name, |b| {
+		let mut rng = new();
+		b.iter(|| {
+			for _ in 0..1024 {
+				black_box(rng.generate_range(0..black_box(1_000_000_u64)));
+			}
+		})
+	});
+	range.finish();
+
+	let mut fill_bytes = group(c, "fill_bytes", Throughput::Bytes(64 * 1024));
+	fill_bytes.bench_function(name, |b| {
+		let mut rng = new();
+		let mut buffer = vec![0u8; 64 * 1024];
+		b.iter(|| {
+			rng.fill_bytes(&mut buffer);
+			black_box(&buffer);
+		})
+	});
+	fill_bytes.finish();
+
+	let mut shuffle = group(c, "shuffle", Throughput::Elements(1024));
+	shuffle.bench_function(name, |b| {
+		let mut rng = new();
+		let mut items: Vec<u32> = (0..1024).collect();
+		b.iter(|| {
+			rng.shuffle(&mut items);
+			black_box(&items);
+		})
+	});
+	shuffle.finish();
+}
+
+/// Compare the default [`RNG::fill_bytes`] over a large, unaligned buffer with filling it one byte at a time.
+#[cfg(feature = "wyrand")]
+fn bench_large_fill(c: &mut Criterion) {
+	const LEN: usize = 1024 * 1024 + 3;
+	let mut group = c.benchmark_group("fill_bytes 1 MiB");
+	group.throughput(Throughput::Bytes(LEN as u64));
+	group.bench_function("default", |b| {
+		let mut rng = nanorand::rand::WyRand::new_seed(42);
+		let mut buffer = vec![0u8; LEN];
+		b.iter(|| {
+			rng.fill_bytes(&mut buffer);
+			black_box(&buffer);
+		})
+	});
+	group.bench_function("naive", |b| {
+		let mut rng = nanorand::rand::WyRand::new_seed(42);
+		let mut buffer = vec![0u8; LEN];
+		b.iter(|| {
+			let mut output = rng.rand();
+			for (idx, byte) in buffer.iter_mut().enumerate() {
+				if idx % output.len() == 0 && idx != 0 {
+					output = rng.rand();
+				}
+				*byte = output[idx % output.len()];
+			}
+			black_box(&buffer);
+		})
+	});
+	group.finish();
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+	#[cfg(feature = "wyrand")]
+	bench_large_fill(c);
+	#[cfg(feature = "wyrand")]
+	bench_rng(c, "wyrand", || nanorand::rand::WyRand::new_seed(42));
+	#[cfg(feature = "pcg64")]
+	bench_rng(c, "pcg64", || nanorand::rand::Pcg64::new_seed(42));
+	#[cfg(feature = "xoroshiro")]
+	bench_rng(c, "xoroshiro128++", || {
+		nanorand::rand::Xoroshiro128::new_seed(42)
+	});
+	#[cfg(feature = "chacha")]
+	{
+		bench_rng(c, "chacha8", || {
+			nanorand::rand::ChaCha::new_key(8, [42; 32], [0; 16])
+		});
+		bench_rng(c, "chacha20", || {
+			nanorand::rand::ChaCha::new_key(20, [42; 32], [0; 16])
+		});
+	}
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -56,6 +56,20 @@ fn criterion_benchmark(c: &mut Criterion) {
 		});
 	}
 
+	#[cfg(feature = "xoroshiro")]
+	{
+		rng_group.bench_function("xoroshiro128++", |b| {
+			let mut rng = nanorand::rand::Xoroshiro128::new();
+			b.iter(|| {
+				let mut n: u64 = u64::MIN;
+				for _ in 0..1024 {
+					n = n.wrapping_add(rng.generate());
+				}
+				black_box(n);
+			})
+		});
+	}
+
 	#[cfg(feature = "chacha")]
 	{
 		rng_group.bench_function("chacha8", |b| {
@@ -83,6 +97,37 @@ fn criterion_benchmark(c: &mut Criterion) {
 
 	rng_group.finish();
 
+	let mut range_group = c.benchmark_group("ranges");
+	range_group.throughput(Throughput::Elements(1024));
+
+	#[cfg(feature = "wyrand")]
+	{
+		// Ranges within u32 avoid the 128-bit multiply, which is emulated on 32-bit targets.
+		range_group.bench_function("wyrand u64 small range", |b| {
+			let mut rng = nanorand::rand::WyRand::new();
+			b.iter(|| {
+				let mut n: u64 = u64::MIN;
+				for _ in 0..1024 {
+					n = n.wrapping_add(rng.generate_range(0..black_box(1_000_000_u64)));
+				}
+				black_box(n);
+			})
+		});
+
+		range_group.bench_function("wyrand u64 large range", |b| {
+			let mut rng = nanorand::rand::WyRand::new();
+			b.iter(|| {
+				let mut n: u64 = u64::MIN;
+				for _ in 0..1024 {
+					n = n.wrapping_add(rng.generate_range(0..black_box(1_000_000_000_000_u64)));
+				}
+				black_box(n);
+			})
+		});
+	}
+
+	range_group.finish();
+
 	let mut other_rngs = c.benchmark_group("other-rng-crates");
 	other_rngs.throughput(Throughput::Bytes(std::mem::size_of::<u64>() as u64 * 1024));
 
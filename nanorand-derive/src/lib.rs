@@ -0,0 +1,166 @@
+//! Derive macros for [nanorand](https://crates.io/crates/nanorand), and the `compile_seed!()` macro.
+//! These are re-exported by nanorand when its `derive` feature is enabled, so there's no need to depend on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Literal, TokenStream as TokenStream2};
+use quote::{quote, quote_spanned};
+use syn::{
+	parse_macro_input, parse_quote, spanned::Spanned, Data, DeriveInput, Fields, GenericParam,
+	LitInt,
+};
+
+/// Derive `RandomGen` for a struct or enum.
+///
+/// * Structs are generated by generating each of their fields, in order.
+/// * Enums are generated by picking one of their variants uniformly, then generating that variant's fields, if any.
+///   Enums without any variants can't be generated, and are rejected.
+///
+/// Every field's type must implement `RandomGen` for the RNG being used.
+/// Type parameters are required to implement `RandomGen` as well.
+/// ```rust,ignore
+/// use nanorand::{RandomGen, RNG, WyRand};
+///
+/// #[derive(RandomGen)]
+/// enum Direction {
+///     North,
+///     East,
+///     South,
+///     West,
+/// }
+///
+/// #[derive(RandomGen)]
+/// struct Move {
+///     direction: Direction,
+///     steps: u8,
+/// }
+///
+/// let mut rng = WyRand::new();
+/// let step: Move = rng.generate();
+/// ```
+#[proc_macro_derive(RandomGen)]
+pub fn derive_random_gen(input: TokenStream) -> TokenStream {
+	let mut input = parse_macro_input!(input as DeriveInput);
+	let rng = quote!(__NanorandRng);
+
+	let body = match &input.data {
+		Data::Struct(data) => generate_fields(quote!(Self), &data.fields, &rng),
+		Data::Enum(data) => {
+			if data.variants.is_empty() {
+				return syn::Error::new(
+					input.ident.span(),
+					"RandomGen can't be derived for an enum without variants",
+				)
+				.to_compile_error()
+				.into();
+			}
+			let count = data.variants.len();
+			let arms = data.variants.iter().enumerate().map(|(idx, variant)| {
+				let ident = &variant.ident;
+				let constructor = generate_fields(quote!(Self::#ident), &variant.fields, &rng);
+				quote!(#idx => #constructor,)
+			});
+			quote! {
+				match <usize as ::nanorand::RandomRange<#rng>>::random_range(r, 0, #count) {
+					#(#arms)*
+					_ => unreachable!(),
+				}
+			}
+		}
+		Data::Union(_) => {
+			return syn::Error::new(input.ident.span(), "RandomGen can't be derived for a union")
+				.to_compile_error()
+				.into();
+		}
+	};
+
+	for param in input.generics.params.iter_mut() {
+		if let GenericParam::Type(param) = param {
+			param.bounds.push(parse_quote!(::nanorand::RandomGen<#rng>));
+		}
+	}
+	let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+	let mut impl_generics = input.generics.clone();
+	impl_generics
+		.params
+		.push(parse_quote!(#rng: ::nanorand::RNG));
+	let (impl_generics, _, _) = impl_generics.split_for_impl();
+	let name = &input.ident;
+
+	quote! {
+		impl #impl_generics ::nanorand::RandomGen<#rng> for #name #ty_generics #where_clause {
+			fn random(r: &mut #rng) -> Self {
+				#body
+			}
+		}
+	}
+	.into()
+}
+
+/// Build `path`, generating each of the fields.
+fn generate_fields(path: TokenStream2, fields: &Fields, rng: &TokenStream2) -> TokenStream2 {
+	let generate = |ty: &syn::Type| quote_spanned!(ty.span()=> <#ty as ::nanorand::RandomGen<#rng>>::random(r));
+	match fields {
+		Fields::Named(fields) => {
+			let fields = fields.named.iter().map(|field| {
+				let ident = &field.ident;
+				let value = generate(&field.ty);
+				quote!(#ident: #value)
+			});
+			quote!(#path { #(#fields),* })
+		}
+		Fields::Unnamed(fields) => {
+			let fields = fields.unnamed.iter().map(|field| generate(&field.ty));
+			quote!(#path(#(#fields),*))
+		}
+		Fields::Unit => path,
+	}
+}
+
+/// Expand to a random seed, chosen when the invoking crate is compiled, from the build machine's entropy.
+///
+/// * `compile_seed!()` expands to a [`u64`] literal.
+/// * `compile_seed!(N)` expands to a `[u8; N]` array literal.
+///
+/// As these are literals, they work in const contexts, such as initializing a `static` generator with a `const fn` constructor.
+/// Every invocation gets its own value, even within a single compilation, and every time the crate is recompiled, it gets new ones.
+/// Within one build, the value is fixed, so the program behaves the same every time it runs.
+/// ```rust,ignore
+/// use nanorand::{compile_seed, WyRand};
+///
+/// static RNG: WyRand = WyRand::new_seed(compile_seed!());
+/// const KEY: [u8; 32] = compile_seed!(32);
+/// ```
+#[proc_macro]
+pub fn compile_seed(input: TokenStream) -> TokenStream {
+	if input.is_empty() {
+		let seed = Literal::u64_suffixed(build_entropy());
+		return quote!(#seed).into();
+	}
+	let len = parse_macro_input!(input as LitInt);
+	let len = match len.base10_parse::<usize>() {
+		Ok(len) => len,
+		Err(err) => return err.to_compile_error().into(),
+	};
+	let mut bytes = Vec::with_capacity(len);
+	while bytes.len() < len {
+		bytes.extend_from_slice(&build_entropy().to_le_bytes());
+	}
+	let bytes = bytes[..len].iter().map(|&byte| Literal::u8_suffixed(byte));
+	quote!([#(#bytes),*]).into()
+}
+
+/// Get a [`u64`] of entropy on the build machine, without depending on anything outside of `std`.  
+/// Each [`RandomState`](std::collections::hash_map::RandomState) is keyed from the OS's entropy,
+/// and differently every time, so hashing the time with a new one gives a different value for every call.
+fn build_entropy() -> u64 {
+	use std::{
+		collections::hash_map::RandomState,
+		hash::{BuildHasher, Hasher},
+		time::{SystemTime, UNIX_EPOCH},
+	};
+	let mut hasher = RandomState::new().build_hasher();
+	if let Ok(time) = SystemTime::now().duration_since(UNIX_EPOCH) {
+		hasher.write_u128(time.as_nanos());
+	}
+	hasher.finish()
+}
@@ -0,0 +1,144 @@
+use crate::{
+	rand::{infallible, RandError},
+	CryptoRNG, SeedableRNG, RNG,
+};
+
+/// Wraps an RNG, keeping the rest of each output around to serve later requests for fewer bytes.  
+/// Without this, generating a [`u8`] or [`bool`] from [`WyRand`](crate::WyRand) uses one byte of an 8 byte output,
+/// and throws the other 7 away.
+///
+/// [`RNG::fill_bytes`], and everything built on it, such as [`RNG::generate`] for integers, [`bool`], and [`char`],
+/// draws from the buffer first, and only pulls a new output from the wrapped RNG once it runs out.
+/// [`RNG::rand`] always returns a whole new output from the wrapped RNG, leaving the buffer alone.
+///
+/// This changes the sequence of values compared to using the wrapped RNG directly.
+/// Callers that need to line the two up again can use [`BufferedRng::flush`].
+/// ```rust
+/// use nanorand::{adapter::BufferedRng, RNG, WyRand};
+///
+/// let mut rng = BufferedRng::new(WyRand::new());
+/// // These all come from a single WyRand output.
+/// let bytes: [u8; 8] = [(); 8].map(|_| rng.generate());
+/// assert_eq!(rng.remaining(), 0);
+/// ```
+pub struct BufferedRng<R: RNG> {
+	rng: R,
+	buffer: Option<R::Output>,
+	position: usize,
+}
+
+impl<R: RNG> BufferedRng<R> {
+	/// Wrap an RNG, starting with an empty buffer.
+	pub fn new(rng: R) -> Self {
+		Self {
+			rng,
+			buffer: None,
+			position: 0,
+		}
+	}
+
+	/// The number of buffered bytes that haven't been used yet.
+	pub fn remaining(&self) -> usize {
+		self.buffer
+			.as_ref()
+			.map_or(0, |_| R::OUTPUT_SIZE - self.position)
+	}
+
+	/// Discard the buffered bytes, so the next request pulls a new output from the wrapped RNG.
+	pub fn flush(&mut self) {
+		self.buffer = None;
+		self.position = 0;
+	}
+
+	/// Return the wrapped RNG, discarding the buffered bytes.
+	pub fn into_inner(self) -> R {
+		self.rng
+	}
+
+	/// Fill `dest` from the buffer, refilling it with `next` whenever it runs out.
+	fn fill_with<E>(
+		&mut self,
+		dest: &mut [u8],
+		mut next: impl FnMut(&mut R) -> Result<R::Output, E>,
+	) -> Result<(), E> {
+		let mut filled = 0;
+		while filled < dest.len() {
+			if self.remaining() == 0 {
+				self.buffer = Some(next(&mut self.rng)?);
+				self.position = 0;
+			}
+			let buffer = match &self.buffer {
+				Some(buffer) => &buffer.as_ref()[self.position..],
+				None => unreachable!(),
+			};
+			let amt = buffer.len().min(dest.len() - filled);
+			dest[filled..filled + amt].copy_from_slice(&buffer[..amt]);
+			self.position += amt;
+			filled += amt;
+		}
+		Ok(())
+	}
+}
+
+impl<R: RNG + core::fmt::Debug> core::fmt::Debug for BufferedRng<R> {
+	/// Prints the wrapped RNG and how many bytes are buffered, but not the buffered bytes themselves.
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		f.debug_struct("BufferedRng")
+			.field("rng", &self.rng)
+			.field("remaining", &self.remaining())
+			.finish()
+	}
+}
+
+impl<R: RNG + Clone> Clone for BufferedRng<R>
+where
+	R::Output: Clone,
+{
+	fn clone(&self) -> Self {
+		Self {
+			rng: self.rng.clone(),
+			buffer: self.buffer.clone(),
+			position: self.position,
+		}
+	}
+}
+
+impl<R: RNG> RNG for BufferedRng<R> {
+	type Output = R::Output;
+	const OUTPUT_SIZE: usize = R::OUTPUT_SIZE;
+	const IS_CRYPTO: bool = R::IS_CRYPTO;
+
+	fn rand(&mut self) -> Self::Output {
+		self.rng.rand()
+	}
+
+	fn fill_bytes(&mut self, dest: &mut [u8]) {
+		infallible(self.fill_with(dest, |rng| Ok(rng.rand())))
+	}
+
+	fn try_rand(&mut self) -> Result<Self::Output, RandError> {
+		self.rng.try_rand()
+	}
+
+	/// Like [`RNG::fill_bytes`], but refills the buffer with [`RNG::try_rand`], so the wrapped RNG's errors are returned.
+	/// The bytes buffered before an error are kept.
+	fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RandError> {
+		self.fill_with(dest, R::try_rand)
+	}
+}
+
+impl<R: CryptoRNG> CryptoRNG for BufferedRng<R> {}
+
+impl<R: SeedableRNG> SeedableRNG for BufferedRng<R> {
+	type Seed = R::Seed;
+
+	fn from_seed(seed: Self::Seed) -> Self {
+		Self::new(R::from_seed(seed))
+	}
+
+	/// Reseeds the wrapped RNG, and discards the buffered bytes.
+	fn reseed(&mut self, seed: Self::Seed) {
+		self.rng.reseed(seed);
+		self.flush();
+	}
+}
@@ -0,0 +1,97 @@
+use crate::{
+	rand::{infallible, try_fill_from_outputs, RandError},
+	SeedableRNG, RNG,
+};
+
+/// Combines two RNGs by XORing their outputs together, so predicting the output requires predicting both of them.  
+/// As long as either RNG is unpredictable, and the two are seeded independently, so is the combination.
+///
+/// The output has the size of `A`'s output. Each output of `A` is XORed with as many bytes of `B`'s output as it takes,
+/// pulling in more outputs of `B` when `B`'s are smaller, and carrying nothing over when they're larger.
+/// ```rust
+/// use nanorand::{adapter::CombinedRng, Pcg64, RNG, WyRand};
+///
+/// let mut rng = CombinedRng::new(WyRand::new(), Pcg64::new());
+/// let n = rng.generate::<u64>();
+/// ```
+#[derive(Clone, Debug)]
+pub struct CombinedRng<A: RNG, B: RNG> {
+	a: A,
+	b: B,
+}
+
+impl<A: RNG, B: RNG> CombinedRng<A, B> {
+	/// Combine two RNGs, which should be seeded independently of each other.
+	pub fn new(a: A, b: B) -> Self {
+		Self { a, b }
+	}
+
+	/// Create both RNGs from their own seeds, which should be independent of each other.
+	pub fn from_seeds(seed_a: A::Seed, seed_b: B::Seed) -> Self
+	where
+		A: SeedableRNG,
+		B: SeedableRNG,
+	{
+		Self::new(A::from_seed(seed_a), B::from_seed(seed_b))
+	}
+
+	/// Reseed both RNGs, with their own seeds.
+	pub fn reseed(&mut self, seed_a: A::Seed, seed_b: B::Seed)
+	where
+		A: SeedableRNG,
+		B: SeedableRNG,
+	{
+		self.a.reseed(seed_a);
+		self.b.reseed(seed_b);
+	}
+
+	/// Return the two combined RNGs.
+	pub fn into_inner(self) -> (A, B) {
+		(self.a, self.b)
+	}
+
+	/// XOR an output of `A` with as many outputs of `B` as it takes, getting each with `next_a` and `next_b`.
+	fn combine<E>(
+		&mut self,
+		next_a: impl FnOnce(&mut A) -> Result<A::Output, E>,
+		mut next_b: impl FnMut(&mut B) -> Result<B::Output, E>,
+	) -> Result<A::Output, E>
+	where
+		A::Output: AsMut<[u8]>,
+	{
+		let mut ret = next_a(&mut self.a)?;
+		let dest = ret.as_mut();
+		let mut filled = 0;
+		while filled < dest.len() {
+			let generated = next_b(&mut self.b)?;
+			let generated = generated.as_ref();
+			let amt = B::OUTPUT_SIZE.min(dest.len() - filled);
+			dest[filled..filled + amt]
+				.iter_mut()
+				.zip(generated)
+				.for_each(|(a, b)| *a ^= *b);
+			filled += amt;
+		}
+		Ok(ret)
+	}
+}
+
+impl<A: RNG, B: RNG> RNG for CombinedRng<A, B>
+where
+	A::Output: AsMut<[u8]>,
+{
+	type Output = A::Output;
+	const OUTPUT_SIZE: usize = A::OUTPUT_SIZE;
+
+	fn rand(&mut self) -> Self::Output {
+		infallible(self.combine(|a| Ok(a.rand()), |b| Ok(b.rand())))
+	}
+
+	fn try_rand(&mut self) -> Result<Self::Output, RandError> {
+		self.combine(A::try_rand, B::try_rand)
+	}
+
+	fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RandError> {
+		try_fill_from_outputs(self, dest)
+	}
+}
@@ -0,0 +1,79 @@
+use crate::{
+	rand::{try_fill_from_outputs, RandError},
+	CryptoRNG, SeedableRNG, RNG,
+};
+
+/// Wraps an RNG, counting how many times [`RNG::rand`] is called on it.  
+/// This is useful for profiling how much entropy something uses, such as rejection sampling.
+/// [`RNG::fill_bytes`] isn't forwarded to the wrapped RNG, so that every output it uses is counted.
+/// Likewise, [`RNG::try_fill_bytes`] is built on the wrapped RNG's [`RNG::try_rand`], which is counted even if it fails.
+/// ```rust
+/// use nanorand::{adapter::CountingRng, RNG, WyRand};
+///
+/// let mut rng = CountingRng::new(WyRand::new());
+/// rng.generate::<u64>();
+/// rng.generate::<u128>();
+/// assert_eq!(rng.calls(), 3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CountingRng<R: RNG> {
+	rng: R,
+	calls: u64,
+}
+
+impl<R: RNG> CountingRng<R> {
+	/// Wrap an RNG, starting the count at 0.
+	pub fn new(rng: R) -> Self {
+		Self { rng, calls: 0 }
+	}
+
+	/// How many times [`RNG::rand`] has been called.
+	pub fn calls(&self) -> u64 {
+		self.calls
+	}
+
+	/// Set the count back to 0.
+	pub fn reset(&mut self) {
+		self.calls = 0;
+	}
+
+	/// Return the wrapped RNG.
+	pub fn into_inner(self) -> R {
+		self.rng
+	}
+}
+
+impl<R: RNG> RNG for CountingRng<R> {
+	type Output = R::Output;
+	const OUTPUT_SIZE: usize = R::OUTPUT_SIZE;
+	const IS_CRYPTO: bool = R::IS_CRYPTO;
+
+	fn rand(&mut self) -> Self::Output {
+		self.calls += 1;
+		self.rng.rand()
+	}
+
+	fn try_rand(&mut self) -> Result<Self::Output, RandError> {
+		self.calls += 1;
+		self.rng.try_rand()
+	}
+
+	fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RandError> {
+		try_fill_from_outputs(self, dest)
+	}
+}
+
+impl<R: CryptoRNG> CryptoRNG for CountingRng<R> {}
+
+impl<R: SeedableRNG> SeedableRNG for CountingRng<R> {
+	type Seed = R::Seed;
+
+	fn from_seed(seed: Self::Seed) -> Self {
+		Self::new(R::from_seed(seed))
+	}
+
+	/// Reseeds the wrapped RNG, keeping the count.
+	fn reseed(&mut self, seed: Self::Seed) {
+		self.rng.reseed(seed)
+	}
+}
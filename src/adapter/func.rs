@@ -0,0 +1,48 @@
+use crate::RNG;
+
+/// Wraps a closure returning [`u64`]s as an RNG, with each call to the closure giving one output.  
+/// This makes it easy to feed a known sequence into code that takes an RNG, in tests,
+/// or to use a generator from another crate without writing a full [`RNG`] implementation.
+/// ```rust
+/// use nanorand::{adapter::FnRng, RNG};
+///
+/// let mut counter = 0_u64;
+/// let mut rng = FnRng::new(|| {
+///     counter += 1;
+///     counter
+/// });
+/// assert_eq!(rng.generate::<u64>(), 1);
+/// assert_eq!(rng.generate::<u64>(), 2);
+/// ```
+/// There's no way to seed a closure, so this doesn't implement [`SeedableRNG`](crate::SeedableRNG).
+/// The closure can't fail either, so [`RNG::try_rand`] and [`RNG::try_fill_bytes`] always succeed.
+#[derive(Clone)]
+pub struct FnRng<F: FnMut() -> u64> {
+	f: F,
+}
+
+impl<F: FnMut() -> u64> FnRng<F> {
+	/// Wrap a closure, which is called once for every output.
+	pub fn new(f: F) -> Self {
+		Self { f }
+	}
+
+	/// Return the wrapped closure.
+	pub fn into_inner(self) -> F {
+		self.f
+	}
+}
+
+impl<F: FnMut() -> u64> core::fmt::Debug for FnRng<F> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_struct("FnRng").finish_non_exhaustive()
+	}
+}
+
+impl<F: FnMut() -> u64> RNG for FnRng<F> {
+	type Output = [u8; 8];
+
+	fn rand(&mut self) -> Self::Output {
+		to_output_bytes!((self.f)())
+	}
+}
@@ -0,0 +1,23 @@
+pub use buffered::BufferedRng;
+pub use combined::CombinedRng;
+pub use counting::CountingRng;
+pub use func::FnRng;
+#[cfg(feature = "std")]
+pub use read::ReadRng;
+#[cfg(feature = "std")]
+pub use reseeding::ReseedingRng;
+
+/// An RNG wrapper that serves small requests from the rest of each output.
+pub mod buffered;
+/// An RNG that XORs the outputs of two others.
+pub mod combined;
+/// An RNG wrapper that counts the outputs used.
+pub mod counting;
+/// An RNG that gets its outputs from a closure.
+pub mod func;
+/// An adapter reading random bytes from an RNG with `std::io::Read`.
+#[cfg(feature = "std")]
+pub mod read;
+/// An RNG wrapper that periodically reseeds from system entropy.
+#[cfg(feature = "std")]
+pub mod reseeding;
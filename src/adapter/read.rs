@@ -0,0 +1,40 @@
+use crate::RNG;
+use std::io;
+
+/// Wraps an RNG as a [`std::io::Read`], for piping random bytes into anything that takes a reader, such as a file or a hasher.  
+/// Every read is filled completely, with [`RNG::try_fill_bytes`], and the reader never runs out.
+/// If the generator fails, the read returns its [`RandError`](crate::RandError), as an [`io::Error`] of kind [`io::ErrorKind::Other`].
+/// ```rust
+/// use nanorand::{adapter::ReadRng, WyRand};
+/// use std::io::{self, Read};
+///
+/// let mut out = Vec::new();
+/// let copied = io::copy(&mut ReadRng::new(WyRand::new()).take(1024), &mut out).unwrap();
+/// assert_eq!(copied, 1024);
+/// assert_eq!(out.len(), 1024);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ReadRng<R: RNG> {
+	rng: R,
+}
+
+impl<R: RNG> ReadRng<R> {
+	/// Wrap an RNG.
+	pub fn new(rng: R) -> Self {
+		Self { rng }
+	}
+
+	/// Return the wrapped RNG.
+	pub fn into_inner(self) -> R {
+		self.rng
+	}
+}
+
+impl<R: RNG> io::Read for ReadRng<R> {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		self.rng
+			.try_fill_bytes(buf)
+			.map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+		Ok(buf.len())
+	}
+}
@@ -0,0 +1,128 @@
+use crate::{
+	entropy::fork,
+	rand::{infallible, RandError},
+	CryptoRNG, SeedableRNG, RNG,
+};
+use alloc::vec::Vec;
+
+/// Wraps an RNG, reseeding it from [`entropy_from_system`](crate::entropy::entropy_from_system)
+/// once every `threshold` bytes it generates.  
+/// Between reseeds, the output is exactly that of the wrapped RNG.
+/// This limits how far a long-running, non-cryptographic generator can be predicted from its past outputs.
+///
+/// On Unix, the wrapped RNG is also reseeded after a `fork()`, so parent and child processes don't generate the same values.
+/// ```rust
+/// use nanorand::{adapter::ReseedingRng, RNG, WyRand};
+///
+/// let mut rng = ReseedingRng::new(WyRand::new(), 1024 * 1024);
+/// let n = rng.generate::<u64>();
+/// ```
+#[derive(Clone, Debug)]
+pub struct ReseedingRng<R: SeedableRNG> {
+	rng: R,
+	threshold: usize,
+	generated: usize,
+	entropy: fn(usize) -> Vec<u8>,
+	forks: usize,
+}
+
+impl<R: SeedableRNG> ReseedingRng<R> {
+	/// Wrap an RNG, reseeding it after every `threshold` bytes generated.  
+	/// Panics if `threshold` is 0.
+	pub fn new(rng: R, threshold: usize) -> Self {
+		Self::with_entropy_source(rng, threshold, crate::entropy::entropy_from_system)
+	}
+
+	/// Wrap an RNG, reseeding it with bytes from `entropy` after every `threshold` bytes generated.
+	/// `entropy` is passed the number of bytes it should return, which is the size of the wrapped RNG's seed.  
+	/// Panics if `threshold` is 0.
+	pub fn with_entropy_source(rng: R, threshold: usize, entropy: fn(usize) -> Vec<u8>) -> Self {
+		assert!(threshold > 0, "The reseed threshold must be above 0!");
+		Self {
+			rng,
+			threshold,
+			generated: 0,
+			entropy,
+			forks: fork::count(),
+		}
+	}
+
+	/// Reseed the wrapped RNG from the entropy source right away, and restart the count towards the threshold.
+	pub fn reseed_now(&mut self) {
+		let mut seed = R::Seed::default();
+		let len = seed.as_mut().len();
+		let mut fetched = (self.entropy)(len);
+		seed.as_mut().copy_from_slice(&fetched);
+		crate::entropy::wipe(&mut fetched);
+		self.reseed(seed);
+	}
+
+	/// Reseed the wrapped RNG with the provided seed, and restart the count towards the threshold.
+	pub fn reseed(&mut self, seed: R::Seed) {
+		self.rng.reseed(seed);
+		self.generated = 0;
+		self.forks = fork::count();
+	}
+
+	/// Return the wrapped RNG.
+	pub fn into_inner(self) -> R {
+		self.rng
+	}
+
+	/// Fill `dest` with `fill`, reseeding whenever the threshold is reached along the way.
+	fn fill_with<E>(
+		&mut self,
+		dest: &mut [u8],
+		mut fill: impl FnMut(&mut R, &mut [u8]) -> Result<(), E>,
+	) -> Result<(), E> {
+		let mut filled = 0;
+		while filled < dest.len() {
+			let amt = self.remaining().min(dest.len() - filled);
+			fill(&mut self.rng, &mut dest[filled..filled + amt])?;
+			self.generated += amt;
+			filled += amt;
+		}
+		Ok(())
+	}
+
+	/// How many bytes can be generated before the next reseed.
+	fn remaining(&mut self) -> usize {
+		if self.generated >= self.threshold || self.forks != fork::count() {
+			self.reseed_now();
+		}
+		self.threshold - self.generated
+	}
+}
+
+impl<R: SeedableRNG> RNG for ReseedingRng<R> {
+	type Output = R::Output;
+	const OUTPUT_SIZE: usize = R::OUTPUT_SIZE;
+	const IS_CRYPTO: bool = R::IS_CRYPTO;
+
+	fn rand(&mut self) -> Self::Output {
+		self.remaining();
+		let ret = self.rng.rand();
+		self.generated += ret.as_ref().len();
+		ret
+	}
+
+	fn fill_bytes(&mut self, dest: &mut [u8]) {
+		infallible(self.fill_with(dest, |rng, dest| {
+			rng.fill_bytes(dest);
+			Ok(())
+		}))
+	}
+
+	fn try_rand(&mut self) -> Result<Self::Output, RandError> {
+		self.remaining();
+		let ret = self.rng.try_rand()?;
+		self.generated += ret.as_ref().len();
+		Ok(ret)
+	}
+
+	fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RandError> {
+		self.fill_with(dest, R::try_fill_bytes)
+	}
+}
+
+impl<R: SeedableRNG + CryptoRNG> CryptoRNG for ReseedingRng<R> {}
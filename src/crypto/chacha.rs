@@ -1,37 +1,32 @@
 const CHACHA_TAU: &[u8] = b"expand 16-byte k";
 
 fn chacha_rotl(a: u32, b: u32) -> u32 {
-	(a << b) | (a >> (32 - b))
+	a.rotate_left(b)
 }
 
 fn chacha_quarter_round(x: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
-	x[a] += x[b];
+	x[a] = x[a].wrapping_add(x[b]);
 	x[d] ^= x[a];
 	x[d] = chacha_rotl(x[d], 16);
-	x[c] += x[d];
+	x[c] = x[c].wrapping_add(x[d]);
 	x[b] ^= x[c];
 	x[b] = chacha_rotl(x[b], 12);
-	x[a] += x[b];
+	x[a] = x[a].wrapping_add(x[b]);
 	x[d] ^= x[a];
 	x[d] = chacha_rotl(x[d], 8);
-	x[c] += x[d];
+	x[c] = x[c].wrapping_add(x[d]);
 	x[b] ^= x[c];
 	x[b] = chacha_rotl(x[b], 7);
 }
 
-fn chacha_pack(x: &[u8], a: usize) -> u32 {
-	let mut res = 0_u32;
-	res |= ((x[a] as u32) << 0 * 8) as u32;
-	res |= ((x[a + 1] as u32) << 1 * 8) as u32;
-	res |= ((x[a + 2] as u32) << 2 * 8) as u32;
-	res |= ((x[a + 3] as u32) << 3 * 8) as u32;
-	res
+const fn chacha_pack(x: &[u8], a: usize) -> u32 {
+	u32::from_le_bytes([x[a], x[a + 1], x[a + 2], x[a + 3]])
 }
 
 /// Do one ChaCha round on the input data.
 pub fn chacha_block(rounds: u8, input: [u32; 16]) -> [u32; 16] {
 	let mut x = input;
-	if rounds % 2 != 0 {
+	if rounds & 1 != 0 {
 		panic!("ChaCha rounds must be divisble by 2!")
 	}
 	for _ in (0..rounds).step_by(2) {
@@ -53,7 +48,7 @@ pub fn chacha_block(rounds: u8, input: [u32; 16]) -> [u32; 16] {
 }
 
 /// Initialize the ChaCha internal state, with a 32-byte kit.
-pub fn chacha_init(key: [u8; 32], nonce: [u8; 16]) -> [u32; 16] {
+pub const fn chacha_init(key: [u8; 32], nonce: [u8; 16]) -> [u32; 16] {
 	let mut state = [0u32; 16];
 	state[0] = chacha_pack(CHACHA_TAU, 0);
 	state[1] = chacha_pack(CHACHA_TAU, 4);
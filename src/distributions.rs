@@ -0,0 +1,517 @@
+use crate::RNG;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Roll `count` dice with `sides` sides each, returning the sum of the rolls,
+/// which is always within `count..=count * sides`.  
+/// The sum is a [`u64`], so it can't overflow, even for [`u32::MAX`] dice with [`u32::MAX`] sides.
+/// ```rust
+/// use nanorand::{distributions::roll_dice, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let damage = roll_dice(&mut rng, 2, 6);
+/// assert!((2..=12).contains(&damage));
+/// ```
+/// Panics if `sides` is 0.
+pub fn roll_dice<R: RNG>(r: &mut R, count: u32, sides: u32) -> u64 {
+	assert!(sides >= 1, "Dice must have at least one side!");
+	(0..count)
+		.map(|_| u64::from(r.generate_range(1..=sides)))
+		.sum()
+}
+
+/// Roll `count` dice with `sides` sides each, returning each individual roll.  
+/// Panics if `sides` is 0.
+#[cfg(feature = "alloc")]
+pub fn roll_dice_detailed<R: RNG>(r: &mut R, count: u32, sides: u32) -> Vec<u32> {
+	assert!(sides >= 1, "Dice must have at least one side!");
+	(0..count).map(|_| r.generate_range(1..=sides)).collect()
+}
+
+/// Generate `-1` or `+1` with equal probability, from a single random bit.  
+/// This works for every signed integer and float type, since they can all be converted from an [`i8`].
+/// ```rust
+/// use nanorand::{distributions::random_sign, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let nudge = random_sign::<_, f64>(&mut rng) * 0.25;
+/// assert!(nudge == 0.25 || nudge == -0.25);
+/// ```
+pub fn random_sign<R: RNG, T: From<i8>>(r: &mut R) -> T {
+	if r.generate::<bool>() {
+		T::from(1)
+	} else {
+		T::from(-1)
+	}
+}
+
+/// Generate a [`bool`] that's `true` with probability `p`, and `false` otherwise.  
+/// `p` is clamped to `0.0..=1.0`, and a `p` of NaN is treated as 0.
+/// When `p` is 0 or below, or 1 or above, the result is known up front, and the RNG isn't used.
+/// ```rust
+/// use nanorand::{distributions::random_bool, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let critical_hit = random_bool(&mut rng, 0.05);
+/// assert!(random_bool(&mut rng, 1.0));
+/// ```
+pub fn random_bool<R: RNG>(r: &mut R, p: f64) -> bool {
+	if p >= 1.0 {
+		return true;
+	}
+	if p <= 0.0 || p.is_nan() {
+		return false;
+	}
+	r.generate::<f64>() < p
+}
+
+/// Generate `n` random bits, packed 8 to a byte, with bit `i` at `bytes[i / 8] >> (i % 8) & 1`.  
+/// The bytes come straight from [`RNG::fill_bytes`], so this pulls whole outputs from the RNG, rather than one per bit.
+/// Bits past `n` in the last byte are always 0.
+/// ```rust
+/// use nanorand::{distributions::random_bitset, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let mask = random_bitset(&mut rng, 12);
+/// assert_eq!(mask.len(), 2);
+/// assert_eq!(mask[1] & 0xF0, 0);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn random_bitset<R: RNG>(r: &mut R, n: usize) -> Vec<u8> {
+	let mut bytes = alloc::vec![0u8; (n + 7) / 8];
+	r.fill_bytes(&mut bytes);
+	if n % 8 != 0 {
+		if let Some(last) = bytes.last_mut() {
+			*last &= (1 << (n % 8)) - 1;
+		}
+	}
+	bytes
+}
+
+/// Generate `n` random [`bool`]s, each `true` with probability 1/2.  
+/// This unpacks [`random_bitset`], so it uses a single bit of the RNG's output for each [`bool`].
+/// ```rust
+/// use nanorand::{distributions::random_bits, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let alive = random_bits(&mut rng, 64 * 64);
+/// assert_eq!(alive.len(), 4096);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn random_bits<R: RNG>(r: &mut R, n: usize) -> Vec<bool> {
+	let bytes = random_bitset(r, n);
+	(0..n)
+		.map(|idx| bytes[idx / 8] >> (idx % 8) & 1 == 1)
+		.collect()
+}
+
+/// Generate a value within `low..=high` from the triangular distribution,
+/// which is most likely at `mode`, and gets linearly less likely towards `low` and `high`.  
+/// This uses the inverse of the distribution's CDF, so it takes a single [`f64`] from the RNG.
+/// Its mean is `(low + mode + high) / 3`.
+/// ```rust
+/// use nanorand::{distributions::random_triangular, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let height = random_triangular(&mut rng, 0.0, 100.0, 20.0);
+/// assert!((0.0..=100.0).contains(&height));
+/// ```
+/// Panics unless `low <= mode <= high`.
+#[cfg(feature = "std")]
+pub fn random_triangular<R: RNG>(r: &mut R, low: f64, high: f64, mode: f64) -> f64 {
+	assert!(
+		low <= mode && mode <= high,
+		"The mode must be within low..=high!"
+	);
+	let span = high - low;
+	if span == 0.0 {
+		return low;
+	}
+	let u = r.generate::<f64>();
+	let ret = if u < (mode - low) / span {
+		low + (u * span * (mode - low)).sqrt()
+	} else {
+		high - ((1.0 - u) * span * (high - mode)).sqrt()
+	};
+	// Rounding can push the result just past a bound when the range is far from zero.
+	ret.max(low).min(high)
+}
+
+/// Generate a count from the Poisson distribution with mean `lambda`,
+/// such as how many events arrive in an interval, when `lambda` arrive on average.  
+/// Both its mean and variance are `lambda`.
+///
+/// Below a `lambda` of 10, this uses Knuth's algorithm, multiplying uniform floats until they drop below `e^-lambda`,
+/// which takes about `lambda` floats.
+/// Otherwise, it uses Hörmann's transformed rejection with squeeze (PTRS), which takes about 2 floats, however large `lambda` is.
+/// ```rust
+/// use nanorand::{distributions::random_poisson, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let arrivals = random_poisson(&mut rng, 3.5);
+/// let requests = random_poisson(&mut rng, 1e6);
+/// ```
+/// Panics unless `lambda` is above 0 and finite.
+#[cfg(feature = "std")]
+pub fn random_poisson<R: RNG>(r: &mut R, lambda: f64) -> u64 {
+	assert!(
+		lambda > 0.0 && lambda.is_finite(),
+		"Lambda must be above 0 and finite!"
+	);
+	if lambda < 10.0 {
+		let limit = (-lambda).exp();
+		let mut product = r.generate::<f64>();
+		let mut count = 0;
+		while product > limit {
+			product *= r.generate::<f64>();
+			count += 1;
+		}
+		return count;
+	}
+	let sqrt_lambda = lambda.sqrt();
+	let ln_lambda = lambda.ln();
+	let b = 0.931 + 2.53 * sqrt_lambda;
+	let a = -0.059 + 0.02483 * b;
+	let ln_inv_alpha = (1.1239 + 1.1328 / (b - 3.4)).ln();
+	let v_r = 0.9277 - 3.6224 / (b - 2.0);
+	loop {
+		let u = r.generate::<f64>() - 0.5;
+		let v = r.generate::<f64>();
+		let us = 0.5 - u.abs();
+		let k = ((2.0 * a / us + b) * u + lambda + 0.43).floor();
+		if us >= 0.07 && v <= v_r {
+			return k as u64;
+		}
+		if k < 0.0 || (us < 0.013 && v > us) {
+			continue;
+		}
+		let accept = -lambda + k * ln_lambda - ln_factorial(k as u64);
+		if v.ln() + ln_inv_alpha - (a / (us * us) + b).ln() <= accept {
+			return k as u64;
+		}
+	}
+}
+
+/// Generate the number of successes in `n` independent trials, which each succeed with probability `p`,
+/// from the binomial distribution.  
+/// Its mean is `n * p`, and its variance is `n * p * (1 - p)`.
+///
+/// For up to 16 trials, this simply runs each one.
+/// Otherwise, when fewer than 10 successes or failures are expected, it inverts the distribution's CDF,
+/// which takes a single float, and time proportional to `n * p`.
+/// Beyond that, it uses Hörmann's transformed rejection with decomposition (BTRD), which takes about 2 floats, however large `n` is.
+/// ```rust
+/// use nanorand::{distributions::random_binomial, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let heads = random_binomial(&mut rng, 10, 0.5);
+/// let clicks = random_binomial(&mut rng, 1_000_000, 0.031);
+/// assert!(heads <= 10 && clicks <= 1_000_000);
+/// ```
+/// Panics unless `p` is within `0.0..=1.0`.
+#[cfg(feature = "std")]
+pub fn random_binomial<R: RNG>(r: &mut R, n: u64, p: f64) -> u64 {
+	assert!(
+		(0.0..=1.0).contains(&p),
+		"The probability must be within 0.0..=1.0!"
+	);
+	if p > 0.5 {
+		// Counting failures instead keeps the fast paths below for small `p` useful.
+		return n - random_binomial(r, n, 1.0 - p);
+	}
+	if p == 0.0 || n == 0 {
+		return 0;
+	}
+	if n <= 16 {
+		return (0..n).filter(|_| r.generate::<f64>() < p).count() as u64;
+	}
+	if (n as f64) * p < 10.0 {
+		binomial_inversion(r, n, p)
+	} else {
+		binomial_btrd(r, n, p)
+	}
+}
+
+/// Binomial sampling by walking up the CDF from 0, for when `n * p` is small.
+#[cfg(feature = "std")]
+fn binomial_inversion<R: RNG>(r: &mut R, n: u64, p: f64) -> u64 {
+	let q = 1.0 - p;
+	let odds = p / q;
+	let a = (n + 1) as f64 * odds;
+	let first = q.powf(n as f64);
+	loop {
+		let mut u = r.generate::<f64>();
+		let mut prob = first;
+		let mut k = 0;
+		while u > prob {
+			u -= prob;
+			k += 1;
+			// Rounding can leave `u` above 0 once every outcome has been passed, so start over.
+			if k > n {
+				break;
+			}
+			prob *= a / k as f64 - odds;
+		}
+		if k <= n {
+			return k;
+		}
+	}
+}
+
+/// Binomial sampling with Hörmann's BTRD, for when `n * p` is at least 10, and `p` is at most 0.5.
+#[cfg(feature = "std")]
+fn binomial_btrd<R: RNG>(r: &mut R, n: u64, p: f64) -> u64 {
+	let nf = n as f64;
+	let q = 1.0 - p;
+	let mode = ((nf + 1.0) * p).floor();
+	let odds = p / q;
+	let n_odds = (nf + 1.0) * odds;
+	let npq = nf * p * q;
+	let sqrt_npq = npq.sqrt();
+	let b = 1.15 + 2.53 * sqrt_npq;
+	let a = -0.0873 + 0.0248 * b + 0.01 * p;
+	let c = nf * p + 0.5;
+	let alpha = (2.83 + 5.1 / b) * sqrt_npq;
+	let v_r = 0.92 - 4.2 / b;
+	let u_r_v_r = 0.86 * v_r;
+	loop {
+		let mut v = r.generate::<f64>();
+		if v <= u_r_v_r {
+			let u = v / v_r - 0.43;
+			return ((2.0 * a / (0.5 - u.abs()) + b) * u + c).floor() as u64;
+		}
+		let u = if v >= v_r {
+			r.generate::<f64>() - 0.5
+		} else {
+			let u = v / v_r - 0.93;
+			v = r.generate::<f64>() * v_r;
+			0.5_f64.copysign(u) - u
+		};
+		let us = 0.5 - u.abs();
+		let k = ((2.0 * a / us + b) * u + c).floor();
+		if k < 0.0 || k > nf {
+			continue;
+		}
+		v *= alpha / (a / (us * us) + b);
+		let km = (k - mode).abs();
+		if km <= 15.0 {
+			// Close to the mode, the ratio of probabilities is cheap to work out term by term.
+			let mut f = 1.0;
+			let mut i = mode;
+			while i < k {
+				i += 1.0;
+				f *= n_odds / i - odds;
+			}
+			let mut i = k;
+			while i < mode {
+				i += 1.0;
+				v *= n_odds / i - odds;
+			}
+			if v <= f {
+				return k as u64;
+			}
+			continue;
+		}
+		let v = v.ln();
+		let rho = (km / npq) * (((km / 3.0 + 0.625) * km + 1.0 / 6.0) / npq + 0.5);
+		let t = -km * km / (2.0 * npq);
+		if v < t - rho {
+			return k as u64;
+		}
+		if v > t + rho {
+			continue;
+		}
+		let n_mode = nf - mode + 1.0;
+		let h = (mode + 0.5) * ((mode + 1.0) / (odds * n_mode)).ln()
+			+ stirling_correction(mode as u64)
+			+ stirling_correction(n - mode as u64);
+		let n_k = nf - k + 1.0;
+		let bound =
+			h + (nf + 1.0) * (n_mode / n_k).ln() + (k + 0.5) * (n_k * odds / (k + 1.0)).ln()
+				- stirling_correction(k as u64)
+				- stirling_correction(n - k as u64);
+		if v <= bound {
+			return k as u64;
+		}
+	}
+}
+
+/// The error of Stirling's approximation of `ln(k!)`, from `(k + 0.5) ln(k + 1) - (k + 1) + ln(2π) / 2`.
+#[cfg(feature = "std")]
+fn stirling_correction(k: u64) -> f64 {
+	const SMALL: [f64; 10] = [
+		0.08106146679532726,
+		0.04134069595540929,
+		0.02767792568499834,
+		0.02079067210376509,
+		0.01664469118982119,
+		0.01387612882307075,
+		0.01189670994589177,
+		0.01041126526197209,
+		0.009255462182712733,
+		0.00833056343336287,
+	];
+	if let Some(&correction) = SMALL.get(k as usize) {
+		return correction;
+	}
+	let k1 = (k + 1) as f64;
+	let inv2 = 1.0 / (k1 * k1);
+	(1.0 / 12.0 - (1.0 / 360.0 - inv2 / 1260.0) * inv2) / k1
+}
+
+/// The natural log of `n!`, exactly summed for small `n`, and from Stirling's series otherwise,
+/// which is accurate to about 1e-12 from there.
+#[cfg(feature = "std")]
+fn ln_factorial(n: u64) -> f64 {
+	if n < 16 {
+		return (2..=n).map(|i| (i as f64).ln()).sum();
+	}
+	let n = n as f64;
+	let inv = 1.0 / n;
+	let inv2 = inv * inv;
+	n * n.ln() - n
+		+ 0.5 * (core::f64::consts::TAU * n).ln()
+		+ inv * (1.0 / 12.0 - inv2 * (1.0 / 360.0 - inv2 / 1260.0))
+}
+
+/// Generate a uniformly distributed direction in 2D, as a point `(x, y)` on the unit circle.  
+/// This picks a uniformly random angle, which is all it takes in 2D.
+/// ```rust
+/// use nanorand::{distributions::random_unit_vector_2d, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let (x, y) = random_unit_vector_2d(&mut rng);
+/// assert!((x * x + y * y - 1.0).abs() < 1e-9);
+/// ```
+#[cfg(feature = "std")]
+pub fn random_unit_vector_2d<R: RNG>(r: &mut R) -> (f64, f64) {
+	let angle = r.generate::<f64>() * core::f64::consts::TAU;
+	let (y, x) = angle.sin_cos();
+	(x, y)
+}
+
+/// Generate a uniformly distributed direction in 3D, as a point `(x, y, z)` on the unit sphere.  
+/// Picking two uniformly random angles would bunch points up at the poles,
+/// so instead this picks a uniformly random height, and a uniformly random angle around the axis at that height.
+/// By Archimedes' hat-box theorem, that covers the sphere's surface evenly.
+/// ```rust
+/// use nanorand::{distributions::random_unit_vector_3d, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let (x, y, z) = random_unit_vector_3d(&mut rng);
+/// assert!((x * x + y * y + z * z - 1.0).abs() < 1e-9);
+/// ```
+#[cfg(feature = "std")]
+pub fn random_unit_vector_3d<R: RNG>(r: &mut R) -> (f64, f64, f64) {
+	let z = r.generate::<f64>() * 2.0 - 1.0;
+	let (x, y) = random_unit_vector_2d(r);
+	let radius = (1.0 - z * z).sqrt();
+	(x * radius, y * radius, z)
+}
+
+/// The reason [`WeightedIndex::new`] couldn't build a table from some weights.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeightedError {
+	/// There were no weights at all.
+	NoWeights,
+	/// Every weight was 0, so there's nothing to pick.
+	AllZero,
+	/// The weight at this index was negative, NaN, or infinite.
+	InvalidWeight(usize),
+}
+
+impl core::fmt::Display for WeightedError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			Self::NoWeights => write!(f, "there are no weights to pick from"),
+			Self::AllZero => write!(f, "every weight is 0"),
+			Self::InvalidWeight(idx) => write!(
+				f,
+				"the weight at index {} is negative, NaN, or infinite",
+				idx
+			),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WeightedError {}
+
+/// A precomputed table for picking indices with probabilities proportional to their weights, in O(1) per pick.  
+/// This is built once with Vose's alias method, in O(n) time and memory,
+/// so it's worth it when the same weights are sampled many times.
+/// For a one-off pick, [`RNG::weighted_choose`] doesn't need to build anything.
+/// ```rust
+/// use nanorand::{distributions::WeightedIndex, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let particles = ["spark", "smoke", "ember"];
+/// let table = WeightedIndex::new(&[70.0, 25.0, 5.0]).unwrap();
+/// let particle = particles[table.sample(&mut rng)];
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightedIndex {
+	/// The probability of keeping each column's own index, rather than its alias.
+	probabilities: Vec<f64>,
+	/// The index each column picks otherwise.
+	aliases: Vec<usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl WeightedIndex {
+	/// Build a table from `weights`, which must be finite and not negative, with at least one above 0.
+	pub fn new(weights: &[f64]) -> Result<Self, WeightedError> {
+		if weights.is_empty() {
+			return Err(WeightedError::NoWeights);
+		}
+		if let Some(idx) = weights
+			.iter()
+			.position(|weight| !weight.is_finite() || *weight < 0.0)
+		{
+			return Err(WeightedError::InvalidWeight(idx));
+		}
+		// Scaling by the largest weight first keeps the sum from overflowing, even for weights near `f64::MAX`.
+		let max = weights.iter().copied().fold(0.0, f64::max);
+		if max == 0.0 {
+			return Err(WeightedError::AllZero);
+		}
+		let len = weights.len();
+		let total: f64 = weights.iter().map(|weight| weight / max).sum();
+		let scale = len as f64 / total;
+		let mut probabilities: Vec<f64> =
+			weights.iter().map(|weight| weight / max * scale).collect();
+		let mut aliases: Vec<usize> = (0..len).collect();
+
+		let (mut small, mut large): (Vec<usize>, Vec<usize>) =
+			(0..len).partition(|&idx| probabilities[idx] < 1.0);
+		while let (Some(&less), Some(&more)) = (small.last(), large.last()) {
+			small.pop();
+			aliases[less] = more;
+			// This order of operations loses the least precision, as in Vose's paper.
+			probabilities[more] = (probabilities[more] + probabilities[less]) - 1.0;
+			if probabilities[more] < 1.0 {
+				large.pop();
+				small.push(more);
+			}
+		}
+		// Whatever's left is only off from 1 by rounding errors.
+		for idx in small.into_iter().chain(large) {
+			probabilities[idx] = 1.0;
+		}
+		Ok(Self {
+			probabilities,
+			aliases,
+		})
+	}
+
+	/// Pick an index into the original weights, with a probability proportional to its weight.
+	pub fn sample<R: RNG>(&self, r: &mut R) -> usize {
+		let idx = r.generate_range(0..self.probabilities.len());
+		if r.generate::<f64>() < self.probabilities[idx] {
+			idx
+		} else {
+			self.aliases[idx]
+		}
+	}
+}
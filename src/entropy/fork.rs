@@ -0,0 +1,35 @@
+#[cfg(all(unix, feature = "std"))]
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(all(unix, feature = "std"))]
+static FORKS: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(all(unix, feature = "std"))]
+extern "C" {
+	fn pthread_atfork(
+		prepare: Option<unsafe extern "C" fn()>,
+		parent: Option<unsafe extern "C" fn()>,
+		child: Option<unsafe extern "C" fn()>,
+	) -> i32;
+}
+
+#[cfg(all(unix, feature = "std"))]
+unsafe extern "C" fn on_fork_child() {
+	FORKS.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Counts the `fork()`s this process has gone through, using a `pthread_atfork` handler in the child.
+#[cfg(all(unix, feature = "std"))]
+pub(crate) fn count() -> usize {
+	static REGISTER: std::sync::Once = std::sync::Once::new();
+	REGISTER.call_once(|| unsafe {
+		pthread_atfork(None, None, Some(on_fork_child));
+	});
+	FORKS.load(Ordering::Relaxed)
+}
+
+/// There's no `fork()` to detect outside of Unix, or without `std` to register the handler only once.
+#[cfg(not(all(unix, feature = "std")))]
+pub(crate) fn count() -> usize {
+	0
+}
@@ -1,15 +1,35 @@
-use alloc::vec::Vec;
+use alloc::{boxed::Box, vec::Vec};
+use core::sync::atomic::{AtomicUsize, Ordering};
 
-#[cfg(all(unix, not(feature = "getrandom")))]
-pub use unix::entropy_from_system;
-#[cfg(all(windows, not(target_vendor = "uwp"), not(feature = "getrandom")))]
-pub use windows::entropy_from_system;
-#[cfg(all(windows, target_vendor = "uwp", not(feature = "getrandom")))]
-pub use windows_uwp::entropy_from_system;
+#[cfg(nanorand_test_entropy)]
+use test_backend::try_entropy_from_system as os_entropy;
+#[cfg(all(unix, not(feature = "getrandom"), not(nanorand_test_entropy)))]
+use unix::try_entropy_from_system as os_entropy;
+#[cfg(all(
+	target_arch = "wasm32",
+	feature = "wasm",
+	not(feature = "getrandom"),
+	not(nanorand_test_entropy)
+))]
+use wasm::try_entropy_from_system as os_entropy;
+#[cfg(all(
+	windows,
+	not(target_vendor = "uwp"),
+	not(feature = "getrandom"),
+	not(nanorand_test_entropy)
+))]
+use windows::try_entropy_from_system as os_entropy;
+#[cfg(all(
+	windows,
+	target_vendor = "uwp",
+	not(feature = "getrandom"),
+	not(nanorand_test_entropy)
+))]
+use windows_uwp::try_entropy_from_system as os_entropy;
 
 #[cfg(all(unix, not(feature = "getrandom")))]
 /// A 100% safe entropy generator, using (in order of priority) `/dev/urandom`,
-/// `/dev/random`, or the system time.
+/// or `/dev/random`.
 pub mod unix;
 
 #[cfg(all(windows, target_vendor = "uwp", not(feature = "getrandom")))]
@@ -20,21 +40,236 @@ pub mod windows_uwp;
 /// An entropy generator for Windows, using WinAPI's `RtlGenRandom` function.
 pub mod windows;
 
-#[cfg(feature = "getrandom")]
-/// Pull in system entropy using the [`getrandom`](https://crates.io/crates/getrandom) crate.  
-/// Uses backup entropy (rdseed and system time) if it fails.
-pub fn entropy_from_system(amt: usize) -> Vec<u8> {
-	let mut entropy: Vec<u8> = vec![42; amt];
-	match getrandom::getrandom(&mut entropy) {
-		Ok(_) => entropy,
-		Err(_) => backup_entropy(amt),
+#[cfg(all(target_arch = "wasm32", feature = "wasm", not(feature = "getrandom")))]
+/// An entropy generator for WebAssembly in JavaScript environments, using the Web Crypto API's `crypto.getRandomValues`.
+pub mod wasm;
+
+#[cfg(nanorand_test_entropy)]
+/// A stand-in for the system's entropy source, which fails or gets stuck on purpose, for testing how that's handled.  
+/// Selected with `RUSTFLAGS="--cfg nanorand_test_entropy"`, and needs the `std` feature.
+mod test_backend;
+#[cfg(nanorand_test_entropy)]
+#[doc(hidden)]
+pub use test_backend::fetches as test_entropy_fetches;
+
+/// Detecting `fork()`, so generators seeded from system entropy don't repeat in the child.
+pub(crate) mod fork;
+
+/// An error from the system's entropy source, such as `/dev/urandom` being unavailable in a sandbox.  
+/// Returned by [`try_entropy_from_system`], and the `try_new` constructors of generators seeded from it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EntropyError {
+	backend: &'static str,
+	degenerate: bool,
+}
+
+impl EntropyError {
+	pub(crate) const fn new(backend: &'static str) -> Self {
+		Self {
+			backend,
+			degenerate: false,
+		}
+	}
+
+	pub(crate) const fn degenerate(backend: &'static str) -> Self {
+		Self {
+			backend,
+			degenerate: true,
+		}
+	}
+
+	/// The name of the entropy source that failed, such as `"/dev/urandom"` or `"getrandom"`.
+	pub const fn backend(&self) -> &'static str {
+		self.backend
+	}
+
+	/// Whether the entropy source gave output, but it failed [`try_checked_entropy_from_system`]'s sanity checks,
+	/// rather than giving no output at all.
+	pub const fn is_degenerate(&self) -> bool {
+		self.degenerate
+	}
+}
+
+impl core::fmt::Display for EntropyError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		if self.degenerate {
+			write!(f, "the entropy from {} looks stuck", self.backend)
+		} else {
+			write!(f, "failed to obtain entropy from {}", self.backend)
+		}
 	}
 }
 
-/// Pull in backup entropy (rdseed and system time).
-#[cfg(not(any(feature = "getrandom", unix, windows)))]
+#[cfg(feature = "std")]
+impl std::error::Error for EntropyError {}
+
+/// Obtain a series of random bytes from the system's entropy source.  
+/// The output of the platform's primary source (see the per-platform modules, or `getrandom`)
+/// is XORed with a stream derived from several weaker sources, such as the system time,
+/// the CPU's timestamp counter, and ASLR'd stack and heap addresses, so that a weak or broken
+/// primary source doesn't directly result in a predictable seed.  
+/// Panics if there's no entropy to be had, see [`try_entropy_from_system`] for the fallible version.
 pub fn entropy_from_system(amt: usize) -> Vec<u8> {
-	backup_entropy(amt)
+	match try_entropy_from_system(amt) {
+		Ok(entropy) => entropy,
+		Err(err) => panic!("Couldn't seed from system entropy: {}", err),
+	}
+}
+
+/// Obtain a series of random bytes from the system's entropy source, like [`entropy_from_system`].  
+/// If the platform's primary source fails, `rdseed` is tried (with the `rdseed` feature),
+/// and if that fails too, the primary source's error is returned, rather than falling back to something predictable like the system time.
+pub fn try_entropy_from_system(amt: usize) -> Result<Vec<u8>, EntropyError> {
+	primary_entropy(amt).map(mix_weak_entropy)
+}
+
+/// Obtain a series of random bytes from the system's entropy source, like [`try_entropy_from_system`],
+/// but first check that the primary source isn't obviously broken.  
+/// Two samples are drawn from it, and if they're the same, or either one is a single byte repeated (such as all zeroes),
+/// an error is returned, for which [`EntropyError::is_degenerate`] is `true`.
+/// That can only catch a source that's stuck, not one that's subtly biased.
+pub fn try_checked_entropy_from_system(amt: usize) -> Result<Vec<u8>, EntropyError> {
+	// Short samples can repeat by chance, so at least 8 bytes are compared.
+	let sample = amt.max(core::mem::size_of::<u64>());
+	let mut first = primary_entropy(sample)?;
+	let mut second = match primary_entropy(sample) {
+		Ok(second) => second,
+		Err(err) => {
+			wipe(&mut first);
+			return Err(err);
+		}
+	};
+	let repeated = |bytes: &[u8]| bytes.iter().all(|&byte| byte == bytes[0]);
+	let degenerate = first == second || repeated(&first) || repeated(&second);
+	wipe(&mut second);
+	if degenerate {
+		wipe(&mut first);
+		return Err(EntropyError::degenerate("the system entropy source"));
+	}
+	wipe(&mut first[amt..]);
+	first.truncate(amt);
+	Ok(mix_weak_entropy(first))
+}
+
+/// Get entropy from the platform's primary source, falling back to `rdseed`.
+fn primary_entropy(amt: usize) -> Result<Vec<u8>, EntropyError> {
+	os_entropy(amt).or_else(|err| match rdseed_entropy(amt) {
+		Some(Ok(mut entropy)) => {
+			wipe(&mut entropy[amt..]);
+			entropy.truncate(amt);
+			Ok(entropy)
+		}
+		Some(Err(mut partial)) => {
+			wipe(&mut partial);
+			Err(err)
+		}
+		None => Err(err),
+	})
+}
+
+/// Zero entropy that's about to be thrown away, with the `zeroize` feature enabled,
+/// so it isn't left behind in freed memory.
+#[cfg_attr(not(feature = "zeroize"), allow(unused_variables))]
+pub(crate) fn wipe(entropy: &mut [u8]) {
+	#[cfg(feature = "zeroize")]
+	zeroize::Zeroize::zeroize(entropy);
+}
+
+/// XOR `entropy` with a stream derived from the weak entropy sources.
+fn mix_weak_entropy(mut entropy: Vec<u8>) -> Vec<u8> {
+	let mut state = weak_entropy_seed();
+	entropy
+		.chunks_mut(core::mem::size_of::<u64>())
+		.for_each(|chunk| {
+			state = state.wrapping_add(0xa0761d6478bd642f);
+			let mixed = mix(state, state ^ 0xe7037ed1a0b428db).to_le_bytes();
+			chunk
+				.iter_mut()
+				.zip(mixed.iter())
+				.for_each(|(a, b)| *a ^= *b);
+		});
+	entropy
+}
+
+#[cfg(all(feature = "getrandom", not(nanorand_test_entropy)))]
+/// Pull in system entropy using the [`getrandom`](https://crates.io/crates/getrandom) crate.
+fn os_entropy(amt: usize) -> Result<Vec<u8>, EntropyError> {
+	let mut entropy: Vec<u8> = vec![42; amt];
+	getrandom::getrandom(&mut entropy).map_err(|_| EntropyError::new("getrandom"))?;
+	Ok(entropy)
+}
+
+/// Pull in backup entropy (rdseed and system time), as there's no OS source on this platform.
+#[cfg(all(
+	feature = "std",
+	not(any(
+		feature = "getrandom",
+		unix,
+		windows,
+		all(target_arch = "wasm32", feature = "wasm"),
+		nanorand_test_entropy
+	))
+))]
+fn os_entropy(amt: usize) -> Result<Vec<u8>, EntropyError> {
+	Ok(backup_entropy(amt))
+}
+
+/// There's no source of entropy at all without an OS or `std`.
+#[cfg(not(any(
+	feature = "std",
+	feature = "getrandom",
+	unix,
+	windows,
+	all(target_arch = "wasm32", feature = "wasm"),
+	nanorand_test_entropy
+)))]
+fn os_entropy(_amt: usize) -> Result<Vec<u8>, EntropyError> {
+	Err(EntropyError::new("a platform with no entropy source"))
+}
+
+/// The wyhash mixing function, used to fold weak entropy sources together.
+#[inline(always)]
+fn mix(a: u64, b: u64) -> u64 {
+	let t = ((a ^ 0xa0761d6478bd642f) as u128).wrapping_mul((b ^ 0xe7037ed1a0b428db) as u128);
+	((t >> 64) ^ t) as u64
+}
+
+/// Fold every weak entropy source available on this platform into a single [`u64`].
+fn weak_entropy_seed() -> u64 {
+	static CALLS: AtomicUsize = AtomicUsize::new(0);
+	let mut seed = mix(CALLS.fetch_add(1, Ordering::Relaxed) as u64, 0);
+
+	// ASLR, where the platform has it.
+	let stack = 0u8;
+	let heap = Box::new(0u8);
+	seed = mix(seed, &stack as *const u8 as usize as u64);
+	seed = mix(seed, &*heap as *const u8 as usize as u64);
+	seed = mix(seed, weak_entropy_seed as fn() -> u64 as usize as u64);
+
+	// wasm32-unknown-unknown has std, but panics when asked for the time.
+	#[cfg(all(
+		feature = "std",
+		not(all(target_arch = "wasm32", target_os = "unknown"))
+	))]
+	{
+		use std::time::{SystemTime, UNIX_EPOCH};
+		if let Ok(time) = SystemTime::now().duration_since(UNIX_EPOCH) {
+			let nanos = time.as_nanos();
+			seed = mix(seed, (nanos >> 64) as u64 ^ nanos as u64);
+		}
+	}
+
+	#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+	{
+		#[cfg(target_arch = "x86")]
+		use core::arch::x86::_rdtsc;
+		#[cfg(target_arch = "x86_64")]
+		use core::arch::x86_64::_rdtsc;
+		// Safety: rdtsc is available on every x86 CPU Rust supports.
+		seed = mix(seed, unsafe { _rdtsc() });
+	}
+
+	seed
 }
 
 #[cfg(feature = "std")]
@@ -49,7 +284,7 @@ pub fn emergency_system_time_entropy(amt: usize) -> Vec<u8> {
 			* core::mem::size_of::<u128>() as f32)
 			.round() as usize
 	];
-	let time_amt = ((amt + core::mem::size_of::<u128>() - 1) / core::mem::size_of::<u128>()).max(0);
+	let time_amt = (amt + core::mem::size_of::<u128>() - 1) / core::mem::size_of::<u128>();
 	for n in 0..time_amt {
 		let time = SystemTime::now()
 			.duration_since(UNIX_EPOCH)
@@ -118,7 +353,7 @@ pub fn rdseed_entropy(amt: usize) -> Option<Result<Vec<u8>, Vec<u8>>> {
 			* core::mem::size_of::<u64>() as f32)
 			.round() as usize
 	];
-	let rdseed_amt = ((amt + core::mem::size_of::<u64>() - 1) / core::mem::size_of::<u64>()).max(0);
+	let rdseed_amt = (amt + core::mem::size_of::<u64>() - 1) / core::mem::size_of::<u64>();
 	for n in 0..rdseed_amt {
 		let seed = match stupid_rdseed_hack() {
 			Some(s) => s,
@@ -152,8 +387,8 @@ pub fn rdseed_entropy(_amt: usize) -> Option<Result<Vec<u8>, Vec<u8>>> {
 pub fn backup_entropy(amt: usize) -> Vec<u8> {
 	let mut entropy = vec![42_u8; amt];
 
-	match rdseed_entropy(amt) {
-		Some(rdseed) => match rdseed {
+	if let Some(rdseed) = rdseed_entropy(amt) {
+		match rdseed {
 			Ok(o) => return o,
 			Err(x) => {
 				let len = x.len();
@@ -161,9 +396,8 @@ pub fn backup_entropy(amt: usize) -> Vec<u8> {
 					.enumerate()
 					.for_each(|(i, val)| entropy[i % len] ^= *val);
 			}
-		},
-		None => {}
-	};
+		}
+	}
 
 	let time_entropy = emergency_system_time_entropy(amt);
 	let time_len = time_entropy.len();
@@ -0,0 +1,22 @@
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use super::EntropyError;
+
+static FETCHES: AtomicUsize = AtomicUsize::new(0);
+
+/// Fails, or gives the same bytes every time, as chosen by the `NANORAND_TEST_ENTROPY` environment variable,
+/// which must be `fail` or `constant`.
+pub(crate) fn try_entropy_from_system(amt: usize) -> Result<Vec<u8>, EntropyError> {
+	FETCHES.fetch_add(1, Ordering::SeqCst);
+	match std::env::var("NANORAND_TEST_ENTROPY").as_deref() {
+		Ok("fail") => Err(EntropyError::new("the failing test backend")),
+		Ok("constant") => Ok(alloc::vec![42; amt]),
+		_ => panic!("Set NANORAND_TEST_ENTROPY to `fail` or `constant`, to choose how the test entropy source behaves!"),
+	}
+}
+
+/// How many times entropy has been fetched from this backend, so tests can check how often generators hit the system.
+pub fn fetches() -> usize {
+	FETCHES.load(Ordering::SeqCst)
+}
@@ -1,37 +1,38 @@
 use std::fs::File;
-use std::io::{BufReader, Read};
+use std::io::Read;
 
-use super::backup_entropy;
+use super::{wipe, EntropyError};
 
 /// Obtain a series of random bytes.  
 /// Sources (in order of priority):  
 ///  1. `/dev/urandom`  
 ///  2. `/dev/random`  
-///  3. rdseed (if available)
-///  4. The emergency time-based entropy source.
+///
+/// Panics if neither can be read, see [`try_entropy_from_system`] for the fallible version.
 pub fn entropy_from_system(amt: usize) -> Vec<u8> {
-	match File::open("/dev/urandom") {
-		Ok(fd) => {
-			let mut entropy: Vec<u8> = vec![42; amt];
-			let mut reader = BufReader::new(fd);
-			match reader.read_exact(&mut entropy[..amt]) {
-				Ok(_) => entropy,
-				Err(_) => backup_entropy(amt),
-			}
-		}
-		Err(_) => {
-			// Ugh, let's try for /dev/random
-			match File::open("/dev/random") {
-				Ok(fd) => {
-					let mut entropy: Vec<u8> = vec![42; amt];
-					let mut reader = BufReader::new(fd);
-					match reader.read_exact(&mut entropy[..amt]) {
-						Ok(_) => entropy,
-						Err(_) => backup_entropy(amt),
-					}
-				}
-				Err(_) => backup_entropy(amt),
+	match try_entropy_from_system(amt) {
+		Ok(entropy) => entropy,
+		Err(err) => panic!("Couldn't seed from system entropy: {}", err),
+	}
+}
+
+/// Obtain a series of random bytes, like [`entropy_from_system`].  
+/// Returns an error naming `/dev/urandom` if neither it nor `/dev/random` can be read.
+pub fn try_entropy_from_system(amt: usize) -> Result<Vec<u8>, EntropyError> {
+	// This reads straight into the output, as a buffered reader would leave extra entropy behind in its own buffer.
+	let read = |path: &str| -> Option<Vec<u8>> {
+		let mut entropy: Vec<u8> = vec![42; amt];
+		match File::open(path).and_then(|mut fd| fd.read_exact(&mut entropy)) {
+			Ok(()) => Some(entropy),
+			Err(_) => {
+				// A failed read may still have written some of the entropy.
+				wipe(&mut entropy);
+				None
 			}
 		}
-	}
+	};
+	// Ugh, if /dev/urandom doesn't work, let's try for /dev/random
+	read("/dev/urandom")
+		.or_else(|| read("/dev/random"))
+		.ok_or(EntropyError::new("/dev/urandom"))
 }
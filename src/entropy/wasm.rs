@@ -0,0 +1,47 @@
+use alloc::vec::Vec;
+use js_sys::{Reflect, Uint8Array};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::Crypto;
+
+use super::{wipe, EntropyError};
+
+/// The most bytes `crypto.getRandomValues` will fill in a single call.
+const MAX_REQUEST: usize = 65536;
+
+/// Obtain a series of random bytes from the Web Crypto API's `crypto.getRandomValues`,
+/// which is available in browsers, web workers, Deno, and recent versions of Node.js.  
+/// Panics if the API isn't available, as there's no other source of entropy to fall back on, see [`try_entropy_from_system`] for the fallible version.
+pub fn entropy_from_system(amt: usize) -> Vec<u8> {
+	match try_entropy_from_system(amt) {
+		Ok(entropy) => entropy,
+		Err(err) => panic!("Couldn't seed from system entropy: {}", err),
+	}
+}
+
+/// Obtain a series of random bytes from the Web Crypto API's `crypto.getRandomValues`, like [`entropy_from_system`].  
+/// Returns an error if the API isn't available.
+pub fn try_entropy_from_system(amt: usize) -> Result<Vec<u8>, EntropyError> {
+	let error = EntropyError::new("crypto.getRandomValues");
+	let crypto: Crypto = Reflect::get(&js_sys::global(), &JsValue::from_str("crypto"))
+		.ok()
+		.filter(JsValue::is_object)
+		.map(JsCast::unchecked_into)
+		.ok_or(error)?;
+	let mut entropy: Vec<u8> = vec![42; amt];
+	for idx in (0..amt).step_by(MAX_REQUEST) {
+		let chunk = &mut entropy[idx..amt.min(idx + MAX_REQUEST)];
+		// getRandomValues can't always write into WebAssembly memory directly, so this goes through a JavaScript buffer.
+		let buffer = Uint8Array::new_with_length(chunk.len() as u32);
+		if crypto
+			.get_random_values_with_array_buffer_view(&buffer)
+			.is_err()
+		{
+			wipe(&mut entropy);
+			return Err(error);
+		}
+		buffer.copy_to(chunk);
+		#[cfg(feature = "zeroize")]
+		buffer.fill(0, 0, chunk.len() as u32);
+	}
+	Ok(entropy)
+}
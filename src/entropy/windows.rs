@@ -1,17 +1,29 @@
-use super::backup_entropy;
+use super::{wipe, EntropyError};
 
 extern "system" {
 	#[link_name = "SystemFunction036"]
 	fn RtlGenRandom(pBuffer: *mut u8, cbBuffer: usize) -> u32;
 }
 
-/// Obtain a random 64-bit number using WinAPI's `RtlGenRandom` function.
+/// Obtain a series of random bytes using WinAPI's `RtlGenRandom` function.  
+/// Panics if it fails, see [`try_entropy_from_system`] for the fallible version.
 pub fn entropy_from_system(amt: usize) -> Vec<u8> {
+	match try_entropy_from_system(amt) {
+		Ok(entropy) => entropy,
+		Err(err) => panic!("Couldn't seed from system entropy: {}", err),
+	}
+}
+
+/// Obtain a series of random bytes using WinAPI's `RtlGenRandom` function, like [`entropy_from_system`].  
+/// Returns an error if it fails.
+pub fn try_entropy_from_system(amt: usize) -> Result<Vec<u8>, EntropyError> {
 	let mut entropy: Vec<u8> = vec![42; amt];
+	// Unlike most of WinAPI, this returns a BOOLEAN, so anything but 0 is a success.
 	let status: u32 = unsafe { RtlGenRandom(entropy.as_mut_ptr(), amt) };
-	if status == 0 {
-		entropy
+	if status != 0 {
+		Ok(entropy)
 	} else {
-		backup_entropy(amt)
+		wipe(&mut entropy);
+		Err(EntropyError::new("RtlGenRandom"))
 	}
 }
@@ -1,7 +1,7 @@
 use core::ffi::c_void;
 use core::ptr;
 
-use super::backup_entropy;
+use super::{wipe, EntropyError};
 
 const BCRYPT_USE_SYSTEM_PREFERRED_RNG: u32 = 0x00000002;
 
@@ -14,8 +14,18 @@ extern "system" {
 	) -> u32;
 }
 
-/// Obtain a random 64-bit number using WinAPI's `BCryptGenRandom` function.
+/// Obtain a series of random bytes using WinAPI's `BCryptGenRandom` function.  
+/// Panics if it fails, see [`try_entropy_from_system`] for the fallible version.
 pub fn entropy_from_system(amt: usize) -> Vec<u8> {
+	match try_entropy_from_system(amt) {
+		Ok(entropy) => entropy,
+		Err(err) => panic!("Couldn't seed from system entropy: {}", err),
+	}
+}
+
+/// Obtain a series of random bytes using WinAPI's `BCryptGenRandom` function, like [`entropy_from_system`].  
+/// Returns an error if it fails.
+pub fn try_entropy_from_system(amt: usize) -> Result<Vec<u8>, EntropyError> {
 	let mut entropy: Vec<u8> = vec![42; amt];
 	let status: u32 = unsafe {
 		BCryptGenRandom(
@@ -26,8 +36,9 @@ pub fn entropy_from_system(amt: usize) -> Vec<u8> {
 		)
 	};
 	if status == 0 {
-		entropy
+		Ok(entropy)
 	} else {
-		backup_entropy(amt)
+		wipe(&mut entropy);
+		Err(EntropyError::new("BCryptGenRandom"))
 	}
 }
@@ -1,147 +1,1016 @@
-use crate::RNG;
+use crate::{RandError, RNG};
 
 /// A trait used for generating a random object with an RNG,
 pub trait RandomGen<R: RNG> {
 	/// Return a random instance of the implementing type, from the specified RNG instance.
+	///
+	/// The integer types read exactly `size_of::<T>()` bytes with [`RNG::fill_bytes`], as little-endian.
+	/// With the default [`RNG::fill_bytes`], that takes as many outputs of [`RNG::rand`] as needed,
+	/// so a [`u128`] from a generator with 64-bit outputs uses two whole outputs,
+	/// and a [`u8`] uses the first byte of one output, throwing the rest away.
+	/// [`bool`] takes one byte, and [`char`] takes 4 bytes per attempt.
 	fn random(r: &mut R) -> Self;
+	/// Fill a slice with random instances of the implementing type, from the specified RNG instance.  
+	/// By default, this calls [`RandomGen::random`] for each element,
+	/// but the integer types override it to fill the slice's bytes in bulk.
+	fn random_fill(r: &mut R, dest: &mut [Self])
+	where
+		Self: Sized,
+	{
+		dest.iter_mut().for_each(|x| *x = Self::random(r));
+	}
+	/// Generate a [`Vec`](alloc::vec::Vec) of `len` random instances of the implementing type, from the specified RNG instance.  
+	/// By default, this calls [`RandomGen::random`] for each element, into a vector allocated once with the exact capacity,
+	/// so it gives the same values as calling [`RNG::generate`] `len` times.
+	/// Single-byte integers override it to fill the vector's bytes in bulk with [`RNG::fill_bytes`] instead,
+	/// since generating each one on its own throws away the rest of an output.
+	#[cfg(feature = "alloc")]
+	fn random_vec(r: &mut R, len: usize) -> alloc::vec::Vec<Self>
+	where
+		Self: Sized,
+	{
+		let mut ret = alloc::vec::Vec::with_capacity(len);
+		for _ in 0..len {
+			ret.push(Self::random(r));
+		}
+		ret
+	}
+}
+
+/// A trait used for generating a random object with an RNG that can fail, such as a hardware-backed one.  
+/// On success, each implementation gives the same value as [`RandomGen::random`] would have.
+pub trait TryRandomGen<R: RNG>: Sized {
+	/// Return a random instance of the implementing type, from the specified RNG instance,
+	/// or the first error from [`RNG::try_fill_bytes`].
+	fn try_random(r: &mut R) -> Result<Self, RandError>;
+}
+
+/// Generating the primitive types from random bytes, which may fail to arrive,
+/// so [`RandomGen`] and [`TryRandomGen`] share one implementation of each.
+trait FromRandomBytes: Sized {
+	fn from_random_bytes<E>(fill: &mut impl FnMut(&mut [u8]) -> Result<(), E>) -> Result<Self, E>;
+}
+
+/// Generate a primitive with [`RNG::fill_bytes`], which can't fail.
+fn from_fill_bytes<T: FromRandomBytes, R: RNG>(r: &mut R) -> T {
+	crate::rand::infallible(T::from_random_bytes(&mut |bytes: &mut [u8]| {
+		r.fill_bytes(bytes);
+		Ok(())
+	}))
+}
+
+macro_rules! from_random_bytes_int {
+	($(($unsigned:ty, $signed:ty)),*) => {
+		$(
+			impl FromRandomBytes for $unsigned {
+				fn from_random_bytes<E>(fill: &mut impl FnMut(&mut [u8]) -> Result<(), E>) -> Result<Self, E> {
+					let mut bytes = [0u8; core::mem::size_of::<$unsigned>()];
+					fill(&mut bytes)?;
+					Ok(Self::from_le_bytes(bytes))
+				}
+			}
+
+			impl FromRandomBytes for $signed {
+				fn from_random_bytes<E>(fill: &mut impl FnMut(&mut [u8]) -> Result<(), E>) -> Result<Self, E> {
+					Ok(<$unsigned>::from_random_bytes(fill)? as $signed)
+				}
+			}
+		)*
+	};
+}
+
+from_random_bytes_int!(
+	(u8, i8),
+	(u16, i16),
+	(u32, i32),
+	(u64, i64),
+	(u128, i128),
+	(usize, isize)
+);
+
+impl FromRandomBytes for bool {
+	fn from_random_bytes<E>(fill: &mut impl FnMut(&mut [u8]) -> Result<(), E>) -> Result<Self, E> {
+		Ok(u8::from_random_bytes(fill)? < 0b10000000)
+	}
+}
+
+impl FromRandomBytes for char {
+	fn from_random_bytes<E>(fill: &mut impl FnMut(&mut [u8]) -> Result<(), E>) -> Result<Self, E> {
+		loop {
+			if let Some(c) = core::char::from_u32(u32::from_random_bytes(fill)?) {
+				break Ok(c);
+			}
+		}
+	}
+}
+
+macro_rules! try_randomgen {
+	($($ty:ty),*) => {
+		$(
+			impl<R: RNG> TryRandomGen<R> for $ty {
+				fn try_random(r: &mut R) -> Result<Self, RandError> {
+					Self::from_random_bytes(&mut |bytes: &mut [u8]| r.try_fill_bytes(bytes))
+				}
+			}
+		)*
+	};
+}
+
+try_randomgen!(
+	u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize, bool, char, f32, f64
+);
+
+macro_rules! random_fill_impl {
+	($ty:ty) => {
+		/// Fills the slice as if its bytes were filled with [`RNG::fill_bytes`], and read as little-endian integers.
+		fn random_fill(r: &mut R, dest: &mut [$ty]) {
+			const SIZE: usize = core::mem::size_of::<$ty>();
+			// A multiple of every generator's output size, so chunking doesn't change which bytes are used.
+			let mut buf = [0u8; 256];
+			for chunk in dest.chunks_mut(buf.len() / SIZE) {
+				let bytes = &mut buf[..chunk.len() * SIZE];
+				r.fill_bytes(bytes);
+				chunk
+					.iter_mut()
+					.zip(bytes.chunks_exact(SIZE))
+					.for_each(|(n, b)| {
+						let mut le = [0u8; SIZE];
+						le.copy_from_slice(b);
+						*n = <$ty>::from_le_bytes(le);
+					});
+			}
+		}
+
+		#[cfg(feature = "alloc")]
+		fn random_vec(r: &mut R, len: usize) -> alloc::vec::Vec<$ty> {
+			if core::mem::size_of::<$ty>() == 1 {
+				let mut ret = alloc::vec![0 as $ty; len];
+				Self::random_fill(r, &mut ret);
+				ret
+			} else {
+				(0..len).map(|_| Self::random(r)).collect()
+			}
+		}
+	};
 }
 
 /// A trait used for generating a random number within a range, with an RNG,
-pub trait RandomRange<R: RNG>: RandomGen<R> {
+pub trait RandomRange<R: RNG>: RandomGen<R> + Sized {
+	/// The smallest value of the implementing type, used for ranges without a start bound.  
+	/// This is `None` by default, in which case [`RNG::generate_range`] panics on a range without a start bound.
+	const MIN: Option<Self> = None;
+	/// The largest value of the implementing type, used for ranges without an end bound.  
+	/// This is `None` by default, in which case [`RNG::generate_range`] panics on a range without an end bound.
+	const MAX: Option<Self> = None;
+
 	/// Return a ranged number of the implementing type, from the specified RNG instance.
+	/// The range is `lower..upper`, meaning `lower` is included and `upper` is excluded.
+	///
+	/// If `lower == upper`, the range is empty, and `lower` is returned without consuming any entropy.  
+	/// If `lower > upper`, that's most likely a mistake, so this panics in debug builds.
+	/// Release builds don't check, and instead swap the bounds, returning a number within `upper..lower`.
 	fn random_range(r: &mut R, lower: Self, upper: Self) -> Self;
+	/// Return a ranged number of the implementing type, from the specified RNG instance.
+	/// The range is `lower..=upper`, meaning both `lower` and `upper` are included,
+	/// so `upper` may be the maximum value of the type.
+	///
+	/// By default, this is [`RandomRange::random_range`] over `lower..upper + 1`, using [`RandomRange::successor`].
+	/// When `upper` is the maximum value, it's `lower - 1..upper` shifted up by one instead, using [`RandomRange::predecessor`],
+	/// and when the range is the whole type, it's [`RandomGen::random`].
+	/// Types that implement neither step must override this.
+	///
+	/// If `lower > upper`, that's most likely a mistake, so this panics in debug builds, unless the range is empty, like `1..=0`.
+	/// Release builds don't check, and return a number between the two bounds.
+	fn random_range_inclusive(r: &mut R, lower: Self, upper: Self) -> Self {
+		match upper.successor() {
+			Some(after) => Self::random_range(r, lower, after),
+			None => match lower.predecessor() {
+				Some(before) => Self::random_range(r, before, upper)
+					.successor()
+					.expect("Only the maximum value has no successor"),
+				None => Self::random(r),
+			},
+		}
+	}
+	/// The value right above this one, or `None` if this is the maximum value of the type.  
+	/// This is `None` by default, and only used by the default [`RandomRange::random_range_inclusive`].
+	fn successor(&self) -> Option<Self> {
+		None
+	}
+	/// The value right below this one, or `None` if this is the minimum value of the type.  
+	/// This is `None` by default, and only used by the default [`RandomRange::random_range_inclusive`].
+	fn predecessor(&self) -> Option<Self> {
+		None
+	}
+	/// Return a ranged number of the implementing type within `lower..upper`, which is never equal to `except`.
+	///
+	/// The integer types, [`char`], [`Duration`](core::time::Duration), and timestamps sample a range one smaller than `lower..upper`
+	/// and step past `except`, and panic if `except` is the only value within the range, as do floats.  
+	/// Other types fall back to rejecting `except` until something else comes up. They panic if `except` is the only value within the range
+	/// when they implement [`RandomRange::successor`], and must override this otherwise, or it never returns for such a range.
+	///
+	/// An empty range gives `lower`, as with [`RandomRange::random_range`], so this panics if `lower == upper == except`.
+	fn random_range_except(r: &mut R, lower: Self, upper: Self, except: Self) -> Self
+	where
+		Self: PartialEq + Copy,
+	{
+		// A range which holds a single value always gives it, so excluding it leaves nothing.
+		let only = if lower == upper || lower.successor() == Some(upper) {
+			Some(lower)
+		} else if upper.successor() == Some(lower) {
+			// Release builds swap inverted bounds.
+			Some(upper)
+		} else {
+			None
+		};
+		if only == Some(except) {
+			panic!("There is nothing but the excluded value within the range!");
+		}
+		loop {
+			let ret = Self::random_range(r, lower, upper);
+			if ret != except {
+				break ret;
+			}
+		}
+	}
+	/// Fill a slice with ranged numbers of the implementing type within `lower..upper`, from the specified RNG instance,
+	/// each with the same distribution as [`RandomRange::random_range`].  
+	/// By default, this calls [`RandomRange::random_range`] for each element,
+	/// but the unsigned integer types override it to use every part of each output of the RNG.
+	fn random_range_fill(r: &mut R, dest: &mut [Self], lower: Self, upper: Self)
+	where
+		Self: Copy,
+	{
+		dest.iter_mut()
+			.for_each(|x| *x = Self::random_range(r, lower, upper));
+	}
 }
 
-impl<R: RNG> RandomGen<R> for char {
-	fn random(r: &mut R) -> Self {
-		loop {
-			let generated = r.rand();
-			let mut bytes = [0u8; core::mem::size_of::<u32>()];
-			bytes
-				.iter_mut()
-				.zip(generated.as_ref())
-				.for_each(|(a, b)| *a = *b);
-			if let Some(c) = core::char::from_u32(u32::from_ne_bytes(bytes)) {
-				break c;
+/// A trait used for generating a random number within a range in constant time, with an RNG.
+///
+/// [`RandomRange::random_range`] rejects and redraws some values to stay perfectly uniform,
+/// so the time it takes leaks information that's correlated with the value it returns.
+/// This always draws 64 random bits for [`u8`], [`u16`] and [`u32`], and 128 for [`u64`], and does a widening multiply,
+/// with no data-dependent branches or loops.  
+/// The tradeoff is a tiny bias: a value within a range of size `n` is at most `n / 2^64` more likely than another
+/// for types up to [`u32`], and at most `n / 2^128` for [`u64`].
+///
+/// The bounds themselves are assumed to be public; only the returned value is treated as secret.
+pub trait RandomRangeCt<R: RNG>: RandomRange<R> {
+	/// Return a ranged number within `lower..upper`, in constant time.
+	/// Empty and inverted ranges are handled the same way as [`RandomRange::random_range`].
+	fn random_range_ct(r: &mut R, lower: Self, upper: Self) -> Self;
+}
+
+macro_rules! randomrange_ct_number {
+	($($ty:ty),*) => {
+		$(
+			impl<R: RNG> RandomRangeCt<R> for $ty {
+				fn random_range_ct(r: &mut R, lower: $ty, upper: $ty) -> Self {
+					debug_assert_range!(lower, upper);
+					let (lower, upper) = (lower.min(upper), lower.max(upper));
+					let range = (upper - lower) as u128;
+					let x = u64::random(r) as u128;
+					lower + ((x * range) >> 64) as $ty
+				}
+			}
+		)*
+	};
+}
+
+randomrange_ct_number!(u8, u16, u32);
+
+impl<R: RNG> RandomRangeCt<R> for u64 {
+	fn random_range_ct(r: &mut R, lower: u64, upper: u64) -> Self {
+		debug_assert_range!(lower, upper);
+		let (lower, upper) = (lower.min(upper), lower.max(upper));
+		let range = (upper - lower) as u128;
+		let x = u128::random(r);
+		// floor(x * range / 2^128), computed in 64-bit limbs so nothing overflows.
+		let high = (x >> 64) * range;
+		let low = ((x as u64 as u128) * range) >> 64;
+		lower + ((high + low) >> 64) as u64
+	}
+}
+
+/// An integer type that can be counted through by offsets from a lower bound, for sampling distinct values from a range,
+/// as [`RNG::sample_range_unique`] does.
+pub trait RangeOffset: Copy {
+	/// How far above `lower` this is, which must be at least `lower`.
+	fn offset_from(self, lower: Self) -> u128;
+	/// The value `offset` above this one, which must fit within the type.
+	fn add_offset(self, offset: u128) -> Self;
+}
+
+macro_rules! range_offset_number {
+	($(($unsigned:ty, $signed:ty)),*) => {
+		$(
+			impl RangeOffset for $unsigned {
+				fn offset_from(self, lower: Self) -> u128 {
+					self.wrapping_sub(lower) as u128
+				}
+
+				fn add_offset(self, offset: u128) -> Self {
+					self.wrapping_add(offset as $unsigned)
+				}
+			}
+
+			impl RangeOffset for $signed {
+				fn offset_from(self, lower: Self) -> u128 {
+					self.wrapping_sub(lower) as $unsigned as u128
+				}
+
+				fn add_offset(self, offset: u128) -> Self {
+					self.wrapping_add(offset as $unsigned as $signed)
+				}
+			}
+		)*
+	};
+}
+
+range_offset_number!(
+	(u8, i8),
+	(u16, i16),
+	(u32, i32),
+	(u64, i64),
+	(u128, i128),
+	(usize, isize)
+);
+
+macro_rules! random_range_except_impl {
+	($ty:ty) => {
+		fn random_range_except(r: &mut R, lower: $ty, upper: $ty, except: $ty) -> Self {
+			debug_assert_range!(lower, upper);
+			let (lower, upper) = if lower > upper {
+				(upper, lower)
+			} else {
+				(lower, upper)
+			};
+			// An empty range gives `lower`, so that can't be the excluded value either.
+			if lower == upper && except == lower {
+				panic!("There is nothing but the excluded value within the range!");
+			}
+			if except < lower || except >= upper {
+				return Self::random_range(r, lower, upper);
+			}
+			if lower + 1 == upper {
+				panic!("There is nothing but the excluded value within the range!");
+			}
+			let ret = Self::random_range(r, lower, upper - 1);
+			if ret >= except {
+				ret + 1
+			} else {
+				ret
 			}
 		}
+	};
+}
+
+impl<R: RNG> RandomGen<R> for char {
+	fn random(r: &mut R) -> Self {
+		from_fill_bytes(r)
 	}
 }
 
 impl<R: RNG> RandomGen<R> for bool {
 	fn random(r: &mut R) -> bool {
-		r.rand().as_ref()[0] < 0b10000000
+		from_fill_bytes(r)
 	}
 }
 
-impl<R: RNG> RandomGen<R> for u64 {
-	fn random(r: &mut R) -> Self {
-		let generated = r.rand();
-		let mut bytes = [0u8; core::mem::size_of::<u64>()];
-		bytes
-			.iter_mut()
-			.zip(generated.as_ref())
-			.for_each(|(a, b)| *a = *b);
-		Self::from_le_bytes(bytes)
-	}
+/// Lemire's method for a range of size `range` above `lower`, as a closure turning a random candidate
+/// into a ranged value, or [`None`] if it has to be rejected to stay unbiased.
+macro_rules! lemire_accept {
+	($unsigned:ty, $bigger_unsigned:ty, $lower:expr, $range:expr) => {{
+		const BIT_SIZE: usize = core::mem::size_of::<$unsigned>() * 8;
+		let (lower, range): ($unsigned, $unsigned) = ($lower, $range);
+		let t = range.wrapping_neg() % range;
+		move |x: $unsigned| {
+			let (high, low) = if BIT_SIZE == 64 && range as u64 <= u32::MAX as u64 {
+				let (high, low) = mul_u64_by_u32(x as u64, range as u32);
+				(high as $unsigned, low as $unsigned)
+			} else {
+				let m = (x as $bigger_unsigned) * (range as $bigger_unsigned);
+				((m >> BIT_SIZE) as $unsigned, m as $unsigned)
+			};
+			// `high` is always below `range`, so this stays below `upper`, and can't overflow,
+			// even when `upper` is close to the type's maximum.
+			if low >= t {
+				Some(lower + high)
+			} else {
+				None
+			}
+		}
+	}};
 }
 
-impl<R: RNG> RandomRange<R> for u64 {
-	fn random_range(r: &mut R, lower: u64, upper: u64) -> Self {
-		let t = ((-(upper as i64)) % (upper as i64)) as u64;
-		let in_range = loop {
-			let x = Self::random(r);
-			let m = (x as u128).wrapping_mul(upper as u128);
-			if (m as u64) >= t {
-				break (m >> 64) as u64;
+macro_rules! randomgen_number {
+	($(($unsigned:ty, $signed:ty, $bigger_unsigned:ty, $bigger_signed:ty)),*) => {
+		$(
+			// The range math below multiplies into the bigger types, and needs the full high half.
+			const _: () = assert!(
+				core::mem::size_of::<$bigger_unsigned>() >= 2 * core::mem::size_of::<$unsigned>()
+					&& core::mem::size_of::<$bigger_signed>() >= 2 * core::mem::size_of::<$signed>()
+			);
+
+			impl<R: RNG> RandomGen<R> for $unsigned {
+				fn random(r: &mut R) -> Self {
+					from_fill_bytes(r)
+				}
+
+				random_fill_impl!($unsigned);
 			}
-		};
-		in_range.max(lower)
+
+			impl<R: RNG> RandomGen<R> for $signed {
+				fn random(r: &mut R) -> Self {
+					from_fill_bytes(r)
+				}
+
+				random_fill_impl!($signed);
+			}
+
+			impl<R: RNG> RandomRange<R> for $unsigned {
+				const MIN: Option<Self> = Some(<$unsigned>::MIN);
+				const MAX: Option<Self> = Some(<$unsigned>::MAX);
+
+				fn random_range(r: &mut R, lower: $unsigned, upper: $unsigned) -> Self {
+					debug_assert_range!(lower, upper);
+					const SIZE: usize = core::mem::size_of::<$unsigned>();
+					const BIT_SIZE: usize = SIZE * 8;
+					let (lower, upper) = if lower > upper {
+						(upper, lower)
+					} else {
+						(lower, upper)
+					};
+					let range = upper - lower;
+					if range == 0 {
+						return lower;
+					}
+					if range.is_power_of_two() {
+						// Lemire's method never rejects here, and just returns the top bits.
+						let x = Self::random(r);
+						return lower + x.checked_shr(BIT_SIZE as u32 - range.trailing_zeros()).unwrap_or(0);
+					}
+					let accept = lemire_accept!($unsigned, $bigger_unsigned, lower, range);
+					loop {
+						if R::OUTPUT_SIZE < SIZE {
+							// An output is too small to hold a candidate, so each one is filled from several outputs.
+							if let Some(ret) = accept(from_fill_bytes(r)) {
+								return ret;
+							}
+							continue;
+						}
+						// Each output may hold several candidates for the smaller types,
+						// so try all of them before pulling in another one.
+						for chunk in r.rand().as_ref().chunks_exact(SIZE) {
+							let mut bytes = [0u8; SIZE];
+							bytes.copy_from_slice(chunk);
+							if let Some(ret) = accept(<$unsigned>::from_le_bytes(bytes)) {
+								return ret;
+							}
+						}
+					}
+				}
+
+				random_range_except_impl!($unsigned);
+
+				/// Fills the slice with candidates from every part of each output, rather than one output per element,
+				/// so filling [`u8`]s from a 64-bit generator takes about an eighth of the outputs.
+				fn random_range_fill(r: &mut R, dest: &mut [$unsigned], lower: $unsigned, upper: $unsigned) {
+					debug_assert_range!(lower, upper);
+					const SIZE: usize = core::mem::size_of::<$unsigned>();
+					let (lower, upper) = if lower > upper {
+						(upper, lower)
+					} else {
+						(lower, upper)
+					};
+					let range = upper - lower;
+					if range == 0 || R::OUTPUT_SIZE < SIZE {
+						dest.iter_mut().for_each(|x| *x = Self::random_range(r, lower, upper));
+						return;
+					}
+					let accept = lemire_accept!($unsigned, $bigger_unsigned, lower, range);
+					let mut filled = 0;
+					while filled < dest.len() {
+						let generated = r.rand();
+						let candidates = generated.as_ref().chunks_exact(SIZE).filter_map(|chunk| {
+							let mut bytes = [0u8; SIZE];
+							bytes.copy_from_slice(chunk);
+							accept(<$unsigned>::from_le_bytes(bytes))
+						});
+						for (slot, ret) in dest[filled..].iter_mut().zip(candidates) {
+							*slot = ret;
+							filled += 1;
+						}
+					}
+				}
+
+				fn successor(&self) -> Option<Self> {
+					self.checked_add(1)
+				}
+
+				fn predecessor(&self) -> Option<Self> {
+					self.checked_sub(1)
+				}
+			}
+
+			impl<R: RNG> RandomRange<R> for $signed {
+				const MIN: Option<Self> = Some(<$signed>::MIN);
+				const MAX: Option<Self> = Some(<$signed>::MAX);
+
+				fn random_range(r: &mut R, lower: $signed, upper: $signed) -> Self {
+					debug_assert_range!(lower, upper);
+					let (lower, upper) = if lower > upper {
+						(upper, lower)
+					} else {
+						(lower, upper)
+					};
+					let range = (upper as $bigger_signed - lower as $bigger_signed) as $unsigned;
+					let offset = <$unsigned>::random_range(r, 0, range);
+					(lower as $bigger_signed + offset as $bigger_signed) as $signed
+				}
+
+				random_range_except_impl!($signed);
+
+				fn successor(&self) -> Option<Self> {
+					self.checked_add(1)
+				}
+
+				fn predecessor(&self) -> Option<Self> {
+					self.checked_sub(1)
+				}
+			}
+		)*
 	}
 }
 
+randomgen_number!(
+	(u8, i8, u16, i16),
+	(u16, i16, u32, i32),
+	(u32, i32, u64, i64),
+	(u64, i64, u128, i128)
+);
+
 #[cfg(target_pointer_width = "64")]
-impl<R: RNG> RandomGen<R> for usize {
+randomgen_number!((usize, isize, u128, i128));
+#[cfg(target_pointer_width = "32")]
+randomgen_number!((usize, isize, u64, i64));
+#[cfg(target_pointer_width = "16")]
+randomgen_number!((usize, isize, u32, i32));
+
+/// Multiplies a [`u64`] by a [`u32`] into a 128-bit result, returned as `(high, low)`.  
+/// This is the same as multiplying within [`u128`], but only needs two 32x32 to 64-bit multiplies,
+/// which 32-bit targets have natively, instead of an emulated 128-bit multiply.
+#[inline]
+fn mul_u64_by_u32(x: u64, range: u32) -> (u64, u64) {
+	let range = range as u64;
+	let high = (x >> 32) * range;
+	let low = (x & u32::MAX as u64) * range;
+	// This can't overflow, as `high` is at most (2^32 - 1)^2, and `low >> 32` is under 2^32 - 1.
+	let mid = high + (low >> 32);
+	(mid >> 32, (mid << 32) | (low & u32::MAX as u64))
+}
+
+/// Multiplies two [`u128`]s into a 256-bit result, returned as `(high, low)`.
+fn wide_mul_u128(a: u128, b: u128) -> (u128, u128) {
+	const MASK: u128 = u64::MAX as u128;
+	let (a_hi, a_lo) = (a >> 64, a & MASK);
+	let (b_hi, b_lo) = (b >> 64, b & MASK);
+	let ll = a_lo * b_lo;
+	let lh = a_lo * b_hi;
+	let hl = a_hi * b_lo;
+	let hh = a_hi * b_hi;
+	let mid = (ll >> 64) + (lh & MASK) + (hl & MASK);
+	let low = (ll & MASK) | (mid << 64);
+	let high = hh + (lh >> 64) + (hl >> 64) + (mid >> 64);
+	(high, low)
+}
+
+impl<R: RNG> RandomGen<R> for u128 {
 	fn random(r: &mut R) -> Self {
-		r.generate::<u64>() as usize
+		from_fill_bytes(r)
 	}
+
+	random_fill_impl!(u128);
 }
-#[cfg(target_pointer_width = "64")]
-impl<R: RNG> RandomRange<R> for usize {
-	fn random_range(r: &mut R, lower: usize, upper: usize) -> Self {
-		r.generate_range::<u64>(lower as u64, upper as u64) as usize
+
+impl<R: RNG> RandomGen<R> for i128 {
+	fn random(r: &mut R) -> Self {
+		from_fill_bytes(r)
 	}
+
+	random_fill_impl!(i128);
 }
 
-#[cfg(target_pointer_width = "32")]
-impl<R: RNG> RandomGen<R> for usize {
-	fn random(r: &mut R) -> Self {
-		r.generate::<u32>() as usize
+impl<R: RNG> RandomRange<R> for u128 {
+	const MIN: Option<Self> = Some(u128::MIN);
+	const MAX: Option<Self> = Some(u128::MAX);
+
+	fn random_range(r: &mut R, lower: u128, upper: u128) -> Self {
+		debug_assert_range!(lower, upper);
+		let (lower, upper) = if lower > upper {
+			(upper, lower)
+		} else {
+			(lower, upper)
+		};
+		let range = upper - lower;
+		if range == 0 {
+			return lower;
+		}
+		// There's no wider type to multiply into, so this is Lemire's method over a 256-bit product.
+		let t = range.wrapping_neg() % range;
+		loop {
+			let (high, low) = wide_mul_u128(Self::random(r), range);
+			// `high` is always below `range`, so this can't overflow, as above.
+			if low >= t {
+				break lower + high;
+			}
+		}
+	}
+
+	random_range_except_impl!(u128);
+
+	fn successor(&self) -> Option<Self> {
+		self.checked_add(1)
+	}
+
+	fn predecessor(&self) -> Option<Self> {
+		self.checked_sub(1)
 	}
 }
-#[cfg(target_pointer_width = "32")]
-impl<R: RNG> RandomRange<R> for usize {
-	fn random_range(r: &mut R, lower: usize, upper: usize) -> Self {
-		r.generate_range::<u32>(lower as u32, upper as u32) as usize
+
+impl<R: RNG> RandomRange<R> for i128 {
+	const MIN: Option<Self> = Some(i128::MIN);
+	const MAX: Option<Self> = Some(i128::MAX);
+
+	fn random_range(r: &mut R, lower: i128, upper: i128) -> Self {
+		debug_assert_range!(lower, upper);
+		let (lower, upper) = if lower > upper {
+			(upper, lower)
+		} else {
+			(lower, upper)
+		};
+		let range = upper.wrapping_sub(lower) as u128;
+		let offset = u128::random_range(r, 0, range);
+		lower.wrapping_add(offset as i128)
+	}
+
+	random_range_except_impl!(i128);
+
+	fn successor(&self) -> Option<Self> {
+		self.checked_add(1)
+	}
+
+	fn predecessor(&self) -> Option<Self> {
+		self.checked_sub(1)
 	}
 }
 
-#[cfg(target_pointer_width = "16")]
-impl<R: RNG> RandomGen<R> for usize {
-	fn random(r: &mut R) -> Self {
-		r.generate::<u16>() as usize
+macro_rules! randomgen_float {
+	($(($float:ty, $bits:ty, $mantissa:expr)),*) => {
+		$(
+			impl FromRandomBytes for $float {
+				fn from_random_bytes<E>(fill: &mut impl FnMut(&mut [u8]) -> Result<(), E>) -> Result<Self, E> {
+					const SHIFT: u32 = (core::mem::size_of::<$bits>() * 8) as u32 - $mantissa;
+					Ok((<$bits>::from_random_bytes(fill)? >> SHIFT) as $float / (1 as $bits << $mantissa) as $float)
+				}
+			}
+
+			/// Generates a float within `0.0..1.0`, from the top mantissa-sized bits of a random integer.
+			impl<R: RNG> RandomGen<R> for $float {
+				fn random(r: &mut R) -> Self {
+					from_fill_bytes(r)
+				}
+
+				fn random_fill(r: &mut R, dest: &mut [$float]) {
+					const SHIFT: u32 = (core::mem::size_of::<$bits>() * 8) as u32 - $mantissa;
+					let mut bits = [0 as $bits; 32];
+					for chunk in dest.chunks_mut(bits.len()) {
+						let bits = &mut bits[..chunk.len()];
+						<$bits>::random_fill(r, bits);
+						chunk
+							.iter_mut()
+							.zip(bits.iter())
+							.for_each(|(f, b)| *f = (b >> SHIFT) as $float / (1 as $bits << $mantissa) as $float);
+					}
+				}
+			}
+		)*
+	};
+}
+
+randomgen_float!((f32, u32, 24), (f64, u64, 53));
+
+macro_rules! randomrange_float {
+	($(($float:ty, $name:ident, $next_up:ident, $mantissa:expr)),*) => {
+		$(
+			/// The next float above the finite `x`, as `next_up` gives on Rust 1.86 and later.
+			fn $next_up(x: $float) -> $float {
+				let bits = x.to_bits();
+				<$float>::from_bits(if x == 0.0 {
+					1
+				} else if x > 0.0 {
+					bits + 1
+				} else {
+					bits - 1
+				})
+			}
+
+			#[doc = concat!("Return a [`", stringify!($float), "`] between `lower` and `upper`, choosing whether each of them can be returned.")]
+			///
+			/// The value is picked uniformly from evenly spaced points between the bounds, one for each possible mantissa,
+			/// and rounded to the nearest representable float.
+			/// Results that would break the requested inclusivity, which rounding can cause, are rejected and redrawn, rather than clamped.
+			/// That means an included endpoint is only ever returned when it's picked exactly,
+			/// which, unless the range is tiny, is about as likely as any other single value: around 1 in 2^mantissa bits.
+			///
+			/// If `lower == upper`, or there's no float strictly between them and both are excluded, `lower` is returned.
+			/// If `lower > upper`, this panics in debug builds, and swaps the bounds in release builds.
+			/// Panics if either bound is infinite or NaN.
+			/// ```rust
+			/// use nanorand::{WyRand, RNG};
+			#[doc = concat!("use nanorand::", stringify!($name), ";")]
+			///
+			/// let mut rng = WyRand::new();
+			#[doc = concat!("let x = ", stringify!($name), "(&mut rng, -1.0, 1.0, false, true);")]
+			/// assert!(x > -1.0 && x <= 1.0);
+			/// ```
+			pub fn $name<R: RNG>(
+				r: &mut R,
+				lower: $float,
+				upper: $float,
+				lower_inclusive: bool,
+				upper_inclusive: bool,
+			) -> $float {
+				assert!(lower.is_finite() && upper.is_finite(), "The bounds of a float range must be finite!");
+				debug_assert_range!(lower, upper);
+				let (lower, upper) = if lower > upper {
+					(upper, lower)
+				} else {
+					(lower, upper)
+				};
+				if lower == upper || (!lower_inclusive && !upper_inclusive && $next_up(lower) == upper) {
+					return lower;
+				}
+				const STEPS: u64 = 1 << $mantissa;
+				let span = upper - lower;
+				loop {
+					let step = if upper_inclusive {
+						u64::random_range_inclusive(r, 0, STEPS)
+					} else {
+						u64::random_range(r, 0, STEPS)
+					};
+					let x = step as $float / STEPS as $float;
+					let ret = if span.is_finite() {
+						lower + x * span
+					} else {
+						// The span overflowed, such as for `MIN..MAX`, so this interpolates without it.
+						lower * (1.0 - x) + upper * x
+					};
+					let in_bounds = (lower < ret || (lower_inclusive && lower == ret))
+						&& (ret < upper || (upper_inclusive && ret == upper));
+					if in_bounds {
+						break ret;
+					}
+				}
+			}
+
+			/// Ranged floats are sampled uniformly between the bounds; see
+			#[doc = concat!("[`", stringify!($name), "`]")]
+			/// for the details, and for ranges that exclude their lower bound.
+			/// Inclusive ranges include `upper` among the evenly spaced points, rather than stepping one float past it,
+			/// and `MIN..=MAX` isn't what [`RandomGen::random`] generates, so floats override [`RandomRange::random_range_inclusive`].
+			impl<R: RNG> RandomRange<R> for $float {
+				const MIN: Option<Self> = Some(<$float>::MIN);
+				const MAX: Option<Self> = Some(<$float>::MAX);
+
+				fn random_range(r: &mut R, lower: $float, upper: $float) -> Self {
+					$name(r, lower, upper, true, false)
+				}
+
+				fn random_range_inclusive(r: &mut R, lower: $float, upper: $float) -> Self {
+					$name(r, lower, upper, true, true)
+				}
+
+				fn random_range_except(r: &mut R, lower: $float, upper: $float, except: $float) -> Self {
+					let (low, high) = if lower > upper {
+						(upper, lower)
+					} else {
+						(lower, upper)
+					};
+					// Without a float between the bounds, `low` is the only value within the range.
+					if (low == high || $next_up(low) == high) && low == except {
+						panic!("There is nothing but the excluded value within the range!");
+					}
+					loop {
+						let ret = Self::random_range(r, lower, upper);
+						if ret != except {
+							break ret;
+						}
+					}
+				}
+			}
+		)*
+	};
+}
+
+randomrange_float!(
+	(f32, random_range_f32, next_up_f32, 24),
+	(f64, random_range_f64, next_up_f64, 53)
+);
+
+/// The first surrogate code point, which isn't a valid [`char`].
+const SURROGATE_START: u32 = 0xD800;
+/// How many surrogate code points there are, between `0xD800` and `0xDFFF`.
+const SURROGATE_COUNT: u32 = 0x800;
+
+/// Map a [`char`] to its index among valid chars, skipping over the surrogate gap.
+const fn char_to_index(c: char) -> u32 {
+	let c = c as u32;
+	if c >= SURROGATE_START {
+		c - SURROGATE_COUNT
+	} else {
+		c
 	}
 }
-#[cfg(target_pointer_width = "16")]
-impl<R: RNG> RandomRange<R> for usize {
-	fn random_range(r: &mut R, lower: usize, upper: usize) -> Self {
-		r.generate_range::<u16>(lower as u16, upper as u16) as usize
+
+/// Map an index among valid chars back to its [`char`].
+fn index_to_char(idx: u32) -> char {
+	let c = if idx >= SURROGATE_START {
+		idx + SURROGATE_COUNT
+	} else {
+		idx
+	};
+	// The index always comes from within a range of valid chars.
+	core::char::from_u32(c).unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+
+/// Ranged chars are sampled uniformly from the valid code points between the bounds.
+/// Ranges that span the surrogates (`'\u{D800}'..='\u{DFFF}'`) skip over them, rather than redrawing.
+impl<R: RNG> RandomRange<R> for char {
+	const MIN: Option<Self> = Some('\0');
+	const MAX: Option<Self> = Some(char::MAX);
+
+	fn random_range(r: &mut R, lower: char, upper: char) -> Self {
+		debug_assert_range!(lower, upper);
+		index_to_char(u32::random_range(
+			r,
+			char_to_index(lower),
+			char_to_index(upper),
+		))
+	}
+
+	fn successor(&self) -> Option<Self> {
+		if *self == char::MAX {
+			return None;
+		}
+		Some(index_to_char(char_to_index(*self) + 1))
+	}
+
+	fn predecessor(&self) -> Option<Self> {
+		char_to_index(*self).checked_sub(1).map(index_to_char)
+	}
+
+	fn random_range_except(r: &mut R, lower: char, upper: char, except: char) -> Self {
+		debug_assert_range!(lower, upper);
+		index_to_char(u32::random_range_except(
+			r,
+			char_to_index(lower),
+			char_to_index(upper),
+			char_to_index(except),
+		))
 	}
 }
 
-impl<R: RNG> RandomGen<R> for u32 {
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Generates a duration with random seconds, and nanoseconds within `0..1_000_000_000`.
+impl<R: RNG> RandomGen<R> for core::time::Duration {
 	fn random(r: &mut R) -> Self {
-		(r.generate::<u64>() >> 32) as u32
+		let secs = u64::random(r);
+		let nanos = u32::random_range(r, 0, NANOS_PER_SEC as u32);
+		core::time::Duration::new(secs, nanos)
 	}
 }
 
-impl<R: RNG> RandomRange<R> for u32 {
-	fn random_range(r: &mut R, lower: u32, upper: u32) -> Self {
-		(r.generate_range::<u64>(lower as u64, upper as u64) >> 32) as u32
+/// Ranged durations are sampled uniformly from the whole nanoseconds between the bounds.
+impl<R: RNG> RandomRange<R> for core::time::Duration {
+	const MIN: Option<Self> = Some(core::time::Duration::ZERO);
+	const MAX: Option<Self> = Some(core::time::Duration::MAX);
+
+	fn random_range(r: &mut R, lower: Self, upper: Self) -> Self {
+		debug_assert_range!(lower, upper);
+		duration_from_nanos(u128::random_range(r, lower.as_nanos(), upper.as_nanos()))
+	}
+
+	fn random_range_except(r: &mut R, lower: Self, upper: Self, except: Self) -> Self {
+		debug_assert_range!(lower, upper);
+		duration_from_nanos(u128::random_range_except(
+			r,
+			lower.as_nanos(),
+			upper.as_nanos(),
+			except.as_nanos(),
+		))
+	}
+
+	fn successor(&self) -> Option<Self> {
+		self.checked_add(core::time::Duration::from_nanos(1))
+	}
+
+	fn predecessor(&self) -> Option<Self> {
+		self.checked_sub(core::time::Duration::from_nanos(1))
 	}
 }
 
-impl<R: RNG> RandomGen<R> for u16 {
+/// Generates any IPv4 address, including reserved ones, such as loopback and multicast addresses.  
+/// See [`net::ipv4_in_subnet`](crate::net::ipv4_in_subnet) for generating addresses within a subnet.
+#[cfg(feature = "std")]
+impl<R: RNG> RandomGen<R> for std::net::Ipv4Addr {
 	fn random(r: &mut R) -> Self {
-		(r.generate::<u64>() >> 16) as u16
+		Self::from(u32::random(r))
 	}
 }
 
-impl<R: RNG> RandomRange<R> for u16 {
-	fn random_range(r: &mut R, lower: u16, upper: u16) -> Self {
-		(r.generate_range::<u64>(lower as u64, upper as u64) >> 16) as u16
+/// Generates any IPv6 address, including reserved ones, such as loopback and multicast addresses.  
+/// See [`net::ipv6_in_subnet`](crate::net::ipv6_in_subnet) for generating addresses within a subnet.
+#[cfg(feature = "std")]
+impl<R: RNG> RandomGen<R> for std::net::Ipv6Addr {
+	fn random(r: &mut R) -> Self {
+		Self::from(u128::random(r))
 	}
 }
 
-impl<R: RNG> RandomGen<R> for u8 {
+/// The inverse of [`Duration::as_nanos`](core::time::Duration::as_nanos), which only works for nanoseconds within [`Duration::MAX`](core::time::Duration::MAX).
+fn duration_from_nanos(nanos: u128) -> core::time::Duration {
+	core::time::Duration::new(
+		(nanos / NANOS_PER_SEC) as u64,
+		(nanos % NANOS_PER_SEC) as u32,
+	)
+}
+
+/// The Unix timestamp of `2100-01-01T00:00:00Z`, where random [`chrono::DateTime`]s end.
+#[cfg(feature = "chrono")]
+const YEAR_2100: i64 = 4_102_444_800;
+
+/// Generates a timestamp between the Unix epoch and `2100-01-01T00:00:00Z`, with random nanoseconds.  
+/// Use [`RNG::generate_range`] for timestamps within other bounds.
+#[cfg(feature = "chrono")]
+impl<R: RNG> RandomGen<R> for chrono::DateTime<chrono::Utc> {
 	fn random(r: &mut R) -> Self {
-		(r.generate::<u64>() >> 8) as u8
+		let secs = i64::random_range(r, 0, YEAR_2100);
+		let nanos = u32::random_range(r, 0, NANOS_PER_SEC as u32);
+		datetime_from_nanos(secs as i128 * NANOS_PER_SEC as i128 + nanos as i128)
 	}
 }
 
-impl<R: RNG> RandomRange<R> for u8 {
-	fn random_range(r: &mut R, lower: u8, upper: u8) -> Self {
-		(r.generate_range::<u64>(lower as u64, upper as u64) >> 8) as u8
+/// Ranged timestamps are sampled uniformly from the whole nanoseconds between the bounds,
+/// which may be anywhere from [`DateTime::MIN_UTC`](chrono::DateTime::MIN_UTC) to [`DateTime::MAX_UTC`](chrono::DateTime::MAX_UTC).  
+/// [`RandomGen::random`] only generates timestamps up to 2100, so this overrides [`RandomRange::random_range_inclusive`] to cover the rest.
+#[cfg(feature = "chrono")]
+impl<R: RNG> RandomRange<R> for chrono::DateTime<chrono::Utc> {
+	const MIN: Option<Self> = Some(chrono::DateTime::<chrono::Utc>::MIN_UTC);
+	const MAX: Option<Self> = Some(chrono::DateTime::<chrono::Utc>::MAX_UTC);
+
+	fn random_range(r: &mut R, lower: Self, upper: Self) -> Self {
+		debug_assert_range!(lower, upper);
+		datetime_from_nanos(i128::random_range(
+			r,
+			datetime_nanos(lower),
+			datetime_nanos(upper),
+		))
 	}
-}
 
-impl<R: RNG> RandomRange<R> for char {
-	fn random_range(r: &mut R, lower: char, upper: char) -> Self {
-		loop {
-			let ret = (r.generate_range::<u64>(lower as u64, upper as u64) >> 32) as u32;
-			if let Some(c) = core::char::from_u32(ret) {
-				break c;
-			}
-		}
+	fn random_range_inclusive(r: &mut R, lower: Self, upper: Self) -> Self {
+		debug_assert_range!(lower, upper);
+		datetime_from_nanos(i128::random_range_inclusive(
+			r,
+			datetime_nanos(lower),
+			datetime_nanos(upper),
+		))
 	}
+
+	fn random_range_except(r: &mut R, lower: Self, upper: Self, except: Self) -> Self {
+		debug_assert_range!(lower, upper);
+		datetime_from_nanos(i128::random_range_except(
+			r,
+			datetime_nanos(lower),
+			datetime_nanos(upper),
+			datetime_nanos(except),
+		))
+	}
+}
+
+/// The nanoseconds since the Unix epoch, which don't fit in an [`i64`] for every timestamp chrono supports.
+#[cfg(feature = "chrono")]
+fn datetime_nanos(datetime: chrono::DateTime<chrono::Utc>) -> i128 {
+	datetime.timestamp() as i128 * NANOS_PER_SEC as i128 + datetime.timestamp_subsec_nanos() as i128
+}
+
+/// The inverse of [`datetime_nanos`], which only works for nanoseconds between two valid timestamps.
+#[cfg(feature = "chrono")]
+fn datetime_from_nanos(nanos: i128) -> chrono::DateTime<chrono::Utc> {
+	let secs = nanos.div_euclid(NANOS_PER_SEC as i128) as i64;
+	let nanos = nanos.rem_euclid(NANOS_PER_SEC as i128) as u32;
+	chrono::DateTime::from_timestamp(secs, nanos)
+		.expect("Timestamps between two valid timestamps are valid")
 }
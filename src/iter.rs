@@ -0,0 +1,76 @@
+use crate::{RandomGen, RNG};
+use core::marker::PhantomData;
+
+/// An endless iterator of randoms of type `T`, borrowing an RNG.  
+/// Returned by [`RNG::iter`]; see [`IntoRandIter`] for an iterator that owns its RNG.
+/// ```rust
+/// use nanorand::{RNG, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let numbers: Vec<u64> = rng.iter().take(10).collect();
+/// assert_eq!(numbers.len(), 10);
+/// ```
+pub struct RandIter<'a, R: RNG, T: RandomGen<R>> {
+	rng: &'a mut R,
+	_marker: PhantomData<fn() -> T>,
+}
+
+impl<'a, R: RNG, T: RandomGen<R>> RandIter<'a, R, T> {
+	/// Create an iterator of randoms, borrowing the specified RNG.
+	pub fn new(rng: &'a mut R) -> Self {
+		Self {
+			rng,
+			_marker: PhantomData,
+		}
+	}
+}
+
+impl<R: RNG, T: RandomGen<R>> Iterator for RandIter<'_, R, T> {
+	type Item = T;
+
+	fn next(&mut self) -> Option<T> {
+		Some(T::random(self.rng))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(usize::MAX, None)
+	}
+}
+
+impl<R: RNG, T: RandomGen<R>> core::iter::FusedIterator for RandIter<'_, R, T> {}
+
+/// An endless iterator of randoms of type `T`, owning an RNG.  
+/// Returned by [`RNG::into_rand_iter`], and useful when the iterator needs to be `'static`.
+pub struct IntoRandIter<R: RNG, T: RandomGen<R>> {
+	rng: R,
+	_marker: PhantomData<fn() -> T>,
+}
+
+impl<R: RNG, T: RandomGen<R>> IntoRandIter<R, T> {
+	/// Create an iterator of randoms, taking ownership of the specified RNG.
+	pub fn new(rng: R) -> Self {
+		Self {
+			rng,
+			_marker: PhantomData,
+		}
+	}
+
+	/// Return the RNG this iterator generates from.
+	pub fn into_inner(self) -> R {
+		self.rng
+	}
+}
+
+impl<R: RNG, T: RandomGen<R>> Iterator for IntoRandIter<R, T> {
+	type Item = T;
+
+	fn next(&mut self) -> Option<T> {
+		Some(T::random(&mut self.rng))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(usize::MAX, None)
+	}
+}
+
+impl<R: RNG, T: RandomGen<R>> core::iter::FusedIterator for IntoRandIter<R, T> {}
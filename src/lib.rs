@@ -18,7 +18,7 @@
 //! use nanorand::RNG;
 //!
 //! fn main() {
-//!		let mut rng = nanorand::tls_rng();
+//!     let mut rng = nanorand::tls_rng();
 //!     println!("Random number: {}", rng.generate::<u64>());
 //! }
 //! ```
@@ -28,7 +28,7 @@
 //!
 //! fn main() {
 //!     let mut rng = WyRand::new();
-//!     println!("Random number between 1 and 100: {}", rng.generate_range::<u64>(1, 100));
+//!     println!("Random number between 1 and 100: {}", rng.generate_range(1_u64..=100));
 //! }
 //! ```
 //! ## Shuffling a Vec
@@ -54,41 +54,124 @@
 //! :-----:|:-----:|:-----:|:-----:|:-----:|:-----:|:-----:
 //! wyrand|[nanohash::WyRand](rand/wyrand/struct.WyRand.html)|64 bits (`u64`)|🚫|4 GB/s||https://github.com/lemire/testingRNG/blob/master/source/wyrand.h
 //! Pcg64|[nanohash::Pcg64](rand/pcg64/struct.Pcg64.html)|64 bits (`u64`)|🚫|1 GB/s||https://github.com/rkern/pcg64
-//! ChaCha|[nanohash::ChaCha](rand/chacha/struct.ChaCha.html)|512 bits (`[u32; 16]`)|✅|90 MB/s (ChaCha8), 40 MB/s (ChaCha20)|Currently only works in **Nightly** Rust, will work with Stable 1.47 (see [rust#74060](https://github.com/rust-lang/rust/pull/74060))|https://cr.yp.to/chacha.html
+//! xoroshiro128++|[nanohash::Xoroshiro128](rand/xoroshiro/struct.Xoroshiro128.html)|64 bits (`u64`)|🚫|||https://prng.di.unimi.it/xoroshiro128plusplus.c
+//! ChaCha|[nanohash::ChaCha](rand/chacha/struct.ChaCha.html)|512 bits (`[u32; 16]`)|✅|90 MB/s (ChaCha8), 40 MB/s (ChaCha20)||https://cr.yp.to/chacha.html
 //!  
 //! ## Entropy Sources
 //!
-//! * Unix-like (Linux, Android, macOS, iOS, FreeBSD, OpenBSD) - first `/dev/urandom`, else `/dev/random`, else system time.
+//! * Unix-like (Linux, Android, macOS, iOS, FreeBSD, OpenBSD) - first `/dev/urandom`, else `/dev/random`.
 //! * Windows - `BCryptGenRandom` with system-preferred RNG.
+//! * WebAssembly, with the `wasm` feature - the Web Crypto API's `crypto.getRandomValues`.
+//!
+//! Whichever source is used, its output is mixed with weaker sources (system time, the CPU timestamp counter, and ASLR'd addresses),
+//! so a broken primary source doesn't directly result in a predictable seed.
+//! If the primary source fails outright, `rdseed` is tried (with the `rdseed` feature), and if there's still no entropy,
+//! constructors like `WyRand::new()` panic, while `WyRand::try_new()` returns an [`EntropyError`](entropy::EntropyError) naming the source that failed.
 //!
 //! ## Feature Flags
 //!
 //! * `std` (default) - Enables Rust `std` lib features, such as seeding from OS entropy sources.
-//! * `tls` (default) - Enables a thread-local WyRand RNG (see below). Requires `tls` to be enabled.
+//! * `alloc` (default) - Enables helpers that return heap-allocated collections, such as [`Vec`](alloc::vec::Vec). Enabled by `std`.
+//! * `tls` (default) - Enables a thread-local [`DefaultRng`] (see below). Requires `std` to be enabled.
 //! * `wyrand` (default) - Enable the [wyrand](rand/wyrand/struct.WyRand.html) RNG.
 //! * `pcg64` (default) - Enable the [Pcg64](rand/pcg64/struct.Pcg64.html)  RNG.
-//! * `chacha` - Enable the [ChaCha](rand/chacha/struct.ChaCha.html) RNG. Requires Rust 1.47 or later.
+//! * `xoroshiro` (default) - Enable the [xoroshiro128++](rand/xoroshiro/struct.Xoroshiro128.html) RNG.
+//! * `chacha` - Enable the [ChaCha](rand/chacha/struct.ChaCha.html) RNG.
 //! * `rdseed` - On x86/x86_64 platforms, the `rdseed` intrinsic will be used when OS entropy isn't available.
-//! * `zeroize` - Implement the [Zeroize](https://crates.io/crates/zeroize) trait for all RNGs.
+//! * `debug-state` - Print the internal state of RNGs in their [`Debug`](core::fmt::Debug) output, instead of keeping it opaque.
+//!   Cryptographically secure RNGs, such as [ChaCha](rand/chacha/struct.ChaCha.html), never print their state.
+//! * `native-endian` - Output bytes, and read seeds, in the platform's native byte order instead of little-endian.
+//!   This restores the behavior of older versions, for anyone who stored sequences generated on a big-endian platform,
+//!   at the cost of the same seed giving different bytes on different platforms.
+//! * `derive` - Enable `#[derive(RandomGen)]`, for generating random structs and enums.
+//!   See [`RandomGen`](derive.RandomGen.html) for what's supported.
+//!   This also enables [`compile_seed!()`](macro.compile_seed.html), for seeds chosen at build time.
+//! * `serde` - Implement [Serde](https://crates.io/crates/serde)'s `Serialize` and `Deserialize` for the non-cryptographic RNGs,
+//!   saving their exact internal state, so a deserialized RNG continues the same sequence.
+//! * `zeroize` - Implement the [Zeroize](https://crates.io/crates/zeroize) trait for all RNGs, and zero their state when they're dropped.
+//!   Key material that [ChaCha](rand/chacha/struct.ChaCha.html) pulls from the system is zeroed once it's been used, too.
+//! * `chrono` - Implement [`RandomGen`] and [`RandomRange`] for [chrono](https://crates.io/crates/chrono)'s `DateTime<Utc>`,
+//!   for random timestamps, such as in tests.
+//! * `wasm` - On WebAssembly, use the Web Crypto API's `crypto.getRandomValues` as an entropy source, through `web-sys`.
+//!   This makes seeding from entropy, such as with `WyRand::new()`, work in browsers and other JavaScript environments.
 //! * `getrandom` - Use the [`getrandom`](https://crates.io/crates/getrandom) crate as an entropy source.
-//! Works on most systems, optional due to the fact that it brings in more dependencies.
+//!   Works on most systems, optional due to the fact that it brings in more dependencies.
+//! * `default-wyrand`, `default-pcg64`, or `default-chacha` - Pick the generator [`DefaultRng`] refers to, which is also used by [`tls_rng`].
+//!   Only one of them can be enabled, and without any, it's WyRand.
+//!
+//! ## Minimum Supported Rust Version
+//!
+//! nanorand needs Rust 1.66 or later, as declared by `rust-version` in its `Cargo.toml`.
+//! Optional dependencies, such as `getrandom` or `serde`, may need a newer version.
 
 #[macro_use]
 extern crate alloc;
 
+/// Converts an integer to the bytes an RNG outputs, which are little-endian unless the `native-endian` feature is enabled.
+macro_rules! to_output_bytes {
+	($x:expr) => {
+		if cfg!(feature = "native-endian") {
+			$x.to_ne_bytes()
+		} else {
+			$x.to_le_bytes()
+		}
+	};
+}
+
+/// Converts bytes to an integer, as the reverse of `to_output_bytes!`.
+macro_rules! from_output_bytes {
+	($ty:ty, $x:expr) => {
+		if cfg!(feature = "native-endian") {
+			<$ty>::from_ne_bytes($x)
+		} else {
+			<$ty>::from_le_bytes($x)
+		}
+	};
+}
+
+/// Checks that the lower bound of a range isn't above its upper bound, in debug builds only.
+macro_rules! debug_assert_range {
+	($lower:expr, $upper:expr) => {
+		debug_assert!(
+			$lower <= $upper,
+			"The lower bound of a range must not be above its upper bound!"
+		)
+	};
+}
+
+#[cfg(any(
+	all(feature = "default-wyrand", feature = "default-pcg64"),
+	all(feature = "default-wyrand", feature = "default-chacha"),
+	all(feature = "default-pcg64", feature = "default-chacha")
+))]
+compile_error!("Only one of the `default-wyrand`, `default-pcg64`, and `default-chacha` features can be enabled.");
+
 pub use gen::*;
+#[cfg(feature = "derive")]
+pub use nanorand_derive::{compile_seed, RandomGen};
 pub use rand::*;
 #[cfg(feature = "tls")]
 pub use tls::tls_rng;
 
+/// Wrappers that change how an RNG generates.
+pub mod adapter;
 /// Implementation of cryptography, for CSPRNGs.
 pub mod crypto;
+/// Sampling from common probability distributions.
+pub mod distributions;
 /// Sources for obtaining entropy.
 pub mod entropy;
 /// Traits for generating types from an RNG.
 pub mod gen;
+/// Iterators over generated values.
+pub mod iter;
+#[cfg(feature = "std")]
+/// Generating network addresses.
+pub mod net;
 /// RNG algorithms.
 pub mod rand;
-#[cfg(feature = "std")]
-/// Provides a thread-local [WyRand] RNG.
+/// Helpers for randomly ordering and sampling sequences.
+pub mod seq;
+#[cfg(feature = "tls")]
+/// Provides a thread-local [`DefaultRng`].
 pub mod tls;
@@ -0,0 +1,45 @@
+use crate::RNG;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// Generate a random IPv4 address within the subnet `network/prefix_len`, such as `10.0.0.0/8`.  
+/// The first `prefix_len` bits come from `network`, and the rest are random.
+/// Every address in the subnet can be generated, including its network and broadcast addresses.
+/// ```rust
+/// use nanorand::{net::ipv4_in_subnet, WyRand};
+/// use std::net::Ipv4Addr;
+///
+/// let mut rng = WyRand::new();
+/// let addr = ipv4_in_subnet(&mut rng, Ipv4Addr::new(192, 168, 1, 0), 24);
+/// assert_eq!(addr.octets()[..3], [192, 168, 1]);
+/// ```
+/// Panics if `prefix_len` is above 32.
+pub fn ipv4_in_subnet<R: RNG>(r: &mut R, network: Ipv4Addr, prefix_len: u8) -> Ipv4Addr {
+	assert!(
+		prefix_len <= 32,
+		"An IPv4 prefix can't be longer than 32 bits!"
+	);
+	let mask = u32::MAX.checked_shl(32 - prefix_len as u32).unwrap_or(0);
+	let host: u32 = r.generate();
+	Ipv4Addr::from((u32::from(network) & mask) | (host & !mask))
+}
+
+/// Generate a random IPv6 address within the subnet `network/prefix_len`, such as `2001:db8::/32`.  
+/// The first `prefix_len` bits come from `network`, and the rest are random.
+/// ```rust
+/// use nanorand::{net::ipv6_in_subnet, WyRand};
+/// use std::net::Ipv6Addr;
+///
+/// let mut rng = WyRand::new();
+/// let addr = ipv6_in_subnet(&mut rng, Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32);
+/// assert_eq!(addr.segments()[..2], [0x2001, 0xdb8]);
+/// ```
+/// Panics if `prefix_len` is above 128.
+pub fn ipv6_in_subnet<R: RNG>(r: &mut R, network: Ipv6Addr, prefix_len: u8) -> Ipv6Addr {
+	assert!(
+		prefix_len <= 128,
+		"An IPv6 prefix can't be longer than 128 bits!"
+	);
+	let mask = u128::MAX.checked_shl(128 - prefix_len as u32).unwrap_or(0);
+	let host: u128 = r.generate();
+	Ipv6Addr::from((u128::from(network) & mask) | (host & !mask))
+}
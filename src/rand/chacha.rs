@@ -1,82 +1,206 @@
-use crate::{crypto::chacha, RNG};
+use crate::{crypto::chacha, CryptoRNG, SeedableRNG, RNG};
 
 /// An instance of the ChaCha random number generator.  
 /// Seeded from the system entropy generator when available.  
 /// **This generator _is theoretically_ cryptographically secure.**
-#[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize))]
-#[cfg_attr(feature = "zeroize", zeroize(drop))]
+#[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize, zeroize::ZeroizeOnDrop))]
 pub struct ChaCha {
 	state: [u32; 16],
 	rounds: u8,
+	/// The rest of the last keystream block used by [`ChaCha::apply_keystream`].
+	keystream: [u8; 64],
+	/// How much of `keystream` has been used up.
+	keystream_used: usize,
+	/// How many words of the next block to skip, after seeking into the middle of it.
+	word_offset: usize,
 }
 
 impl ChaCha {
-	/// Create a new [`ChaCha`] instance, seeding from the system's default source of entropy.
+	/// Create a new [`ChaCha`] instance, seeding from the system's default source of entropy.  
+	/// With the `zeroize` feature enabled, the buffers the key and nonce pass through are zeroed afterwards.  
+	/// Panics if the system has no entropy to give, see [`ChaCha::try_new`] for the fallible version.
 	pub fn new(rounds: u8) -> Self {
-		let mut key: [u8; core::mem::size_of::<u8>() * 32] = Default::default();
-		key.copy_from_slice(&crate::entropy::entropy_from_system(
-			core::mem::size_of::<u8>() * 32,
-		));
-		let mut nonce: [u8; core::mem::size_of::<u8>() * 16] = Default::default();
-		nonce.copy_from_slice(&crate::entropy::entropy_from_system(
-			core::mem::size_of::<u8>() * 16,
-		));
-		let state = chacha::chacha_init(key, nonce);
-		Self { rounds, state }
+		match Self::try_new(rounds) {
+			Ok(rng) => rng,
+			Err(err) => panic!("Couldn't seed ChaCha: {}", err),
+		}
 	}
 
-	/// Create a new [`ChaCha`] instance, using the provided key and nonce.
-	pub fn new_key(rounds: u8, key: [u8; 32], nonce: [u8; 16]) -> Self {
+	/// Create a new [`ChaCha`] instance, seeding from the system's default source of entropy,
+	/// or return an [`EntropyError`](crate::entropy::EntropyError) if it's unavailable.  
+	/// As there's no good fallback for a cryptographic key, this is the one to use if failing loudly isn't an option.
+	pub fn try_new(rounds: u8) -> Result<Self, crate::entropy::EntropyError> {
+		let mut key = [0u8; 32];
+		let mut nonce = [0u8; 16];
+		let entropy = crate::entropy::try_entropy_from_system(key.len() + nonce.len())?;
+		key.copy_from_slice(&entropy[..32]);
+		nonce.copy_from_slice(&entropy[32..]);
+		let rng = Self::new_key(rounds, key, nonce);
+		#[cfg(feature = "zeroize")]
+		{
+			use zeroize::Zeroize;
+			let mut entropy = entropy;
+			entropy.zeroize();
+			key.zeroize();
+			nonce.zeroize();
+		}
+		Ok(rng)
+	}
+
+	/// Create a new [`ChaCha`] instance, using the provided key and nonce.  
+	/// This is a `const fn`, so it can be used to initialize a `static`.
+	pub const fn new_key(rounds: u8, key: [u8; 32], nonce: [u8; 16]) -> Self {
 		let state = chacha::chacha_init(key, nonce);
-		Self { rounds, state }
+		Self {
+			rounds,
+			state,
+			keystream: [0; 64],
+			keystream_used: 64,
+			word_offset: 0,
+		}
+	}
+
+	/// The position of the next word of keystream [`RNG::rand`] or [`RNG::fill_bytes`] will use, counted in 4 byte words from the start.  
+	/// Those always start on a new block, so after them, this is a multiple of 16, unless [`ChaCha::set_word_pos`] was used.
+	pub fn word_pos(&self) -> u128 {
+		self.state[12] as u128 * 16 + self.word_offset as u128
+	}
+
+	/// Seek to a position in the keystream, counted in 4 byte words from the start,
+	/// so the next [`RNG::rand`], [`RNG::fill_bytes`], or [`ChaCha::apply_keystream`] continues from there.  
+	/// Seeking to the middle of a block is supported, and gives the same bytes that reading up to that point would have.
+	/// Any rest of a block left over from [`ChaCha::apply_keystream`] is discarded.
+	///
+	/// The block counter is 32 bits, so the keystream repeats every 2^36 words, and positions past that wrap around.
+	/// ```rust
+	/// use nanorand::{ChaCha, RNG};
+	///
+	/// let mut whole = [0u8; 256];
+	/// ChaCha::new_key(20, [1; 32], [2; 16]).fill_bytes(&mut whole);
+	///
+	/// // A worker can pick up from any word, without generating everything before it.
+	/// let mut part = [0u8; 56];
+	/// let mut worker = ChaCha::new_key(20, [1; 32], [2; 16]);
+	/// worker.set_word_pos(50);
+	/// worker.fill_bytes(&mut part);
+	/// assert_eq!(part, whole[200..]);
+	/// ```
+	pub fn set_word_pos(&mut self, word_pos: u128) {
+		self.state[12] = (word_pos / 16) as u32;
+		self.word_offset = (word_pos % 16) as usize;
+		self.keystream_used = self.keystream.len();
+	}
+
+	/// XOR the ChaCha keystream over `data`, to encrypt or decrypt it.
+	/// Applying the keystream twice, starting from the same state, restores the original data.
+	///
+	/// The keystream continues across calls, so applying it to a buffer in pieces gives the same result as applying it all at once.
+	/// Unlike [`RNG::rand`], the keystream is always made of little-endian words, as the ChaCha specification requires,
+	/// even with the `native-endian` feature enabled.
+	/// The rest of a partially-used block is only ever used by this; [`RNG::rand`] and [`RNG::fill_bytes`] start on a new block.
+	///
+	/// This is meant for lightweight obfuscation, such as of test data, and isn't interchangeable with other ChaCha20 implementations,
+	/// since this generator's state is set up with the `"expand 16-byte k"` constant, rather than the `"expand 32-byte k"` one.
+	/// ```rust
+	/// use nanorand::ChaCha;
+	///
+	/// let mut data = *b"attack at dawn";
+	/// ChaCha::new_key(20, [1; 32], [2; 16]).apply_keystream(&mut data);
+	/// assert_ne!(&data, b"attack at dawn");
+	/// ChaCha::new_key(20, [1; 32], [2; 16]).apply_keystream(&mut data);
+	/// assert_eq!(&data, b"attack at dawn");
+	/// ```
+	pub fn apply_keystream(&mut self, data: &mut [u8]) {
+		let mut applied = 0;
+		while applied < data.len() {
+			if self.keystream_used == self.keystream.len() {
+				let block = chacha::chacha_block(self.rounds, self.state);
+				self.keystream
+					.chunks_mut(4)
+					.zip(block.iter())
+					.for_each(|(bytes, word)| bytes.copy_from_slice(&word.to_le_bytes()));
+				self.keystream_used = self.word_offset * 4;
+				self.word_offset = 0;
+				self.state[12] = self.state[12].wrapping_add(1);
+			}
+			let keystream = &self.keystream[self.keystream_used..];
+			let amt = keystream.len().min(data.len() - applied);
+			data[applied..applied + amt]
+				.iter_mut()
+				.zip(keystream)
+				.for_each(|(a, b)| *a ^= *b);
+			self.keystream_used += amt;
+			applied += amt;
+		}
 	}
 }
 
 impl Default for ChaCha {
+	/// Create a new [`ChaCha`] instance with 20 rounds, seeding from the system's default source of entropy.
 	fn default() -> Self {
-		let mut key: [u8; core::mem::size_of::<u8>() * 32] = Default::default();
-		key.copy_from_slice(&crate::entropy::entropy_from_system(
-			core::mem::size_of::<u8>() * 32,
-		));
-		let mut nonce: [u8; core::mem::size_of::<u8>() * 16] = Default::default();
-		nonce.copy_from_slice(&crate::entropy::entropy_from_system(
-			core::mem::size_of::<u8>() * 16,
-		));
-		let state = chacha::chacha_init(key, nonce);
-		Self { state, rounds: 20 }
+		Self::new(20)
 	}
 }
 
 impl RNG for ChaCha {
 	type Output = [u8; 64];
+	const IS_CRYPTO: bool = true;
 
 	fn rand(&mut self) -> Self::Output {
+		if self.word_offset != 0 {
+			// This straddles two blocks, after seeking into the middle of one.
+			let mut ret = [0u8; 64];
+			self.fill_bytes(&mut ret);
+			return ret;
+		}
 		let block = chacha::chacha_block(self.rounds, self.state);
 		let mut ret = [0u8; 64];
 		block.iter().enumerate().for_each(|(idx, num)| {
-			let x = num.to_ne_bytes();
+			let x = to_output_bytes!(num);
 			let n = idx * 4;
 			ret[n] = x[0];
 			ret[n + 1] = x[1];
 			ret[n + 2] = x[2];
 			ret[n + 3] = x[3];
 		});
-		self.state[12] += 1;
+		self.state[12] = self.state[12].wrapping_add(1);
 		ret
 	}
 
-	fn rand_with_seed(_seed: &[u8]) -> Self::Output {
-		panic!("ChaCha RNG requires a state!");
+	fn fill_bytes(&mut self, dest: &mut [u8]) {
+		let mut filled = 0;
+		while filled < dest.len() {
+			let block = chacha::chacha_block(self.rounds, self.state);
+			let words = &block[self.word_offset..];
+			let amt = (words.len() * 4).min(dest.len() - filled);
+			dest[filled..filled + amt]
+				.chunks_mut(4)
+				.zip(words.iter())
+				.for_each(|(bytes, word)| {
+					bytes.copy_from_slice(&to_output_bytes!(word)[..bytes.len()])
+				});
+			self.word_offset = 0;
+			self.state[12] = self.state[12].wrapping_add(1);
+			filled += amt;
+		}
 	}
+}
 
-	fn reseed(&mut self, new_seed: &[u8]) {
-		let mut seed = [42u8; 48];
-		seed.iter_mut().zip(new_seed).for_each(|(a, b)| *a = *b);
-		let mut key = [0u8; 32];
-		let mut nonce = [0u8; 16];
-		key.copy_from_slice(&seed[..32]);
-		nonce.copy_from_slice(&seed[32..48]);
-		self.state = chacha::chacha_init(key, nonce);
+impl CryptoRNG for ChaCha {}
+
+impl SeedableRNG for ChaCha {
+	type Seed = [u8; 32];
+
+	/// Create a new [`ChaCha`] instance with 20 rounds, using the seed as the key, and an all-zero nonce.  
+	/// Use [`ChaCha::new_key`] for a different number of rounds, or a nonce.
+	fn from_seed(seed: Self::Seed) -> Self {
+		Self::new_key(20, seed, [0; 16])
+	}
+
+	/// Reseeds the RNG, using the seed as the key, and an all-zero nonce.
+	/// Unlike [`SeedableRNG::from_seed`], this keeps the current number of rounds, rather than using 20.
+	fn reseed(&mut self, seed: Self::Seed) {
+		*self = Self::new_key(self.rounds, seed, [0; 16]);
 	}
 }
 
@@ -85,10 +209,48 @@ impl Clone for ChaCha {
 		Self {
 			state: self.state,
 			rounds: self.rounds,
+			keystream: self.keystream,
+			keystream_used: self.keystream_used,
+			word_offset: self.word_offset,
 		}
 	}
 }
 
+impl PartialEq for ChaCha {
+	/// Two [`ChaCha`]s are equal if they have the same state, rounds, and unused keystream, so they'll generate the same sequence.  
+	/// This is a constant-time comparison, so it doesn't leak how much of the key matched through timing.
+	/// [`Hash`](core::hash::Hash) isn't implemented, as hashing the key would leak it.
+	fn eq(&self, other: &Self) -> bool {
+		let mut diff = (self.rounds ^ other.rounds) as u32;
+		diff |= (self.keystream_used ^ other.keystream_used) as u32;
+		diff |= (self.word_offset ^ other.word_offset) as u32;
+		for (a, b) in self.state.iter().zip(other.state.iter()) {
+			diff |= a ^ b;
+		}
+		// The used-up part of the keystream will never be used again, so it doesn't matter.
+		let unused = self.keystream_used.min(other.keystream_used);
+		for (a, b) in self.keystream[unused..]
+			.iter()
+			.zip(other.keystream[unused..].iter())
+		{
+			diff |= (a ^ b) as u32;
+		}
+		core::hint::black_box(diff) == 0
+	}
+}
+
+impl Eq for ChaCha {}
+
+impl core::fmt::Debug for ChaCha {
+	/// Prints `ChaCha { rounds: 20, .. }`, with the number of rounds.
+	/// The key and keystream are never printed, even with the `debug-state` feature enabled, so they can't end up in logs.
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		f.debug_struct("ChaCha")
+			.field("rounds", &self.rounds)
+			.finish_non_exhaustive()
+	}
+}
+
 #[cfg(feature = "std")]
 impl std::fmt::Display for ChaCha {
 	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
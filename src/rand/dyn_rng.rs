@@ -0,0 +1,97 @@
+use crate::{RandError, RNG};
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+/// An object-safe RNG, for storing and swapping generators at runtime, such as `Box<dyn DynRng>`.  
+/// This is implemented for every [`RNG`], and `&mut dyn DynRng` and `Box<dyn DynRng>` implement [`RNG`] in turn,
+/// so everything that works with an [`RNG`], such as [`RNG::generate`], works through them too.
+/// ```rust
+/// use nanorand::{DynRng, Pcg64, RNG, WyRand};
+///
+/// let mut rng: Box<dyn DynRng> = if std::env::var("USE_PCG").is_ok() {
+///     Box::new(Pcg64::new())
+/// } else {
+///     Box::new(WyRand::new())
+/// };
+/// let n = rng.generate_range(1_u8..=6);
+/// ```
+pub trait DynRng {
+	/// Fills the provided buffer with random bytes, as with [`RNG::fill_bytes`].
+	fn fill_bytes_dyn(&mut self, dest: &mut [u8]);
+	/// Fills the provided buffer with random bytes, or returns an error if the generator fails, as with [`RNG::try_fill_bytes`].  
+	/// The default just wraps [`DynRng::fill_bytes_dyn`].
+	fn try_fill_bytes_dyn(&mut self, dest: &mut [u8]) -> Result<(), RandError> {
+		self.fill_bytes_dyn(dest);
+		Ok(())
+	}
+	/// Box the RNG, erasing its type.
+	#[cfg(feature = "alloc")]
+	fn into_boxed<'a>(self) -> Box<dyn DynRng + 'a>
+	where
+		Self: Sized + 'a,
+	{
+		Box::new(self)
+	}
+}
+
+impl<R: RNG> DynRng for R {
+	fn fill_bytes_dyn(&mut self, dest: &mut [u8]) {
+		self.fill_bytes(dest)
+	}
+
+	fn try_fill_bytes_dyn(&mut self, dest: &mut [u8]) -> Result<(), RandError> {
+		self.try_fill_bytes(dest)
+	}
+}
+
+/// Type-erased RNGs output 8 bytes at a time, filled with [`DynRng::fill_bytes_dyn`].
+// These call through `**self`, as `Self` is itself a `DynRng` through the blanket impl, which would recurse back here.
+impl RNG for &mut (dyn DynRng + '_) {
+	type Output = [u8; 8];
+
+	fn rand(&mut self) -> Self::Output {
+		let mut ret = [0u8; 8];
+		(**self).fill_bytes_dyn(&mut ret);
+		ret
+	}
+
+	fn fill_bytes(&mut self, dest: &mut [u8]) {
+		(**self).fill_bytes_dyn(dest)
+	}
+
+	fn try_rand(&mut self) -> Result<Self::Output, RandError> {
+		let mut ret = [0u8; 8];
+		(**self).try_fill_bytes_dyn(&mut ret)?;
+		Ok(ret)
+	}
+
+	fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RandError> {
+		(**self).try_fill_bytes_dyn(dest)
+	}
+}
+
+/// Type-erased RNGs output 8 bytes at a time, filled with [`DynRng::fill_bytes_dyn`].
+#[cfg(feature = "alloc")]
+impl RNG for Box<dyn DynRng + '_> {
+	type Output = [u8; 8];
+
+	fn rand(&mut self) -> Self::Output {
+		let mut ret = [0u8; 8];
+		(**self).fill_bytes_dyn(&mut ret);
+		ret
+	}
+
+	fn fill_bytes(&mut self, dest: &mut [u8]) {
+		(**self).fill_bytes_dyn(dest)
+	}
+
+	fn try_rand(&mut self) -> Result<Self::Output, RandError> {
+		let mut ret = [0u8; 8];
+		(**self).try_fill_bytes_dyn(&mut ret)?;
+		Ok(ret)
+	}
+
+	fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RandError> {
+		(**self).try_fill_bytes_dyn(dest)
+	}
+}
@@ -1,11 +1,64 @@
 #[cfg(feature = "chacha")]
 pub use chacha::ChaCha;
+pub use dyn_rng::DynRng;
 #[cfg(feature = "pcg64")]
 pub use pcg64::Pcg64;
 #[cfg(feature = "wyrand")]
-pub use wyrand::WyRand;
+pub use wyrand::{hash_u64, WyRand};
+#[cfg(feature = "xoroshiro")]
+pub use xoroshiro::Xoroshiro128;
 
-use crate::gen::{RandomGen, RandomRange};
+use crate::adapter::CountingRng;
+#[cfg(feature = "alloc")]
+use crate::gen::RangeOffset;
+use crate::gen::{RandomGen, RandomRange, TryRandomGen};
+use crate::iter::{IntoRandIter, RandIter};
+#[cfg(feature = "alloc")]
+use alloc::{collections::BTreeSet, string::String, vec::Vec};
+use core::ops::{Bound, RangeBounds};
+
+/// The recommended general-purpose generator, which is [`WyRand`], unless another is picked with a `default-*` feature.  
+/// Code using this instead of a concrete generator picks up a better default when nanorand changes it,
+/// or can be switched to a CSPRNG for a whole build with the `default-chacha` feature,
+/// but the sequence from a given seed changes along with it, so use a concrete generator when outputs must be reproducible across versions.
+/// ```rust
+/// use nanorand::{DefaultRng, RNG};
+///
+/// let mut rng = DefaultRng::default();
+/// let roll = rng.generate_range(1_u8..=6);
+/// ```
+#[cfg(all(
+	feature = "wyrand",
+	not(any(feature = "default-pcg64", feature = "default-chacha"))
+))]
+pub type DefaultRng = WyRand;
+
+/// The recommended general-purpose generator, picked with the `default-pcg64` feature.
+#[cfg(feature = "default-pcg64")]
+pub type DefaultRng = Pcg64;
+
+/// The recommended general-purpose generator, picked with the `default-chacha` feature.
+#[cfg(feature = "default-chacha")]
+pub type DefaultRng = ChaCha;
+
+/// Create a new [`DefaultRng`], seeded from the system's default source of entropy.
+/// ```rust
+/// use nanorand::RNG;
+///
+/// let mut rng = nanorand::default_rng();
+/// println!("Random number: {}", rng.generate::<u64>());
+/// ```
+#[cfg(all(
+	feature = "std",
+	any(
+		feature = "wyrand",
+		feature = "default-pcg64",
+		feature = "default-chacha"
+	)
+))]
+pub fn default_rng() -> DefaultRng {
+	DefaultRng::default()
+}
 
 /// Implementation of the wyrand PRNG algorithm.
 /// More details can be seen at https://github.com/wangyi-fudan/wyhash
@@ -17,37 +70,975 @@ pub mod wyrand;
 #[cfg(feature = "pcg64")]
 pub mod pcg64;
 
+/// Implementation of the xoroshiro128++ PRNG algorithm.
+/// More details can be seen at https://prng.di.unimi.it/
+#[cfg(feature = "xoroshiro")]
+pub mod xoroshiro;
+
 /// Implementation of the ChaCha CSPRNG algorithm.
 /// More details can be seen at https://en.wikipedia.org/wiki/Salsa20
 #[cfg(feature = "chacha")]
 pub mod chacha;
 
-/// A trait that represents a random number generator.
-pub trait RNG: Clone {
+/// An object-safe layer over [`RNG`], for type-erased generators.
+pub mod dyn_rng;
+
+/// An error from a generator that can fail at runtime, such as one backed by a hardware instruction that can run dry.  
+/// Returned by [`RNG::try_rand`], and the other fallible methods built on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RandError {
+	generator: &'static str,
+}
+
+impl RandError {
+	/// Create an error for the named generator, for implementing [`RNG::try_rand`].
+	pub const fn new(generator: &'static str) -> Self {
+		Self { generator }
+	}
+
+	/// The name of the generator that failed.
+	pub const fn generator(&self) -> &'static str {
+		self.generator
+	}
+}
+
+impl core::fmt::Display for RandError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "{} failed to generate random bytes", self.generator)
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RandError {}
+
+/// The amount SplitMix64 advances its state by for each output.
+pub(crate) const SPLITMIX64_GAMMA: u64 = 0x9e3779b97f4a7c15;
+
+/// The SplitMix64 generator, used to expand a single [`u64`] seed into a larger state.
+pub(crate) fn splitmix64(state: &mut u64) -> u64 {
+	*state = state.wrapping_add(SPLITMIX64_GAMMA);
+	splitmix64_mix(*state)
+}
+
+/// SplitMix64's output function, for an already advanced state.  
+/// Unlike [`splitmix64`], this is a `const fn`, since those can only take a `&mut` from Rust 1.83.
+pub(crate) const fn splitmix64_mix(mut z: u64) -> u64 {
+	z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+	z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+	z ^ (z >> 31)
+}
+
+/// Unwrap a result which can't be an error, so infallible methods can share code with fallible ones.
+pub(crate) fn infallible<T>(result: Result<T, core::convert::Infallible>) -> T {
+	match result {
+		Ok(ret) => ret,
+		Err(never) => match never {},
+	}
+}
+
+/// Fill `dest` with whole outputs of [`RNG::try_rand`], as the default [`RNG::fill_bytes`] does with [`RNG::rand`],
+/// for wrappers which can't forward [`RNG::try_fill_bytes`] to the RNG they wrap.
+pub(crate) fn try_fill_from_outputs<R: RNG>(rng: &mut R, dest: &mut [u8]) -> Result<(), RandError> {
+	let mut chunks = dest.chunks_exact_mut(R::OUTPUT_SIZE);
+	for chunk in chunks.by_ref() {
+		chunk.copy_from_slice(rng.try_rand()?.as_ref());
+	}
+	let tail = chunks.into_remainder();
+	if !tail.is_empty() {
+		let generated = rng.try_rand()?;
+		tail.copy_from_slice(&generated.as_ref()[..tail.len()]);
+	}
+	Ok(())
+}
+
+/// Fill `seed` with the output of SplitMix64, started from `state`, as little-endian [`u64`]s.
+pub(crate) fn expand_seed(mut state: u64, seed: &mut [u8]) {
+	for chunk in seed.chunks_mut(8) {
+		let bytes = splitmix64(&mut state).to_le_bytes();
+		chunk.copy_from_slice(&bytes[..chunk.len()]);
+	}
+}
+
+/// Fit `input` of any length to `seed`, without ignoring any of its bytes.  
+/// A shorter input is used as the start of the seed, and the rest is filled with SplitMix64, started from a hash of the input.
+/// A longer input is XORed in, one seed-sized chunk at a time, with a SplitMix64 round after each chunk,
+/// so the order of the chunks matters too.
+pub(crate) fn absorb_seed(input: &[u8], seed: &mut [u8]) {
+	if input.len() <= seed.len() {
+		seed[..input.len()].copy_from_slice(input);
+		expand_seed(fnv1a(input) ^ input.len() as u64, &mut seed[input.len()..]);
+		return;
+	}
+	seed.iter_mut().for_each(|byte| *byte = 0);
+	for chunk in input.chunks(seed.len()) {
+		seed.iter_mut().zip(chunk).for_each(|(a, b)| *a ^= *b);
+		let mut carry = input.len() as u64;
+		for word in seed.chunks_mut(8) {
+			let mut bytes = [0u8; 8];
+			bytes[..word.len()].copy_from_slice(word);
+			let mut state = u64::from_le_bytes(bytes) ^ carry;
+			carry = splitmix64(&mut state);
+			word.copy_from_slice(&carry.to_le_bytes()[..word.len()]);
+		}
+	}
+}
+
+/// The characters of [`RNG::fill_alphanumeric`], ASCII letters and digits.
+const ALPHANUMERIC: &[u8; 62] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+/// The lowercase hexadecimal digits of [`RNG::hex_string`].
+#[cfg(feature = "alloc")]
+const HEX: &[u8; 16] = b"0123456789abcdef";
+/// The URL-safe base64 alphabet of [`RNG::base64url_string`].
+#[cfg(feature = "alloc")]
+const BASE64URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// The 64-bit FNV-1a hash of `bytes`.
+fn fnv1a(bytes: &[u8]) -> u64 {
+	bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| {
+		(hash ^ byte as u64).wrapping_mul(0x100000001b3)
+	})
+}
+
+/// A uniform float within `0.0..1.0`, excluding both ends, so its log is always finite and below 0.
+#[cfg(feature = "std")]
+fn open_unit<R: RNG>(r: &mut R) -> f64 {
+	((r.generate::<u64>() >> 11) as f64 + 0.5) / (1_u64 << 53) as f64
+}
+
+/// A trait that represents a random number generator.  
+/// This is implemented for `&mut R` where `R: RNG`, so a generator can be lent out to functions taking `impl RNG`
+/// and still be used afterwards.
+/// ```rust
+/// use nanorand::{RNG, WyRand};
+///
+/// fn roll(mut rng: impl RNG) -> u8 {
+///     rng.generate_range(1_u8..=6)
+/// }
+///
+/// let mut rng = WyRand::new();
+/// let first = roll(&mut rng);
+/// let second = roll(&mut rng);
+/// ```
+///
+/// # Implementing
+/// [`RNG::OUTPUT_SIZE`] defaults to the size of [`RNG::Output`], which is the number of bytes in it for byte arrays such as `[u8; 8]`,
+/// so implementations written for an older version, which didn't have it, don't need to change.
+/// Implementations with any other output, such as a `Vec<u8>`, must set it to the number of bytes in every output.
+///
+/// `RNG::reseed(&[u8])` has moved to [`SeedableRNG`], which takes a fixed-size seed, and this is a breaking change:
+/// a deprecated `RNG::reseed` would make every call to [`SeedableRNG::reseed`] ambiguous while both traits are in scope.
+/// [`SeedableRNG::reseed_from_slice`] takes a seed of any length, like `RNG::reseed` did.
+///
+/// `RNG` no longer has [`Clone`] as a supertrait, as `&mut R` can't be cloned, and this is a breaking change too:
+/// generic code which clones an `R: RNG` needs an `R: RNG + Clone` bound instead.
+/// Every generator in this crate still implements [`Clone`].
+pub trait RNG: Sized {
 	/// The byte output that this RNG emits.
 	type Output: AsRef<[u8]>;
+	/// The number of bytes in every [`RNG::Output`], known at compile time.
+	const OUTPUT_SIZE: usize = core::mem::size_of::<Self::Output>();
+	/// Whether this RNG is cryptographically secure, known at compile time.  
+	/// This defaults to `false`. Secure generators set it to `true`, and also implement [`CryptoRNG`],
+	/// so they can be required with a trait bound.
+	const IS_CRYPTO: bool = false;
 
 	/// Generates a random sequence of bytes, seeding from the internal state.
 	fn rand(&mut self) -> Self::Output;
-	/// Generates a random sequence of bytes, with a custom seed.
-	fn rand_with_seed(seed: &[u8]) -> Self::Output;
+	/// Generates a random sequence of bytes, with a custom seed.  
+	/// The seed can be any length, and is fit to the generator's seed as with [`SeedableRNG::from_seed_slice`].
+	///
+	/// Panics for every generator other than [`WyRand`] and [`Xoroshiro128`](crate::Xoroshiro128), which is the default.
+	#[deprecated(
+		note = "Create the generator with `SeedableRNG::from_seed`, and call `RNG::rand` on it instead"
+	)]
+	fn rand_with_seed(_seed: &[u8]) -> Self::Output {
+		panic!("Only WyRand and Xoroshiro128 can generate from a seed alone!")
+	}
+	/// Fills the provided buffer with random bytes, seeding from the internal state.  
+	/// By default, this copies whole outputs of [`RNG::rand`] into the buffer, with the last one truncated to fit,
+	/// so the result is the same as concatenating outputs of [`RNG::rand`].
+	/// Generators that can write their output directly into the buffer should override this.
+	fn fill_bytes(&mut self, dest: &mut [u8]) {
+		// Whole outputs are copied without any per-byte indexing, leaving just the tail to truncate.
+		let mut chunks = dest.chunks_exact_mut(Self::OUTPUT_SIZE);
+		for chunk in chunks.by_ref() {
+			chunk.copy_from_slice(self.rand().as_ref());
+		}
+		let tail = chunks.into_remainder();
+		if !tail.is_empty() {
+			let generated = self.rand();
+			tail.copy_from_slice(&generated.as_ref()[..tail.len()]);
+		}
+	}
+	/// Generates a random sequence of bytes like [`RNG::rand`], but returns an error instead of panicking if the generator fails.  
+	/// Software generators can't fail, so the default just wraps [`RNG::rand`].
+	/// Generators which can fail at runtime, such as hardware-backed ones, should override this,
+	/// along with [`RNG::try_fill_bytes`].
+	fn try_rand(&mut self) -> Result<Self::Output, RandError> {
+		Ok(self.rand())
+	}
+	/// Fills the provided buffer with random bytes like [`RNG::fill_bytes`], but returns an error if the generator fails,
+	/// in which case the contents of `dest` are unspecified.  
+	/// The default just wraps [`RNG::fill_bytes`].
+	fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RandError> {
+		self.fill_bytes(dest);
+		Ok(())
+	}
+	/// Generates an array of `N` random bytes, seeding from the internal state.
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let nonce: [u8; 12] = rng.generate_bytes();
+	/// ```
+	fn generate_bytes<const N: usize>(&mut self) -> [u8; N] {
+		let mut bytes = [0u8; N];
+		self.fill_bytes(&mut bytes);
+		bytes
+	}
+	/// Fills the provided slice with randoms of the specified type, seeding from the internal state.  
+	/// Integers and floats are generated in bulk, by filling the slice's bytes with [`RNG::fill_bytes`],
+	/// rather than generating each element on its own.
+	fn fill<T: RandomGen<Self>>(&mut self, dest: &mut [T]) {
+		T::random_fill(self, dest)
+	}
 	/// Generates a random of the specified type, seeding from the internal state.
 	fn generate<R: RandomGen<Self>>(&mut self) -> R {
 		R::random(self)
 	}
-	/// Generates a random of the specified type, seeding from the internal state.
-	fn generate_range<R: RandomRange<Self>>(&mut self, lower: R, upper: R) -> R {
-		R::random_range(self, lower, upper)
+	/// Generates a [`Vec`] of `len` randoms of the specified type, allocated once with the exact capacity.  
+	/// This gives the same values as calling [`RNG::generate`] `len` times,
+	/// except for [`u8`] and [`i8`], which are filled in bulk with [`RNG::fill_bytes`] instead, like [`RNG::fill`].
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let payload = rng.generate_vec::<u8>(1024);
+	/// let scores = rng.generate_vec::<f64>(10);
+	/// assert_eq!((payload.len(), scores.len()), (1024, 10));
+	/// ```
+	#[cfg(feature = "alloc")]
+	fn generate_vec<T: RandomGen<Self>>(&mut self, len: usize) -> Vec<T> {
+		T::random_vec(self, len)
+	}
+	/// Collects `len` randoms of the specified type into any collection, such as a `String` of [`char`]s.  
+	/// This gives the same values as calling [`RNG::generate`] `len` times.
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	/// use std::collections::HashSet;
+	///
+	/// let mut rng = WyRand::new();
+	/// let ids: HashSet<u64> = rng.generate_collect(100);
+	/// let flags: Vec<bool> = rng.generate_collect(8);
+	/// ```
+	fn generate_collect<C: core::iter::FromIterator<T>, T: RandomGen<Self>>(
+		&mut self,
+		len: usize,
+	) -> C {
+		(0..len).map(|_| T::random(self)).collect()
+	}
+	/// Generates a random of the specified type like [`RNG::generate`], but returns an error if the generator fails.  
+	/// On success, this gives the same value [`RNG::generate`] would have.
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// match rng.try_generate::<u64>() {
+	///     Ok(n) => println!("Random number: {}", n),
+	///     Err(err) => eprintln!("{}", err),
+	/// }
+	/// ```
+	fn try_generate<T: TryRandomGen<Self>>(&mut self) -> Result<T, RandError> {
+		T::try_random(self)
+	}
+	/// Generates a random of the specified type, along with how many outputs of [`RNG::rand`] it took.  
+	/// This is meant for diagnostics, such as profiling the worst-case entropy use of rejection sampling.
+	/// Counts above [`u32::MAX`] are reported as [`u32::MAX`].
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let (_, calls) = rng.generate_counted::<u64>();
+	/// assert_eq!(calls, 1);
+	/// ```
+	fn generate_counted<T>(&mut self) -> (T, u32)
+	where
+		T: for<'a> RandomGen<CountingRng<&'a mut Self>>,
+	{
+		let mut counting = CountingRng::new(self);
+		let ret = T::random(&mut counting);
+		(ret, counting.calls().min(u32::MAX as u64) as u32)
+	}
+	/// Returns an endless iterator of randoms of the specified type, borrowing the RNG.
+	/// The values are the same as calling [`RNG::generate`] repeatedly, and the RNG can be used again once the iterator is dropped.
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let evens: Vec<u32> = rng.iter::<u32>().filter(|n| n % 2 == 0).take(5).collect();
+	/// let next = rng.generate::<u64>();
+	/// ```
+	fn iter<T: RandomGen<Self>>(&mut self) -> RandIter<'_, Self, T> {
+		RandIter::new(self)
+	}
+	/// Returns an endless iterator of randoms of the specified type, taking ownership of the RNG.
+	/// This is useful when the iterator needs to be `'static`, such as when returning it from a function.
+	fn into_rand_iter<T: RandomGen<Self>>(self) -> IntoRandIter<Self, T> {
+		IntoRandIter::new(self)
+	}
+	/// Generates a random of the specified type within the provided range, seeding from the internal state.  
+	/// A range without a start bound starts at [`RandomRange::MIN`], and a range without an end bound ends at [`RandomRange::MAX`], inclusive.
+	/// Types without a [`RandomRange::MIN`] or [`RandomRange::MAX`] panic on a range without the matching bound,
+	/// and ranges with an excluded start bound aren't supported, and will panic too.  
+	/// See [`RandomRange::random_range`] for how empty and inverted ranges are handled.
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let roll = rng.generate_range(1_u8..=6);
+	/// let index = rng.generate_range(0..10_usize);
+	/// let high = rng.generate_range(250_u8..);
+	/// ```
+	/// Like [`RNG::generate`], the type can be given with a turbofish, instead of a suffix on the bounds.
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let percent = rng.generate_range::<u32>(1..=100);
+	/// let offset = rng.generate_range::<i64>(-10..10);
+	/// assert!((1..=100).contains(&percent) && (-10..10).contains(&offset));
+	/// ```
+	fn generate_range<T: RandomRange<Self> + Copy>(&mut self, bounds: impl RangeBounds<T>) -> T {
+		let lower = match bounds.start_bound() {
+			Bound::Included(&lower) => lower,
+			Bound::Excluded(_) => panic!("Ranges with an excluded start bound are not supported!"),
+			Bound::Unbounded => {
+				T::MIN.expect("Ranges without a start bound need RandomRange::MIN!")
+			}
+		};
+		match bounds.end_bound() {
+			Bound::Included(&upper) => T::random_range_inclusive(self, lower, upper),
+			Bound::Excluded(&upper) => T::random_range(self, lower, upper),
+			Bound::Unbounded => T::random_range_inclusive(
+				self,
+				lower,
+				T::MAX.expect("Ranges without an end bound need RandomRange::MAX!"),
+			),
+		}
 	}
-	/// Shuffle a slice, using the RNG.
+	/// Fill a slice with randoms of the specified type within `lower..upper`, each as likely as with [`RNG::generate_range`].  
+	/// For small unsigned integers, each output of the RNG fills several elements, so filling a thousand [`u8`] dice rolls
+	/// from a generator with 8-byte outputs takes about 125 outputs, rather than a thousand.
+	/// Empty and inverted ranges are handled the same way as [`RandomRange::random_range`].
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let mut rolls = [0_u8; 1000];
+	/// rng.fill_range(&mut rolls, 1, 7);
+	/// assert!(rolls.iter().all(|roll| (1..=6).contains(roll)));
+	/// ```
+	fn fill_range<T: RandomRange<Self> + Copy>(&mut self, dest: &mut [T], lower: T, upper: T) {
+		T::random_range_fill(self, dest, lower, upper)
+	}
+	/// Fill a buffer with random ASCII letters and digits, `[A-Za-z0-9]`, each equally likely, without allocating.  
+	/// Each byte is drawn with [`RNG::fill_range`], so there's no modulo bias, even though 62 isn't a power of two.
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let mut token = [0_u8; 16];
+	/// rng.fill_alphanumeric(&mut token);
+	/// assert!(token.iter().all(u8::is_ascii_alphanumeric));
+	/// ```
+	fn fill_alphanumeric(&mut self, dest: &mut [u8]) {
+		self.fill_range(dest, 0, ALPHANUMERIC.len() as u8);
+		for byte in dest.iter_mut() {
+			*byte = ALPHANUMERIC[*byte as usize];
+		}
+	}
+	/// Generate a [`String`] of `len` random ASCII letters and digits, `[A-Za-z0-9]`, such as for tokens or temporary names.  
+	/// See [`RNG::fill_alphanumeric`] for a version which doesn't allocate.
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let token = rng.alphanumeric_string(32);
+	/// assert_eq!(token.len(), 32);
+	/// assert!(token.chars().all(|c| c.is_ascii_alphanumeric()));
+	/// ```
+	#[cfg(feature = "alloc")]
+	fn alphanumeric_string(&mut self, len: usize) -> String {
+		let mut bytes = alloc::vec![0; len];
+		self.fill_alphanumeric(&mut bytes);
+		String::from_utf8(bytes).expect("Alphanumeric ASCII is valid UTF-8")
+	}
+	/// Generate `bytes` random bytes with [`RNG::fill_bytes`], and encode them as lowercase hex,
+	/// so the [`String`] is `2 * bytes` characters long.  
+	/// With a cryptographically secure generator, such as `ChaCha`, this makes a session token with `8 * bytes` bits of entropy.
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let token = rng.hex_string(16);
+	/// assert_eq!(token.len(), 32);
+	/// assert!(token.chars().all(|c| c.is_ascii_hexdigit()));
+	/// ```
+	#[cfg(feature = "alloc")]
+	fn hex_string(&mut self, bytes: usize) -> String {
+		let mut raw = alloc::vec![0; bytes];
+		self.fill_bytes(&mut raw);
+		let mut hex = String::with_capacity(bytes * 2);
+		for byte in raw {
+			hex.push(HEX[(byte >> 4) as usize] as char);
+			hex.push(HEX[(byte & 0xF) as usize] as char);
+		}
+		hex
+	}
+	/// Generate `bytes` random bytes with [`RNG::fill_bytes`], and encode them as URL-safe base64, without padding,
+	/// as in [RFC 4648 section 5](https://datatracker.ietf.org/doc/html/rfc4648#section-5).  
+	/// Every 3 bytes become 4 characters, and a trailing 1 or 2 bytes become 2 or 3 characters,
+	/// so the [`String`] is `(4 * bytes).div_ceil(3)` characters long, such as 32 characters for 24 bytes.
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let token = rng.base64url_string(24);
+	/// assert_eq!(token.len(), 32);
+	/// assert!(token.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+	/// ```
+	#[cfg(feature = "alloc")]
+	fn base64url_string(&mut self, bytes: usize) -> String {
+		let mut raw = alloc::vec![0; bytes];
+		self.fill_bytes(&mut raw);
+		let mut encoded = String::with_capacity((bytes * 4 + 2) / 3);
+		for chunk in raw.chunks(3) {
+			let mut group = [0; 3];
+			group[..chunk.len()].copy_from_slice(chunk);
+			let group = u32::from_be_bytes([0, group[0], group[1], group[2]]);
+			// Each byte of input needs at least one more character, with no padding after the last.
+			for idx in 0..=chunk.len() {
+				encoded.push(BASE64URL[(group >> (18 - 6 * idx) & 0x3F) as usize] as char);
+			}
+		}
+		encoded
+	}
+	/// Shuffle a slice in place, using the RNG, with an unbiased Fisher–Yates shuffle, so every ordering is equally likely.  
+	/// It doesn't allocate, and for a given seed, it always gives the same order, so it's safe to use for things like game replays.
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let mut deck: Vec<u8> = (0..52).collect();
+	/// rng.shuffle(&mut deck);
+	/// ```
 	fn shuffle<I, S: AsMut<[I]>>(&mut self, mut target: S) {
 		let target = target.as_mut();
-		let target_len = target.len();
-		for idx in 0..target_len {
-			let random_idx = self.generate_range::<usize>(0, target_len - 1);
-			target.swap(idx, random_idx);
+		for idx in (1..target.len()).rev() {
+			let other = self.generate_range(0..=idx);
+			target.swap(idx, other);
+		}
+	}
+	/// Shuffle just the first `amount` elements of a slice into place, with `amount` steps of a Fisher–Yates shuffle,
+	/// returning the shuffled prefix, and the rest of the slice.  
+	/// The prefix is a uniformly random ordered sample of the whole slice, so dealing a hand of 5 doesn't shuffle the whole deck.
+	/// The rest holds the elements that weren't picked, in no particular order.
+	/// If `amount` is at least the length of the slice, the whole slice is shuffled, and the rest is empty.
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let mut deck: Vec<u8> = (0..52).collect();
+	/// let (hand, rest) = rng.partial_shuffle(&mut deck, 5);
+	/// assert_eq!((hand.len(), rest.len()), (5, 47));
+	/// ```
+	fn partial_shuffle<'a, T>(
+		&mut self,
+		slice: &'a mut [T],
+		amount: usize,
+	) -> (&'a mut [T], &'a mut [T]) {
+		let amount = amount.min(slice.len());
+		for idx in 0..amount {
+			let other = self.generate_range(idx..slice.len());
+			slice.swap(idx, other);
+		}
+		slice.split_at_mut(amount)
+	}
+	/// Generate a uniformly random index within `0..len`, or [`None`] if `len` is 0.  
+	/// When `len` is 0, no randomness is used, so the RNG isn't advanced.
+	/// This is Lemire's bounded method, through [`RNG::generate_range`], so unlike `rng.generate::<usize>() % len`,
+	/// it isn't biased towards lower indices, and doesn't panic when `len` is 0.
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let items = ["rock", "paper", "scissors"];
+	/// let idx = rng.random_index(items.len()).unwrap();
+	/// assert!(idx < 3);
+	/// assert_eq!(rng.random_index(0), None);
+	/// ```
+	fn random_index(&mut self, len: usize) -> Option<usize> {
+		if len == 0 {
+			return None;
+		}
+		Some(self.generate_range(0..len))
+	}
+	/// Pick a uniformly random element of a slice, or [`None`] if it's empty.  
+	/// The index is generated with [`RNG::random_index`], so unlike `slice[rng.generate::<usize>() % slice.len()]`,
+	/// it isn't biased towards the start of the slice, and doesn't panic on an empty one.
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let greeting = rng.choose(&["hello", "hi", "hey"]);
+	/// assert!(greeting.is_some());
+	/// ```
+	fn choose<'a, T>(&mut self, slice: &'a [T]) -> Option<&'a T> {
+		let idx = self.random_index(slice.len())?;
+		Some(&slice[idx])
+	}
+	/// Pick a uniformly random element of a slice, like [`RNG::choose`], but mutably.
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let mut health = [10, 10, 10];
+	/// if let Some(target) = rng.choose_mut(&mut health) {
+	///     *target -= 3;
+	/// }
+	/// assert_eq!(health.iter().sum::<i32>(), 27);
+	/// ```
+	fn choose_mut<'a, T>(&mut self, slice: &'a mut [T]) -> Option<&'a mut T> {
+		let idx = self.random_index(slice.len())?;
+		Some(&mut slice[idx])
+	}
+	/// Pick an element of a slice with a probability proportional to its weight, as returned by `weight`.
+	/// Returns [`None`] if the slice is empty, or every weight is 0.  
+	/// Panics if any weight is negative, NaN, or infinite.
+	///
+	/// This is a single pass over the slice, which doesn't allocate, replacing the pick with each element
+	/// with a probability of its weight over the total weight so far.
+	/// For integer weights paired with each choice, see [`crate::seq::choose_weighted`].
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let loot = [("common", 90.0), ("rare", 9.5), ("legendary", 0.5)];
+	/// let (drop, _) = rng.weighted_choose(&loot, |&(_, weight)| weight).unwrap();
+	/// assert!(["common", "rare", "legendary"].contains(drop));
+	/// ```
+	fn weighted_choose<'a, T, F: FnMut(&T) -> f64>(
+		&mut self,
+		slice: &'a [T],
+		mut weight: F,
+	) -> Option<&'a T> {
+		let mut total = 0.0;
+		let mut picked = None;
+		for item in slice {
+			let weight = weight(item);
+			assert!(
+				weight.is_finite() && weight >= 0.0,
+				"Weights must be finite and not negative, but got {}!",
+				weight
+			);
+			if weight == 0.0 {
+				continue;
+			}
+			total += weight;
+			if self.generate::<f64>() * total < weight {
+				picked = Some(item);
+			}
+		}
+		picked
+	}
+	/// Pick up to `amount` distinct indices into `weights`, without replacement, as if each pick were made
+	/// with a probability proportional to its weight, then removed from the pool before the next.  
+	/// Indices with a weight of 0 are never picked, so fewer than `amount` are returned if there aren't enough others.
+	/// They're returned in the order they'd be picked.  
+	/// Panics if any weight is negative, NaN, or infinite.
+	///
+	/// This uses the Efraimidis–Spirakis method, giving each index a random key of `u^(1 / weight)`,
+	/// and taking the `amount` largest keys, which is a single pass and a selection, instead of `amount` passes.
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let tickets = [5.0, 1.0, 0.0, 3.0];
+	/// let winners = rng.weighted_sample_without_replacement(&tickets, 2);
+	/// assert_eq!(winners.len(), 2);
+	/// assert!(!winners.contains(&2));
+	/// ```
+	#[cfg(feature = "std")]
+	fn weighted_sample_without_replacement(
+		&mut self,
+		weights: &[f64],
+		amount: usize,
+	) -> Vec<usize> {
+		let mut keyed: Vec<(f64, usize)> = Vec::new();
+		for (idx, &weight) in weights.iter().enumerate() {
+			assert!(
+				weight.is_finite() && weight >= 0.0,
+				"Weights must be finite and not negative, but got {}!",
+				weight
+			);
+			if weight > 0.0 {
+				// `ln(u) / weight` orders the same as `u^(1 / weight)`, without underflowing for tiny weights.
+				let u = 1.0 - self.generate::<f64>();
+				keyed.push((u.ln() / weight, idx));
+			}
+		}
+		let by_key = |a: &(f64, usize), b: &(f64, usize)| b.0.total_cmp(&a.0);
+		if amount < keyed.len() {
+			if amount == 0 {
+				return Vec::new();
+			}
+			keyed.select_nth_unstable_by(amount - 1, by_key);
+			keyed.truncate(amount);
+		}
+		keyed.sort_unstable_by(by_key);
+		keyed.into_iter().map(|(_, idx)| idx).collect()
+	}
+	/// Pick `k` items from an iterator of unknown length, each with an equal chance, using reservoir sampling.  
+	/// If the iterator has `k` or fewer items, all of them are returned, and if `k` is 0, the iterator isn't touched.
+	/// The order of the returned items is not random; shuffle them if that matters.
+	///
+	/// This is Li's Algorithm L, which skips ahead a random, geometrically growing number of items at a time with [`Iterator::nth`],
+	/// so it only draws from the RNG about `k * (1 + ln(n / k))` times for `n` items, instead of once per item
+	/// like [`crate::seq::reservoir_sample`].
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let rows = (0..1_000_000).map(|n| n * 3);
+	/// let sample = rng.sample_iter(rows, 10);
+	/// assert_eq!(sample.len(), 10);
+	/// ```
+	#[cfg(feature = "std")]
+	fn sample_iter<I: Iterator>(&mut self, mut iter: I, k: usize) -> Vec<I::Item> {
+		if k == 0 {
+			return Vec::new();
+		}
+		let mut reservoir: Vec<I::Item> = iter.by_ref().take(k).collect();
+		if reservoir.len() < k {
+			return reservoir;
+		}
+		let mut w = (open_unit(self).ln() / k as f64).exp();
+		loop {
+			let skip = (open_unit(self).ln() / (1.0 - w).ln()).floor();
+			// A skip too large for a `usize` runs past the end of anything that can be iterated anyway.
+			match iter.nth(skip as usize) {
+				Some(item) => {
+					let slot = self.generate_range(0..k);
+					reservoir[slot] = item;
+					w *= (open_unit(self).ln() / k as f64).exp();
+				}
+				None => break reservoir,
+			}
+		}
+	}
+	/// Pick `k` distinct integers within `lower..upper`, each set of `k` equally likely, in no particular order.  
+	/// `k` is clamped to the size of the range, so if it's at least that, every integer within the range is returned, in a random order.
+	/// That allocates the whole range, however large `k` is, so `k` must be kept below the size of a huge range.
+	///
+	/// This is Robert Floyd's algorithm, which makes exactly `k` ranged draws, however large the range is,
+	/// and only keeps the result and a set of what's been picked so far, so picking 10 IDs out of `0..10^18` is cheap.
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let ids = rng.sample_range_unique(0_u64, 1_000_000_000_000_000_000, 10);
+	/// assert_eq!(ids.len(), 10);
+	/// ```
+	/// Like [`RandomRange::random_range`], this panics in debug builds if `lower > upper`, and swaps them in release builds.
+	#[cfg(feature = "alloc")]
+	fn sample_range_unique<T: RandomRange<Self> + RangeOffset + PartialOrd>(
+		&mut self,
+		lower: T,
+		upper: T,
+		k: usize,
+	) -> Vec<T> {
+		debug_assert_range!(lower, upper);
+		let (lower, upper) = if lower > upper {
+			(upper, lower)
+		} else {
+			(lower, upper)
+		};
+		let size = upper.offset_from(lower);
+		let k = size.min(k as u128);
+		if k == size {
+			let mut all: Vec<T> = (0..size).map(|offset| lower.add_offset(offset)).collect();
+			self.shuffle(&mut all);
+			return all;
+		}
+		let mut picked = BTreeSet::new();
+		let mut ret = Vec::with_capacity(k as usize);
+		for top in size - k..size {
+			let offset =
+				T::random_range_inclusive(self, lower, lower.add_offset(top)).offset_from(lower);
+			let offset = if picked.insert(offset) {
+				offset
+			} else {
+				picked.insert(top);
+				top
+			};
+			ret.push(lower.add_offset(offset));
+		}
+		ret
+	}
+	/// Pick `amount` distinct indices within `0..len`, uniformly, in a random order.  
+	/// If `amount >= len`, every index is returned, in a random order.
+	///
+	/// When `amount` is small compared to `len`, this uses Floyd's algorithm, which takes O(`amount`²) time,
+	/// and only allocates the result, so picking a few out of millions is cheap.
+	/// Otherwise, it uses a partial Fisher–Yates shuffle of every index, which takes O(`len`) time and memory.
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let indices = rng.sample_indices(1_000_000, 5);
+	/// assert_eq!(indices.len(), 5);
+	/// ```
+	#[cfg(feature = "alloc")]
+	fn sample_indices(&mut self, len: usize, amount: usize) -> Vec<usize> {
+		let amount = amount.min(len);
+		if (amount as u128) * (amount as u128) <= len as u128 {
+			// Floyd's algorithm, inserting rather than pushing when there's a collision, so the order is random too.
+			let mut indices = Vec::with_capacity(amount);
+			for idx in len - amount..len {
+				let picked = self.generate_range(0..=idx);
+				match indices.iter().position(|&x| x == picked) {
+					Some(pos) => indices.insert(pos, idx),
+					None => indices.push(picked),
+				}
+			}
+			indices
+		} else {
+			let mut indices: Vec<usize> = (0..len).collect();
+			self.partial_shuffle(&mut indices, amount);
+			indices.truncate(amount);
+			indices
+		}
+	}
+	/// Pick `amount` distinct elements of a slice, without replacement, in a random order.  
+	/// If `amount` is at least the length of the slice, every element is returned, in a random order.
+	/// See [`RNG::sample_indices`] for how they're picked.
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let entries: Vec<u32> = (0..10_000).collect();
+	/// let winners = rng.choose_multiple(&entries, 5);
+	/// assert_eq!(winners.len(), 5);
+	/// ```
+	#[cfg(feature = "alloc")]
+	fn choose_multiple<'a, T>(&mut self, slice: &'a [T], amount: usize) -> Vec<&'a T> {
+		self.sample_indices(slice.len(), amount)
+			.into_iter()
+			.map(|idx| &slice[idx])
+			.collect()
+	}
+}
+
+/// A trait for RNGs that can be created from, and reseeded with, a seed of a fixed size.
+/// ```rust
+/// use nanorand::{RNG, SeedableRNG, WyRand};
+///
+/// let mut rng = WyRand::from_seed([42; 8]);
+/// let first = rng.generate::<u64>();
+/// rng.reseed([42; 8]);
+/// assert_eq!(rng.generate::<u64>(), first);
+/// ```
+pub trait SeedableRNG: RNG {
+	/// The seed for this RNG, which is a byte array, such as `[u8; 8]`.
+	/// As its size is fixed, a seed can't be too short or too long.
+	type Seed: AsMut<[u8]> + Default;
+
+	/// Create a new instance of this RNG, from the provided seed.
+	fn from_seed(seed: Self::Seed) -> Self;
+	/// Reseeds the RNG, leaving it in a state equivalent to a fresh instance of the same configuration, created with this seed.  
+	/// By default, that's [`SeedableRNG::from_seed`], but an RNG with other settings, such as `ChaCha`'s number of rounds, keeps them.
+	fn reseed(&mut self, seed: Self::Seed) {
+		*self = Self::from_seed(seed);
+	}
+	/// Mix `entropy` into this RNG's current state, rather than replacing it.  
+	/// A seed is drawn from this RNG, XORed with `entropy`, and then used to reseed it,
+	/// so entropy from a bad or attacker-controlled source can't make the state any more predictable than it already was.
+	///
+	/// Panics if `entropy` is all zeroes, as that can only mean the source of it is broken.
+	fn mix_entropy(&mut self, mut entropy: Self::Seed) {
+		assert!(
+			entropy.as_mut().iter().any(|&byte| byte != 0),
+			"Refusing to mix in all-zero entropy!"
+		);
+		let mut seed = Self::Seed::default();
+		self.fill_bytes(seed.as_mut());
+		seed.as_mut()
+			.iter_mut()
+			.zip(entropy.as_mut().iter())
+			.for_each(|(a, b)| *a ^= *b);
+		self.reseed(seed);
+	}
+	/// Mix fresh entropy from the system into this RNG's current state, such as after restoring a VM snapshot, or forking.  
+	/// See [`SeedableRNG::mix_entropy`] for how it's mixed in.
+	#[cfg(feature = "std")]
+	fn reseed_from_system(&mut self) {
+		let mut entropy = Self::Seed::default();
+		let mut fetched = crate::entropy::entropy_from_system(entropy.as_mut().len());
+		entropy.as_mut().copy_from_slice(&fetched);
+		crate::entropy::wipe(&mut fetched);
+		self.mix_entropy(entropy);
+	}
+	/// Create a new instance of this RNG from a single [`u64`].  
+	/// The [`u64`] is expanded into a full seed with SplitMix64, written as little-endian [`u64`]s, the same way for every generator,
+	/// so the same number gives the same seed with every version of nanorand, on every platform,
+	/// and swapping one generator for another with the same seed is predictable. SplitMix64 never gives an all-zero seed from this,
+	/// not even for 0.
+	///
+	/// This is not the same as constructors like [`WyRand::new_seed`](crate::WyRand::new_seed), which use the number as-is.
+	/// ```rust
+	/// use nanorand::{RNG, SeedableRNG, Pcg64, WyRand};
+	///
+	/// let mut a = WyRand::seed_from_u64(42);
+	/// let mut b = WyRand::seed_from_u64(42);
+	/// assert_eq!(a.generate::<u64>(), b.generate::<u64>());
+	///
+	/// let mut pcg = Pcg64::seed_from_u64(42);
+	/// ```
+	fn seed_from_u64(seed: u64) -> Self {
+		let mut expanded = Self::Seed::default();
+		expand_seed(seed, expanded.as_mut());
+		Self::from_seed(expanded)
+	}
+	/// Create a new instance of this RNG from a seed of any length, such as a 32-byte key for a generator with a 16-byte seed.  
+	/// Every byte of the input affects the seed, and it's fit to the seed's length deterministically, on every platform:
+	/// * A seed of exactly the right length is used as-is, the same as [`SeedableRNG::from_seed`].
+	/// * A shorter seed is used as the start of the seed, and the rest is filled with SplitMix64, started from a hash of the input.
+	/// * A longer seed is XORed in, one seed-sized chunk at a time, with a SplitMix64 mixing round after each chunk.
+	/// ```rust
+	/// use nanorand::{RNG, SeedableRNG, WyRand};
+	///
+	/// let key = [7u8; 32];
+	/// let mut rng = WyRand::from_seed_slice(&key);
+	/// let first = rng.generate::<u64>();
+	/// assert_eq!(WyRand::from_seed_slice(&key).generate::<u64>(), first);
+	/// ```
+	fn from_seed_slice(seed: &[u8]) -> Self {
+		let mut absorbed = Self::Seed::default();
+		absorb_seed(seed, absorbed.as_mut());
+		Self::from_seed(absorbed)
+	}
+	/// Reseeds the RNG from a seed of any length, so that it generates the same sequence
+	/// as [`SeedableRNG::from_seed_slice`] with the same seed would.
+	fn reseed_from_slice(&mut self, seed: &[u8]) {
+		let mut absorbed = Self::Seed::default();
+		absorb_seed(seed, absorbed.as_mut());
+		self.reseed(absorbed);
+	}
+	/// Create a new instance of this RNG from a string, such as one passed on the command line.  
+	/// The string's UTF-8 bytes are hashed with 64-bit FNV-1a, and the hash is passed to [`SeedableRNG::seed_from_u64`],
+	/// so the same string gives the same seed with every version of nanorand, on every platform,
+	/// but with the `native-endian` feature enabled, generators read that seed in the platform's byte order,
+	/// so big- and little-endian platforms give different sequences for the same string.
+	///
+	/// The empty string is a seed like any other, and doesn't give an all-zero seed.
+	/// ```rust
+	/// use nanorand::{RNG, SeedableRNG, WyRand};
+	///
+	/// let mut rng = WyRand::from_seed_str("my reproducible run");
+	/// let first = rng.generate::<u64>();
+	/// assert_eq!(WyRand::from_seed_str("my reproducible run").generate::<u64>(), first);
+	/// ```
+	fn from_seed_str(seed: &str) -> Self {
+		Self::seed_from_u64(fnv1a(seed.as_bytes()))
+	}
+	/// Create a child RNG, seeded from this one's output, for handing out to a sub-task.  
+	/// The seed is drawn from this RNG and then mixed with SplitMix64, so the child's sequence doesn't simply follow this one's.
+	///
+	/// Forking advances this RNG, so every fork gets a different child, and a tree of RNGs built by forking
+	/// is reproducible from the seed of the RNG at its root.
+	/// ```rust
+	/// use nanorand::{RNG, SeedableRNG, WyRand};
+	///
+	/// let mut root = WyRand::new_seed(42);
+	/// let mut workers: Vec<WyRand> = (0..4).map(|_| root.fork()).collect();
+	/// let first = workers[0].generate::<u64>();
+	/// ```
+	fn fork(&mut self) -> Self {
+		let mut seed = Self::Seed::default();
+		self.fill_bytes(seed.as_mut());
+		for chunk in seed.as_mut().chunks_mut(8) {
+			let mut bytes = [0u8; 8];
+			bytes[..chunk.len()].copy_from_slice(chunk);
+			let mut state = u64::from_le_bytes(bytes);
+			let mixed = splitmix64(&mut state).to_le_bytes();
+			chunk.copy_from_slice(&mixed[..chunk.len()]);
 		}
+		Self::from_seed(seed)
+	}
+}
+
+impl<R: RNG> RNG for &mut R {
+	type Output = R::Output;
+	const OUTPUT_SIZE: usize = R::OUTPUT_SIZE;
+	const IS_CRYPTO: bool = R::IS_CRYPTO;
+
+	fn rand(&mut self) -> Self::Output {
+		(**self).rand()
+	}
+
+	fn fill_bytes(&mut self, dest: &mut [u8]) {
+		(**self).fill_bytes(dest)
+	}
+
+	fn try_rand(&mut self) -> Result<Self::Output, RandError> {
+		(**self).try_rand()
+	}
+
+	fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RandError> {
+		(**self).try_fill_bytes(dest)
+	}
+}
+
+/// A marker for cryptographically secure RNGs, such as `ChaCha`,
+/// so code that needs unpredictable output, such as for keys or tokens, can reject other generators at compile time.  
+/// Implementations must also set [`RNG::IS_CRYPTO`] to `true`.
+/// ```rust
+/// use nanorand::{CryptoRNG, RNG};
+///
+/// fn session_token(rng: &mut impl CryptoRNG) -> [u8; 32] {
+///     rng.generate_bytes()
+/// }
+/// ```
+/// Fast, insecure generators like [`WyRand`] don't implement it:
+/// ```compile_fail
+/// use nanorand::{CryptoRNG, WyRand};
+///
+/// fn session_token(rng: &mut impl CryptoRNG) {}
+/// session_token(&mut WyRand::new());
+/// ```
+pub trait CryptoRNG: RNG {}
+
+impl<R: CryptoRNG> CryptoRNG for &mut R {}
+
+/// An RNG whose next output is a pure function of its current state,
+/// so it can be looked at without advancing the generator, such as for lookahead in rejection sampling.  
+/// [`WyRand`](crate::WyRand), [`Pcg64`](crate::Pcg64) and [`Xoroshiro128`](crate::Xoroshiro128) implement it.
+/// `ChaCha` doesn't, as peeking would compute a whole block just to throw it away.
+/// ```rust
+/// use nanorand::{PeekableRNG, RNG, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let next = rng.peek();
+/// assert_eq!(rng.peek(), next);
+/// assert_eq!(rng.rand(), next);
+/// ```
+pub trait PeekableRNG: RNG {
+	/// Return the output the next call to [`RNG::rand`] will give, without changing the generator's state.
+	fn peek(&self) -> Self::Output;
+}
+
+impl<R: PeekableRNG> PeekableRNG for &mut R {
+	fn peek(&self) -> Self::Output {
+		(**self).peek()
 	}
-	/// Reseeds the RNG using a custom seed.
-	fn reseed(&mut self, new_seed: &[u8]);
 }
@@ -1,14 +1,18 @@
 // Based off Robert Kern's C implementation at https://github.com/rkern/pcg64/blob/master/pcg64.c
 
-use crate::RNG;
+use crate::{PeekableRNG, SeedableRNG, RNG};
 
 const PCG_DEFAULT_MULTIPLIER_128: u128 = 47026247687942121848144207491837523525;
 
 /// An instance of the Pcg64 random number generator.  
 /// Seeded from the system entropy generator when available.  
 /// **This generator is _NOT_ cryptographically secure.**
-#[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize))]
-#[cfg_attr(feature = "zeroize", zeroize(drop))]
+///
+/// Unlike a textbook PCG, each output is built from the previous one, rather than from a linear congruential state,
+/// so there's no way to jump ahead by many steps at once, and it can't be split into non-overlapping streams.
+/// [`Xoroshiro128::jump`](crate::Xoroshiro128::jump) can be used for that instead.
+#[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize, zeroize::ZeroizeOnDrop))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Pcg64 {
 	seed: u128,
 	state: u128,
@@ -16,22 +20,34 @@ pub struct Pcg64 {
 }
 
 impl Pcg64 {
-	/// Create a new [`Pcg64`] instance, seeding from the system's default source of entropy.
+	/// Create a new [`Pcg64`] instance, seeding from the system's default source of entropy.  
+	/// Panics if the system has no entropy to give, see [`Pcg64::try_new`] for the fallible version.
 	#[cfg(feature = "std")]
 	pub fn new() -> Self {
+		match Self::try_new() {
+			Ok(rng) => rng,
+			Err(err) => panic!("Couldn't seed Pcg64: {}", err),
+		}
+	}
+
+	/// Create a new [`Pcg64`] instance, seeding from the system's default source of entropy,
+	/// or return an [`EntropyError`](crate::entropy::EntropyError) if it's unavailable.
+	#[cfg(feature = "std")]
+	pub fn try_new() -> Result<Self, crate::entropy::EntropyError> {
 		let mut entropy: [u8; core::mem::size_of::<u128>()] = Default::default();
-		entropy.copy_from_slice(&crate::entropy::entropy_from_system(core::mem::size_of::<
-			u128,
-		>()));
-		Self {
+		let mut fetched = crate::entropy::try_entropy_from_system(core::mem::size_of::<u128>())?;
+		entropy.copy_from_slice(&fetched);
+		crate::entropy::wipe(&mut fetched);
+		Ok(Self {
 			seed: u128::from_ne_bytes(entropy),
 			inc: 0,
 			state: 0,
-		}
+		})
 	}
 
-	/// Create a new [`Pcg64`] instance, using a provided seed.
-	pub fn new_seed(seed: u128) -> Self {
+	/// Create a new [`Pcg64`] instance, using a provided seed.  
+	/// This is a `const fn`, so it can be used to initialize a `static`.
+	pub const fn new_seed(seed: u128) -> Self {
 		Self {
 			seed,
 			inc: 0,
@@ -39,6 +55,21 @@ impl Pcg64 {
 		}
 	}
 
+	/// Export the generator's exact internal state, as a little-endian [`u128`], unless the `native-endian` feature is enabled.  
+	/// Passing it to [`Pcg64::from_state`] gives a generator that continues from exactly where this one is.
+	///
+	/// Only the seed carries over between outputs, as the rest of the PCG state is rebuilt from it for every output,
+	/// so that's all this includes.
+	pub fn state(&self) -> [u8; 16] {
+		to_output_bytes!(self.seed)
+	}
+
+	/// Restore a generator from a state exported with [`Pcg64::state`].  
+	/// Every state is valid for Pcg64.
+	pub fn from_state(state: [u8; 16]) -> Self {
+		Self::new_seed(from_output_bytes!(u128, state))
+	}
+
 	#[inline(always)]
 	fn step(&mut self) {
 		self.state = self
@@ -63,15 +94,7 @@ impl Pcg64 {
 impl Default for Pcg64 {
 	/// Create a new [`Pcg64`] instance, seeding from the system's default source of entropy.
 	fn default() -> Self {
-		let mut entropy: [u8; core::mem::size_of::<u128>()] = Default::default();
-		entropy.copy_from_slice(&crate::entropy::entropy_from_system(core::mem::size_of::<
-			u128,
-		>()));
-		Self {
-			seed: u128::from_ne_bytes(entropy),
-			inc: 0,
-			state: 0,
-		}
+		Self::new()
 	}
 }
 
@@ -81,17 +104,24 @@ impl RNG for Pcg64 {
 	fn rand(&mut self) -> Self::Output {
 		let ret = self.rand128();
 		self.seed = self.state ^ (ret as u128).rotate_right(64);
-		ret.to_ne_bytes()
+		to_output_bytes!(ret)
 	}
+}
 
-	fn rand_with_seed(_seed: &[u8]) -> Self::Output {
-		unimplemented!("Pcg64 requires a state!");
+impl PeekableRNG for Pcg64 {
+	fn peek(&self) -> Self::Output {
+		// The rest of the PCG state is rebuilt from the seed for every output, so a copy is all it takes.
+		self.clone().rand()
 	}
+}
 
-	fn reseed(&mut self, new_seed: &[u8]) {
-		let mut seed = [0u8; 16];
-		seed.iter_mut().zip(new_seed).for_each(|(a, b)| *a = *b);
-		self.seed = u128::from_ne_bytes(seed);
+impl SeedableRNG for Pcg64 {
+	type Seed = [u8; 16];
+
+	/// Create a new [`Pcg64`] instance, using the seed as a little-endian [`u128`],
+	/// unless the `native-endian` feature is enabled.
+	fn from_seed(seed: Self::Seed) -> Self {
+		Self::new_seed(from_output_bytes!(u128, seed))
 	}
 }
 
@@ -105,6 +135,38 @@ impl Clone for Pcg64 {
 	}
 }
 
+impl PartialEq for Pcg64 {
+	/// Two [`Pcg64`]s are equal if they'll generate the same sequence.
+	/// Only the seed is compared, as the rest of the state is rebuilt from it for every output.
+	fn eq(&self, other: &Self) -> bool {
+		self.seed == other.seed
+	}
+}
+
+impl Eq for Pcg64 {}
+
+impl core::hash::Hash for Pcg64 {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		self.seed.hash(state);
+	}
+}
+
+impl core::fmt::Debug for Pcg64 {
+	/// Prints `Pcg64 { .. }`, unless the `debug-state` feature is enabled,
+	/// in which case the seed and state are printed too.
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		#[cfg(feature = "debug-state")]
+		return f
+			.debug_struct("Pcg64")
+			.field("seed", &self.seed)
+			.field("state", &self.state)
+			.field("inc", &self.inc)
+			.finish();
+		#[cfg(not(feature = "debug-state"))]
+		return f.write_str("Pcg64 { .. }");
+	}
+}
+
 #[cfg(feature = "std")]
 impl std::fmt::Display for Pcg64 {
 	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -1,44 +1,214 @@
 // Based off lemire's wyrand C++ code at https://github.com/lemire/testingRNG/blob/master/source/wyrand.h
 
-use crate::RNG;
+use crate::{PeekableRNG, SeedableRNG, RNG};
+
+const WY0: u64 = 0xa0761d6478bd642f;
+const WY1: u64 = 0xe7037ed1a0b428db;
+
+/// Hash a single [`u64`] with wyrand's mixing function, without keeping a generator around.  
+/// This is the same as the first output of [`WyRand::new_seed`] with `key` as the seed,
+/// which makes it handy for deterministic values keyed by something, such as coordinates in procedural generation.  
+/// **This is _NOT_ a cryptographic hash.**
+/// ```rust
+/// use nanorand::hash_u64;
+///
+/// let (x, y) = (12_u32, 34_u32);
+/// let tile = hash_u64((x as u64) << 32 | y as u64) % 4;
+/// assert_eq!(tile, hash_u64((x as u64) << 32 | y as u64) % 4);
+/// ```
+pub const fn hash_u64(key: u64) -> u64 {
+	let key = key.wrapping_add(WY0);
+	wymix(key, key ^ WY1)
+}
 
 /// An instance of the WyRand random number generator.
 /// Seeded from the system entropy generator when available.  
 /// **This generator is _NOT_ cryptographically secure.**
-#[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize))]
-#[cfg_attr(feature = "zeroize", zeroize(drop))]
+#[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize, zeroize::ZeroizeOnDrop))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Hash)]
 pub struct WyRand {
 	seed: u64,
 }
 
 impl WyRand {
-	/// Create a new [`WyRand`] instance, seeding from the system's default source of entropy.
+	/// Create a new [`WyRand`] instance, seeding from the system's default source of entropy.  
+	/// Panics if the system has no entropy to give, see [`WyRand::try_new`] for the fallible version.
 	pub fn new() -> Self {
+		match Self::try_new() {
+			Ok(rng) => rng,
+			Err(err) => panic!("Couldn't seed WyRand: {}", err),
+		}
+	}
+
+	/// Create a new [`WyRand`] instance, seeding from the system's default source of entropy,
+	/// or return an [`EntropyError`](crate::entropy::EntropyError) if it's unavailable, such as in a locked-down sandbox.
+	///
+	/// Only the first call fetches entropy, to seed a process-wide master generator, and every instance after that
+	/// is seeded from the master's output, so constructing throwaway generators in a loop doesn't cost a syscall each time.
+	/// On Unix, the first call after a `fork()` fetches entropy again, so the child's new generators differ from the parent's.
+	/// Generators which already existed before the fork are copied into the child as they are,
+	/// so use [`SeedableRNG::reseed_from_system`] on those which must differ between the two.
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::try_new().unwrap_or_else(|_| WyRand::new_seed(42));
+	/// println!("Random number: {}", rng.generate::<u64>());
+	/// ```
+	#[cfg(target_has_atomic = "64")]
+	pub fn try_new() -> Result<Self, crate::entropy::EntropyError> {
+		use core::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+		static MASTER: AtomicU64 = AtomicU64::new(0);
+		// The fork count when the master was last seeded, or `usize::MAX` if it never has been.
+		static MASTER_FORKS: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+		let forks = crate::entropy::fork::count();
+		if MASTER_FORKS.load(Ordering::Acquire) != forks {
+			// Threads racing to seed the master each mix in their own entropy, which does no harm.
+			MASTER.fetch_xor(Self::system_seed()?, Ordering::Relaxed);
+			MASTER_FORKS.store(forks, Ordering::Release);
+		}
+		Ok(Self {
+			seed: hash_u64(MASTER.fetch_add(WY0, Ordering::Relaxed)),
+		})
+	}
+
+	/// Create a new [`WyRand`] instance, seeding from the system's default source of entropy,
+	/// or return an [`EntropyError`](crate::entropy::EntropyError) if it's unavailable, such as in a locked-down sandbox.
+	#[cfg(not(target_has_atomic = "64"))]
+	pub fn try_new() -> Result<Self, crate::entropy::EntropyError> {
+		Ok(Self {
+			seed: Self::system_seed()?,
+		})
+	}
+
+	/// Create a new [`WyRand`] instance, seeded straight from the system's default source of entropy,
+	/// after checking that the source doesn't look stuck, with [`try_checked_entropy_from_system`](crate::entropy::try_checked_entropy_from_system).  
+	/// This is for the paranoid, on platforms where the entropy source might be broken.
+	/// Unlike [`WyRand::new`], this doesn't go through the process-wide master generator, so every call fetches entropy.
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new_checked().expect("the system's entropy source is broken");
+	/// println!("Random number: {}", rng.generate::<u64>());
+	/// ```
+	pub fn new_checked() -> Result<Self, crate::entropy::EntropyError> {
 		let mut entropy: [u8; core::mem::size_of::<u64>()] = Default::default();
-		entropy.copy_from_slice(&crate::entropy::entropy_from_system(core::mem::size_of::<
-			u64,
-		>()));
-		Self {
+		let mut fetched =
+			crate::entropy::try_checked_entropy_from_system(core::mem::size_of::<u64>())?;
+		entropy.copy_from_slice(&fetched);
+		crate::entropy::wipe(&mut fetched);
+		Ok(Self {
 			seed: u64::from_ne_bytes(entropy),
-		}
+		})
 	}
 
-	/// Create a new [`WyRand`] instance, using a provided seed.
-	pub fn new_seed(seed: u64) -> Self {
+	fn system_seed() -> Result<u64, crate::entropy::EntropyError> {
+		let mut entropy: [u8; core::mem::size_of::<u64>()] = Default::default();
+		let mut fetched = crate::entropy::try_entropy_from_system(core::mem::size_of::<u64>())?;
+		entropy.copy_from_slice(&fetched);
+		crate::entropy::wipe(&mut fetched);
+		Ok(u64::from_ne_bytes(entropy))
+	}
+
+	/// Create a new [`WyRand`] instance, using a provided seed.  
+	/// This is a `const fn`, so it can be used to initialize a `static`, even without the `std` feature:
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// static SEEDED: WyRand = WyRand::new_seed(0xDEADBEEF);
+	///
+	/// let mut rng = SEEDED.clone();
+	/// assert_eq!(rng.generate::<u64>(), WyRand::new_seed(0xDEADBEEF).generate::<u64>());
+	/// ```
+	pub const fn new_seed(seed: u64) -> Self {
 		Self { seed }
 	}
+
+	/// Create a new [`WyRand`] instance from a string, such as one passed on the command line.  
+	/// This is the same as [`SeedableRNG::from_seed_str`], without needing to import [`SeedableRNG`].
+	pub fn new_seed_str(seed: &str) -> Self {
+		Self::from_seed_str(seed)
+	}
+
+	/// Create a new [`WyRand`] instance from a memorable passphrase, such as `"my-seed-2024"`.  
+	/// This is another name for [`WyRand::new_seed_str`], so it hashes the passphrase the same way as [`SeedableRNG::from_seed_str`],
+	/// and the two give the same generator for the same string.
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::from_passphrase("my-seed-2024");
+	/// let first = rng.generate::<u64>();
+	/// assert_eq!(WyRand::from_passphrase("my-seed-2024").generate::<u64>(), first);
+	/// ```
+	pub fn from_passphrase(passphrase: &str) -> Self {
+		Self::new_seed_str(passphrase)
+	}
+
+	/// Create a new [`WyRand`] instance in a `const` context, using a provided seed.  
+	/// This is the same as [`WyRand::new_seed`], and is only a separate function so it's easy to find
+	/// when looking for a way to build a generator in a `static` or `const`.
+	pub const fn from_seed_const(seed: u64) -> Self {
+		Self::new_seed(seed)
+	}
+
+	/// Export the generator's exact internal state, as a little-endian [`u64`], unless the `native-endian` feature is enabled.  
+	/// Passing it to [`WyRand::from_state`] gives a generator that continues from exactly where this one is.
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let saved = rng.state();
+	/// let first = rng.generate::<u64>();
+	/// assert_eq!(WyRand::from_state(saved).generate::<u64>(), first);
+	/// ```
+	pub fn state(&self) -> [u8; 8] {
+		to_output_bytes!(self.seed)
+	}
+
+	/// Restore a generator from a state exported with [`WyRand::state`].  
+	/// Every state is valid for WyRand.
+	pub fn from_state(state: [u8; 8]) -> Self {
+		Self::new_seed(from_output_bytes!(u64, state))
+	}
+
+	/// Create a child generator keyed by `label`, by mixing this generator's current state with the label, using wyrand's mixing function.  
+	/// The same parent state and label always give the same child, and different labels give unrelated children,
+	/// which makes it handy for reproducible streams per entity, such as one per NPC in a game, keyed by its name or ID.
+	/// Unlike [`SeedableRNG::fork`], this doesn't advance the parent.
+	/// ```rust
+	/// use nanorand::{RNG, WyRand};
+	///
+	/// let world = WyRand::new_seed(42);
+	/// let mut goblin = world.derive(b"goblin");
+	/// let mut troll = world.derive(b"troll");
+	/// assert_eq!(world.derive(b"goblin").generate::<u64>(), goblin.generate::<u64>());
+	/// ```
+	pub fn derive(&self, label: &[u8]) -> Self {
+		let mut seed = self.seed;
+		// Each step hashes the running state and the chunk together, so wymix's product can only vanish
+		// for a particular pairing of the two, rather than for some parent state or chunk on its own.
+		for chunk in label.chunks(8) {
+			let mut bytes = [0u8; 8];
+			bytes[..chunk.len()].copy_from_slice(chunk);
+			seed = hash_u64(seed ^ u64::from_le_bytes(bytes));
+		}
+		// The length keeps labels that only differ by trailing zero bytes apart.
+		Self::new_seed(hash_u64(seed ^ label.len() as u64))
+	}
+}
+
+/// wyrand's mixing function: the two halves of the 128-bit product of `a` and `b`, XORed together.
+const fn wymix(a: u64, b: u64) -> u64 {
+	let t: u128 = (a as u128).wrapping_mul(b as u128);
+	((t >> 64) ^ t) as u64
 }
 
 impl Default for WyRand {
 	/// Create a new [`WyRand`] instance, seeding from the system's default source of entropy.
 	fn default() -> Self {
-		let mut entropy: [u8; core::mem::size_of::<u64>()] = Default::default();
-		entropy.copy_from_slice(&crate::entropy::entropy_from_system(core::mem::size_of::<
-			u64,
-		>()));
-		Self {
-			seed: u64::from_ne_bytes(entropy),
-		}
+		Self::new()
 	}
 }
 
@@ -46,26 +216,29 @@ impl RNG for WyRand {
 	type Output = [u8; 8];
 
 	fn rand(&mut self) -> Self::Output {
-		self.seed = self.seed.wrapping_add(0xa0761d6478bd642f);
-		let t: u128 = (self.seed as u128).wrapping_mul((self.seed ^ 0xe7037ed1a0b428db) as u128);
-		let ret = ((t >> 64) ^ t) as u64;
-		ret.to_ne_bytes()
+		let ret = hash_u64(self.seed);
+		self.seed = self.seed.wrapping_add(WY0);
+		to_output_bytes!(ret)
 	}
 
 	fn rand_with_seed(seed: &[u8]) -> Self::Output {
-		let mut seed_bytes = [0u8; 8];
-		seed_bytes.iter_mut().zip(seed).for_each(|(a, b)| *a = *b);
-		let seed = u64::from_ne_bytes(seed_bytes);
-		let seed = seed.wrapping_add(0xa0761d6478bd642f);
-		let t: u128 = (seed as u128).wrapping_mul((seed ^ 0xe7037ed1a0b428db) as u128);
-		let ret = ((t >> 64) ^ t) as u64;
-		ret.to_ne_bytes()
+		Self::from_seed_slice(seed).rand()
 	}
+}
 
-	fn reseed(&mut self, new_seed: &[u8]) {
-		let mut seed = [0u8; 8];
-		seed.iter_mut().zip(new_seed).for_each(|(a, b)| *a = *b);
-		self.seed = u64::from_ne_bytes(seed)
+impl PeekableRNG for WyRand {
+	fn peek(&self) -> Self::Output {
+		to_output_bytes!(hash_u64(self.seed))
+	}
+}
+
+impl SeedableRNG for WyRand {
+	type Seed = [u8; 8];
+
+	/// Create a new [`WyRand`] instance, using the seed as a little-endian [`u64`],
+	/// unless the `native-endian` feature is enabled.
+	fn from_seed(seed: Self::Seed) -> Self {
+		Self::new_seed(from_output_bytes!(u64, seed))
 	}
 }
 
@@ -75,6 +248,17 @@ impl Clone for WyRand {
 	}
 }
 
+impl core::fmt::Debug for WyRand {
+	/// Prints `WyRand { .. }`, unless the `debug-state` feature is enabled,
+	/// in which case the seed is printed too, as it's enough to reproduce every future output.
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		#[cfg(feature = "debug-state")]
+		return f.debug_struct("WyRand").field("seed", &self.seed).finish();
+		#[cfg(not(feature = "debug-state"))]
+		return f.write_str("WyRand { .. }");
+	}
+}
+
 #[cfg(feature = "std")]
 impl std::fmt::Display for WyRand {
 	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
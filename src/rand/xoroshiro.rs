@@ -0,0 +1,232 @@
+// Based off Blackman and Vigna's C code at https://prng.di.unimi.it/xoroshiro128plusplus.c
+
+use crate::{
+	rand::{splitmix64_mix, SPLITMIX64_GAMMA},
+	PeekableRNG, SeedableRNG, RNG,
+};
+
+const XOROSHIRO_JUMP: [u64; 2] = [0x2bd7a6a6e99c2ddc, 0x0992ccaf6a6fca05];
+const XOROSHIRO_LONG_JUMP: [u64; 2] = [0x360fd5f2cf8d5d99, 0x9c6e6877736c46e3];
+
+/// An instance of the xoroshiro128++ random number generator.  
+/// Seeded from the system entropy generator when available.  
+/// **This generator is _NOT_ cryptographically secure.**
+#[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize, zeroize::ZeroizeOnDrop))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(PartialEq, Eq, Hash)]
+pub struct Xoroshiro128 {
+	state: [u64; 2],
+}
+
+impl Xoroshiro128 {
+	/// Create a new [`Xoroshiro128`] instance, seeding from the system's default source of entropy.  
+	/// Panics if the system has no entropy to give, see [`Xoroshiro128::try_new`] for the fallible version.
+	#[cfg(feature = "std")]
+	pub fn new() -> Self {
+		match Self::try_new() {
+			Ok(rng) => rng,
+			Err(err) => panic!("Couldn't seed Xoroshiro128: {}", err),
+		}
+	}
+
+	/// Create a new [`Xoroshiro128`] instance, seeding from the system's default source of entropy,
+	/// or return an [`EntropyError`](crate::entropy::EntropyError) if it's unavailable.
+	#[cfg(feature = "std")]
+	pub fn try_new() -> Result<Self, crate::entropy::EntropyError> {
+		let mut entropy: [u8; core::mem::size_of::<u128>()] = Default::default();
+		let mut fetched = crate::entropy::try_entropy_from_system(core::mem::size_of::<u128>())?;
+		entropy.copy_from_slice(&fetched);
+		crate::entropy::wipe(&mut fetched);
+		Ok(Self::from_u128(u128::from_ne_bytes(entropy)))
+	}
+
+	/// Use `seed` as the state, unless it's zero, which would only ever output zeros,
+	/// so every seeding path goes through this to swap it for [`Xoroshiro128::new_seed`] with 0.
+	fn from_u128(seed: u128) -> Self {
+		if seed == 0 {
+			return Self::new_seed(0);
+		}
+		Self {
+			state: [seed as u64, (seed >> 64) as u64],
+		}
+	}
+
+	/// Create a new [`Xoroshiro128`] instance, expanding the provided seed into the full state with SplitMix64.  
+	/// This is a `const fn`, so it can be used to initialize a `static`.
+	pub const fn new_seed(seed: u64) -> Self {
+		// The first two outputs of SplitMix64, started from `seed`.
+		let first = seed.wrapping_add(SPLITMIX64_GAMMA);
+		Self {
+			state: [
+				splitmix64_mix(first),
+				splitmix64_mix(first.wrapping_add(SPLITMIX64_GAMMA)),
+			],
+		}
+	}
+
+	/// Export the generator's exact internal state, as two little-endian [`u64`]s, unless the `native-endian` feature is enabled.  
+	/// Passing it to [`Xoroshiro128::from_state`] gives a generator that continues from exactly where this one is.
+	pub fn state(&self) -> [u8; 16] {
+		let mut state = [0u8; 16];
+		state[..8].copy_from_slice(&to_output_bytes!(self.state[0]));
+		state[8..].copy_from_slice(&to_output_bytes!(self.state[1]));
+		state
+	}
+
+	/// Restore a generator from a state exported with [`Xoroshiro128::state`].  
+	/// Unlike [`SeedableRNG::from_seed`], the state is used as-is, without any adjustment.
+	///
+	/// Panics if the state is all zeroes, as xoroshiro128++ would only ever output zeroes from it,
+	/// and [`Xoroshiro128::state`] never returns that.
+	pub fn from_state(state: [u8; 16]) -> Self {
+		let mut low = [0u8; 8];
+		let mut high = [0u8; 8];
+		low.copy_from_slice(&state[..8]);
+		high.copy_from_slice(&state[8..]);
+		let state = [from_output_bytes!(u64, low), from_output_bytes!(u64, high)];
+		assert!(
+			state != [0, 0],
+			"The xoroshiro128++ state must not be all zeroes!"
+		);
+		Self { state }
+	}
+
+	/// Advance the generator by 2^64 steps, as if [`RNG::rand`] had been called that many times.  
+	/// This can be used to create 2^64 non-overlapping streams for parallel computations.
+	pub fn jump(&mut self) {
+		self.jump_with(&XOROSHIRO_JUMP);
+	}
+
+	/// Advance the generator by 2^96 steps, as if [`RNG::rand`] had been called that many times.  
+	/// This can be used to create 2^32 starting points, each of which can then be split into 2^32 streams with [`Xoroshiro128::jump`],
+	/// such as one starting point per machine, and one stream per thread.
+	/// ```rust
+	/// use nanorand::{RNG, Xoroshiro128};
+	///
+	/// let mut rng = Xoroshiro128::new();
+	/// let streams: Vec<Xoroshiro128> = (0..4)
+	///     .map(|_| {
+	///         let stream = rng.clone();
+	///         rng.long_jump();
+	///         stream
+	///     })
+	///     .collect();
+	/// ```
+	pub fn long_jump(&mut self) {
+		self.jump_with(&XOROSHIRO_LONG_JUMP);
+	}
+
+	/// Apply a jump polynomial to the state, as in the reference implementation.
+	fn jump_with(&mut self, polynomial: &[u64; 2]) {
+		let mut jumped = [0u64; 2];
+		for word in polynomial.iter() {
+			for bit in 0..64 {
+				if word & (1 << bit) != 0 {
+					jumped[0] ^= self.state[0];
+					jumped[1] ^= self.state[1];
+				}
+				self.step();
+			}
+		}
+		self.state = jumped;
+	}
+
+	#[inline(always)]
+	fn output(&self) -> u64 {
+		let [s0, s1] = self.state;
+		s0.wrapping_add(s1).rotate_left(17).wrapping_add(s0)
+	}
+
+	#[inline(always)]
+	fn step(&mut self) -> u64 {
+		let ret = self.output();
+		let [s0, mut s1] = self.state;
+		s1 ^= s0;
+		self.state = [s0.rotate_left(49) ^ s1 ^ (s1 << 21), s1.rotate_left(28)];
+		ret
+	}
+}
+
+#[cfg(feature = "std")]
+impl Default for Xoroshiro128 {
+	/// Create a new [`Xoroshiro128`] instance, seeding from the system's default source of entropy.
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl RNG for Xoroshiro128 {
+	type Output = [u8; 8];
+
+	fn rand(&mut self) -> Self::Output {
+		to_output_bytes!(self.step())
+	}
+
+	fn rand_with_seed(seed: &[u8]) -> Self::Output {
+		Self::from_seed_slice(seed).rand()
+	}
+}
+
+impl PeekableRNG for Xoroshiro128 {
+	fn peek(&self) -> Self::Output {
+		to_output_bytes!(self.output())
+	}
+}
+
+impl SeedableRNG for Xoroshiro128 {
+	type Seed = [u8; 16];
+
+	/// Create a new [`Xoroshiro128`] instance, using the seed as the state, in little-endian [`u64`]s,
+	/// unless the `native-endian` feature is enabled.  
+	/// The all-zero state would only ever output zeros, so an all-zero seed is treated as [`Xoroshiro128::new_seed`] with 0 instead.
+	fn from_seed(seed: Self::Seed) -> Self {
+		Self::from_u128(from_output_bytes!(u128, seed))
+	}
+}
+
+impl Clone for Xoroshiro128 {
+	fn clone(&self) -> Self {
+		Self { state: self.state }
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Xoroshiro128 {
+	/// Deserialize a [`Xoroshiro128`], rejecting an all-zero state, which would only ever output zeroes.
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		#[derive(serde::Deserialize)]
+		#[serde(rename = "Xoroshiro128")]
+		struct State {
+			state: [u64; 2],
+		}
+
+		let State { state } = State::deserialize(deserializer)?;
+		if state == [0, 0] {
+			return Err(serde::de::Error::custom(
+				"xoroshiro128++ state must not be all zeroes",
+			));
+		}
+		Ok(Self { state })
+	}
+}
+
+impl core::fmt::Debug for Xoroshiro128 {
+	/// Prints `Xoroshiro128 { .. }`, unless the `debug-state` feature is enabled,
+	/// in which case the state is printed too.
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		#[cfg(feature = "debug-state")]
+		return f
+			.debug_struct("Xoroshiro128")
+			.field("state", &self.state)
+			.finish();
+		#[cfg(not(feature = "debug-state"))]
+		return f.write_str("Xoroshiro128 { .. }");
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for Xoroshiro128 {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		write!(f, "Xoroshiro128 ({:p})", self)
+	}
+}
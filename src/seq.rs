@@ -0,0 +1,252 @@
+use crate::{rand::splitmix64, RNG};
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+/// Generate a uniformly random permutation of `0..n`, using a Fisher–Yates shuffle.  
+/// Each index appears exactly once, and every ordering is equally likely.
+/// ```rust
+/// use nanorand::{seq::permutation, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let order = permutation(&mut rng, 5);
+/// let mut sorted = order.clone();
+/// sorted.sort_unstable();
+/// assert_eq!(sorted, [0, 1, 2, 3, 4]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn permutation<R: RNG>(r: &mut R, n: usize) -> Vec<usize> {
+	let mut indices: Vec<usize> = (0..n).collect();
+	r.shuffle(&mut indices);
+	indices
+}
+
+/// Return a shuffled clone of `items`, leaving `items` itself untouched.  
+/// This clones every item, then does the same Fisher–Yates shuffle as [`RNG::shuffle`].
+/// ```rust
+/// use nanorand::{seq::shuffled, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let deck = ['A', 'K', 'Q', 'J'];
+/// let mut dealt = shuffled(&mut rng, &deck);
+/// dealt.sort_unstable();
+/// assert_eq!(dealt, ['A', 'J', 'K', 'Q']);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn shuffled<R: RNG, T: Clone>(r: &mut R, items: &[T]) -> Vec<T> {
+	let mut copy = items.to_vec();
+	r.shuffle(&mut copy);
+	copy
+}
+
+/// How many Feistel rounds [`Permutation`] runs per step.
+const PERMUTATION_ROUNDS: usize = 6;
+
+/// A lazy iterator over a random permutation of `0..n`, which yields each index exactly once, using O(1) memory.  
+/// Unlike [`permutation`], nothing is materialized, so it can permute far more indices than would fit in memory.
+///
+/// Indices are encrypted with a small Feistel network keyed from the RNG, over the smallest power-of-4 domain that fits `n`,
+/// and any that land outside `0..n` are encrypted again until they're back within it ("cycle walking").
+/// The domain is at most 4 times `n`, so that's under 4 encryptions per index on average.  
+/// This is a pseudorandom permutation, rather than a perfectly uniform one: for large `n`,
+/// there are far more orderings than there are keys, so only some of them can come up.
+/// Use [`RNG::shuffle`] when every ordering must be equally likely.
+/// ```rust
+/// use nanorand::{seq::Permutation, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let order = Permutation::new(&mut rng, 100_000_000);
+/// assert_eq!(order.len(), 100_000_000);
+/// let first_batch: Vec<usize> = order.take(10).collect();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Permutation {
+	keys: [u64; PERMUTATION_ROUNDS],
+	half_bits: u32,
+	len: usize,
+	next: usize,
+}
+
+impl Permutation {
+	/// Create a lazy permutation of `0..n`, keyed from the RNG.  
+	/// The same RNG state always gives the same order.
+	pub fn new<R: RNG>(r: &mut R, n: usize) -> Self {
+		let mut keys = [0; PERMUTATION_ROUNDS];
+		r.fill(&mut keys);
+		// The bits it takes to hold `n - 1`, rounded up to an even number, so the halves are equal.
+		let bits = usize::BITS - (n.max(2) - 1).leading_zeros();
+		Self {
+			keys,
+			half_bits: (bits + 1) / 2,
+			len: n,
+			next: 0,
+		}
+	}
+
+	/// Encrypt an index within the domain of `4^half_bits` indices, returning another within it.
+	fn encrypt(&self, idx: u64) -> u64 {
+		let mask = (1 << self.half_bits) - 1;
+		let (mut left, mut right) = (idx >> self.half_bits, idx & mask);
+		for key in self.keys.iter() {
+			let mut state = right ^ key;
+			let mixed = left ^ (splitmix64(&mut state) & mask);
+			left = right;
+			right = mixed;
+		}
+		(left << self.half_bits) | right
+	}
+}
+
+impl Iterator for Permutation {
+	type Item = usize;
+
+	fn next(&mut self) -> Option<usize> {
+		if self.next >= self.len {
+			return None;
+		}
+		let mut idx = self.encrypt(self.next as u64);
+		// Every cycle of the encryption that starts within `0..len` comes back within it.
+		while idx >= self.len as u64 {
+			idx = self.encrypt(idx);
+		}
+		self.next += 1;
+		Some(idx as usize)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.len - self.next;
+		(remaining, Some(remaining))
+	}
+}
+
+impl ExactSizeIterator for Permutation {}
+
+impl core::iter::FusedIterator for Permutation {}
+
+/// Pick `k` items from `iter` without knowing its length ahead of time, using reservoir sampling (Algorithm R).  
+/// Every item has an equal chance of being picked. If `iter` has `k` or fewer items, all of them are returned.  
+/// The order of the returned items is not random; shuffle them if that matters.
+/// ```rust
+/// use nanorand::{seq::reservoir_sample, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let lines = "a\nb\nc\nd\ne".lines();
+/// let picked = reservoir_sample(&mut rng, lines, 2);
+/// assert_eq!(picked.len(), 2);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn reservoir_sample<R: RNG, T, I: Iterator<Item = T>>(
+	r: &mut R,
+	mut iter: I,
+	k: usize,
+) -> Vec<T> {
+	let mut reservoir: Vec<T> = iter.by_ref().take(k).collect();
+	if reservoir.len() < k {
+		return reservoir;
+	}
+	for (idx, item) in iter.enumerate() {
+		let slot = r.generate_range(0..=idx + k);
+		if slot < k {
+			reservoir[slot] = item;
+		}
+	}
+	reservoir
+}
+
+/// Remove a uniformly random item from `items` and return it, or [`None`] if it's empty.  
+/// The item is removed with [`Vec::swap_remove`], so this is O(1), but the last item takes the removed one's place.
+/// Calling this until it returns [`None`] drains the vector in a random order.
+/// ```rust
+/// use nanorand::{seq::take_random, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let mut deck: Vec<u8> = (1..=52).collect();
+/// let hand: Vec<u8> = (0..5).filter_map(|_| take_random(&mut rng, &mut deck)).collect();
+/// assert_eq!(deck.len(), 47);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn take_random<R: RNG, T>(r: &mut R, items: &mut Vec<T>) -> Option<T> {
+	let idx = r.random_index(items.len())?;
+	Some(items.swap_remove(idx))
+}
+
+/// Generate a string of `len` characters, each picked uniformly from the characters in `charset`.  
+/// The charset is split into [`char`]s, so multi-byte characters work, and a character listed twice is twice as likely.
+/// ```rust
+/// use nanorand::{seq::random_from_charset, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let code = random_from_charset(&mut rng, "ABCDEFGHJKLMNPQRSTUVWXYZ23456789", 8);
+/// assert_eq!(code.chars().count(), 8);
+/// ```
+/// Panics if `charset` is empty, unless `len` is 0.
+#[cfg(feature = "alloc")]
+pub fn random_from_charset<R: RNG>(r: &mut R, charset: &str, len: usize) -> String {
+	let chars: Vec<char> = charset.chars().collect();
+	assert!(
+		len == 0 || !chars.is_empty(),
+		"Can't pick characters from an empty charset!"
+	);
+	(0..len)
+		.map(|_| chars[r.generate_range(0..chars.len())])
+		.collect()
+}
+
+/// Pick one of the `choices`, where each is paired with its weight, with a probability proportional to its weight.  
+/// Returns [`None`] if there are no choices, or if every weight is 0.
+/// ```rust
+/// use nanorand::{seq::choose_weighted, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let loot = [("common", 90), ("rare", 9), ("legendary", 1)];
+/// let drop = choose_weighted(&mut rng, &loot);
+/// assert!(drop.is_some());
+/// ```
+pub fn choose_weighted<'a, R: RNG, T>(r: &mut R, choices: &'a [(T, u32)]) -> Option<&'a T> {
+	let total = total_weight(choices);
+	if total == 0 {
+		return None;
+	}
+	Some(pick_weighted(r, choices, total))
+}
+
+/// Fill `dest` with clones of the `choices`, where each is paired with its weight, picking each one with a probability proportional to its weight.  
+/// Panics if `dest` isn't empty, and there are no choices, or every weight is 0.
+/// ```rust
+/// use nanorand::{seq::fill_weighted, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let mut grades = ['?'; 100];
+/// fill_weighted(&mut rng, &mut grades, &[('A', 70), ('B', 30)]);
+/// assert!(grades.iter().all(|&grade| grade == 'A' || grade == 'B'));
+/// ```
+pub fn fill_weighted<R: RNG, T: Clone>(r: &mut R, dest: &mut [T], choices: &[(T, u32)]) {
+	if dest.is_empty() {
+		return;
+	}
+	let total = total_weight(choices);
+	assert!(
+		total > 0,
+		"There must be at least one choice with a weight above 0!"
+	);
+	for slot in dest.iter_mut() {
+		*slot = pick_weighted(r, choices, total).clone();
+	}
+}
+
+/// The sum of all weights, which can't overflow, as it's summed as a [`u64`].
+fn total_weight<T>(choices: &[(T, u32)]) -> u64 {
+	choices.iter().map(|(_, weight)| *weight as u64).sum()
+}
+
+/// Pick one of the `choices`, given their `total` weight, which must be above 0.
+fn pick_weighted<'a, R: RNG, T>(r: &mut R, choices: &'a [(T, u32)], total: u64) -> &'a T {
+	let mut target = r.generate_range(0..total);
+	for (choice, weight) in choices {
+		let weight = *weight as u64;
+		if target < weight {
+			return choice;
+		}
+		target -= weight;
+	}
+	unreachable!("The target is always below the total weight")
+}
@@ -1,40 +1,43 @@
-use crate::WyRand;
+use crate::DefaultRng;
 use core::{
 	cell::UnsafeCell,
 	ops::{Deref, DerefMut},
 };
 
 thread_local! {
-	static WYRAND: UnsafeCell<WyRand> = UnsafeCell::new(WyRand::new());
+	static RNG: UnsafeCell<DefaultRng> = UnsafeCell::new(DefaultRng::default());
 }
 
 #[doc(hidden)]
-pub struct TlsWyRand(*mut WyRand);
+pub struct TlsRng(*mut DefaultRng);
 
-impl Deref for TlsWyRand {
-	type Target = WyRand;
+#[doc(hidden)]
+pub type TlsWyRand = TlsRng;
+
+impl Deref for TlsRng {
+	type Target = DefaultRng;
 
-	/// Safety: [`TlsWyRand`] is neither [Send] nor [Sync], and thus,
-	/// there will always be a thread-local [`WyRand`] when there is a [`TlsWyRand`]
+	/// Safety: [`TlsRng`] is neither [Send] nor [Sync], and thus,
+	/// there will always be a thread-local [`DefaultRng`] when there is a [`TlsRng`]
 	fn deref(&self) -> &Self::Target {
 		unsafe { &*self.0 }
 	}
 }
 
-impl DerefMut for TlsWyRand {
-	/// Safety: [`TlsWyRand`] is neither [Send] nor [Sync], and thus,
-	/// there will always be a thread-local [`WyRand`] when there is a [`TlsWyRand`]
+impl DerefMut for TlsRng {
+	/// Safety: [`TlsRng`] is neither [Send] nor [Sync], and thus,
+	/// there will always be a thread-local [`DefaultRng`] when there is a [`TlsRng`]
 	fn deref_mut(&mut self) -> &mut Self::Target {
 		unsafe { &mut *self.0 }
 	}
 }
 
-/// Fetch a thread-local [WyRand]
+/// Fetch a thread-local [`DefaultRng`]
 /// ```rust
 /// use nanorand::RNG;
 ///
 /// fn main() {
-///		let mut rng = nanorand::tls_rng();
+///     let mut rng = nanorand::tls_rng();
 ///     println!("Random number: {}", rng.generate::<u64>());
 /// }
 /// ```
@@ -43,12 +46,12 @@ impl DerefMut for TlsWyRand {
 /// use nanorand::RNG;
 ///
 /// fn main() {
-///		let mut rng = nanorand::tls_rng();
+///     let mut rng = nanorand::tls_rng();
 ///     std::thread::spawn(move || {
 ///         println!("Random number: {}", rng.generate::<u64>());
 ///     });
 /// }
 /// ```
-pub fn tls_rng() -> TlsWyRand {
-	WYRAND.with(|tls| TlsWyRand(tls.get()))
+pub fn tls_rng() -> TlsRng {
+	RNG.with(|tls| TlsRng(tls.get()))
 }
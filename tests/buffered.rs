@@ -0,0 +1,84 @@
+use nanorand::{adapter::BufferedRng, SeedableRNG, WyRand, RNG};
+
+/// Counts how many outputs are pulled from the wrapped RNG.
+struct CountingRng {
+	rng: WyRand,
+	calls: usize,
+}
+
+impl RNG for CountingRng {
+	type Output = [u8; 8];
+	const OUTPUT_SIZE: usize = 8;
+
+	fn rand(&mut self) -> Self::Output {
+		self.calls += 1;
+		self.rng.rand()
+	}
+}
+
+fn counting() -> CountingRng {
+	CountingRng {
+		rng: WyRand::new_seed(42),
+		calls: 0,
+	}
+}
+
+#[test]
+fn byte_streams_use_fewer_outputs() {
+	let mut unbuffered = counting();
+	for _ in 0..800 {
+		unbuffered.generate::<u8>();
+	}
+	assert_eq!(unbuffered.calls, 800);
+
+	let mut buffered = BufferedRng::new(counting());
+	for _ in 0..800 {
+		buffered.generate::<u8>();
+	}
+	assert_eq!(buffered.into_inner().calls, 100);
+
+	let mut buffered = BufferedRng::new(counting());
+	for _ in 0..800 {
+		buffered.generate::<bool>();
+	}
+	assert_eq!(buffered.into_inner().calls, 100);
+}
+
+#[test]
+fn buffered_bytes_are_the_output_stream() {
+	let mut expected = WyRand::new_seed(42);
+	let mut expected_bytes = [0u8; 64];
+	expected.fill_bytes(&mut expected_bytes);
+
+	let mut rng = BufferedRng::new(WyRand::new_seed(42));
+	let mut bytes = [0u8; 64];
+	// Uneven pieces, which straddle outputs.
+	let (a, rest) = bytes.split_at_mut(3);
+	let (b, c) = rest.split_at_mut(20);
+	rng.fill_bytes(a);
+	rng.fill_bytes(b);
+	rng.fill_bytes(c);
+	assert_eq!(bytes, expected_bytes);
+}
+
+#[test]
+fn remaining_and_flush() {
+	let mut rng = BufferedRng::new(WyRand::new_seed(42));
+	assert_eq!(rng.remaining(), 0);
+	rng.generate::<u8>();
+	assert_eq!(rng.remaining(), 7);
+	rng.generate::<u16>();
+	assert_eq!(rng.remaining(), 5);
+	rng.flush();
+	assert_eq!(rng.remaining(), 0);
+
+	// After a flush, the next request lines up with the start of the wrapped RNG's next output.
+	let mut expected = WyRand::new_seed(42);
+	expected.rand();
+	assert_eq!(rng.generate::<u64>(), expected.generate::<u64>());
+
+	rng.generate::<u8>();
+	rng.reseed(7u64.to_le_bytes());
+	assert_eq!(rng.remaining(), 0);
+	assert_eq!(rng.generate::<u64>(), WyRand::new_seed(7).generate::<u64>());
+}
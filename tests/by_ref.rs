@@ -0,0 +1,32 @@
+use nanorand::{WyRand, RNG};
+
+fn takes_rng(mut r: impl RNG) -> u64 {
+	r.generate()
+}
+
+#[test]
+fn mut_ref_is_rng() {
+	let mut rng = WyRand::new_seed(42);
+	let mut expected = WyRand::new_seed(42);
+
+	let first = takes_rng(&mut rng);
+	let second = takes_rng(&mut rng);
+	assert_eq!(first, expected.generate::<u64>());
+	assert_eq!(second, expected.generate::<u64>());
+
+	// The original generator picks up where the borrows left off.
+	assert_eq!(rng.generate::<u64>(), expected.generate::<u64>());
+}
+
+#[test]
+fn mut_ref_forwards() {
+	let mut rng = WyRand::new_seed(42);
+	let mut borrowed = &mut rng;
+	let mut bytes = [0u8; 24];
+	<&mut WyRand as RNG>::fill_bytes(&mut borrowed, &mut bytes);
+
+	let mut expected = WyRand::new_seed(42);
+	let mut expected_bytes = [0u8; 24];
+	expected.fill_bytes(&mut expected_bytes);
+	assert_eq!(bytes, expected_bytes);
+}
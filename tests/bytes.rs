@@ -0,0 +1,94 @@
+use nanorand::{WyRand, RNG};
+
+#[test]
+fn generate_bytes_test() {
+	let mut rng = WyRand::new_seed(42);
+	let nonce: [u8; 12] = rng.generate_bytes();
+	let key = rng.generate_bytes::<32>();
+
+	let mut expected = WyRand::new_seed(42);
+	let mut expected_bytes = [0u8; 44];
+	expected.fill_bytes(&mut expected_bytes[..12]);
+	expected.fill_bytes(&mut expected_bytes[12..]);
+	assert_eq!(nonce[..], expected_bytes[..12]);
+	assert_eq!(key[..], expected_bytes[12..]);
+	assert_ne!(key, [0u8; 32]);
+}
+
+fn concatenated_outputs<R: RNG>(rng: &mut R, len: usize) -> Vec<u8> {
+	let mut bytes = Vec::new();
+	while bytes.len() < len {
+		bytes.extend_from_slice(rng.rand().as_ref());
+	}
+	bytes.truncate(len);
+	bytes
+}
+
+#[test]
+fn fill_bytes_matches_rand() {
+	for &len in [0, 1, 3, 8, 13, 16, 64, 100].iter() {
+		let mut rng = WyRand::new_seed(42);
+		let mut bytes = vec![0u8; len];
+		rng.fill_bytes(&mut bytes);
+		let mut expected_rng = WyRand::new_seed(42);
+		let expected = concatenated_outputs(&mut expected_rng, len);
+		assert_eq!(bytes, expected);
+		// Whatever was left over from the last output is discarded.
+		assert_eq!(rng.rand(), expected_rng.rand());
+	}
+}
+
+#[test]
+#[cfg(feature = "chacha")]
+fn chacha_fill_bytes_matches_rand() {
+	use nanorand::ChaCha;
+
+	for &len in [0, 1, 4, 13, 64, 65, 200].iter() {
+		let mut rng = ChaCha::new_key(20, [7; 32], [9; 16]);
+		let mut bytes = vec![0u8; len];
+		rng.fill_bytes(&mut bytes);
+		let mut expected_rng = ChaCha::new_key(20, [7; 32], [9; 16]);
+		let expected = concatenated_outputs(&mut expected_rng, len);
+		assert_eq!(bytes, expected);
+		assert_eq!(rng.rand().to_vec(), expected_rng.rand().to_vec());
+	}
+}
+
+/// Fill a buffer one byte at a time, from whole outputs, the obvious way.
+fn naive_fill<R: RNG>(rng: &mut R, dest: &mut [u8]) {
+	let mut output = rng.rand();
+	let mut used = 0;
+	for byte in dest.iter_mut() {
+		if used == R::OUTPUT_SIZE {
+			output = rng.rand();
+			used = 0;
+		}
+		*byte = output.as_ref()[used];
+		used += 1;
+	}
+}
+
+#[test]
+fn large_unaligned_fill_matches_naive() {
+	for len in [0, 1, 7, 9, 63, 65, 1024 * 1024 + 3] {
+		let mut fast = vec![0u8; len];
+		let mut naive = vec![0u8; len];
+		let mut rng = WyRand::new_seed(42);
+		let mut expected = WyRand::new_seed(42);
+		rng.fill_bytes(&mut fast);
+		naive_fill(&mut expected, &mut naive);
+		assert_eq!(fast, naive, "length {}", len);
+		if len > 0 {
+			// Both used the same number of outputs.
+			assert_eq!(rng, expected);
+		}
+		#[cfg(feature = "chacha")]
+		{
+			let mut rng = nanorand::ChaCha::new_key(20, [42; 32], [0; 16]);
+			let mut expected = nanorand::ChaCha::new_key(20, [42; 32], [0; 16]);
+			rng.fill_bytes(&mut fast);
+			naive_fill(&mut expected, &mut naive);
+			assert_eq!(fast, naive, "length {}", len);
+		}
+	}
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "chacha")]
+use nanorand::{ChaCha, RNG};
+
+const PLAINTEXT: &[u8] = b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+
+#[test]
+fn keystream_roundtrip() {
+	let mut data = PLAINTEXT.to_vec();
+	ChaCha::new_key(20, [3; 32], [4; 16]).apply_keystream(&mut data);
+	assert_ne!(data, PLAINTEXT);
+	ChaCha::new_key(20, [3; 32], [4; 16]).apply_keystream(&mut data);
+	assert_eq!(data, PLAINTEXT);
+}
+
+#[test]
+fn keystream_continues_across_calls() {
+	let mut whole = PLAINTEXT.to_vec();
+	ChaCha::new_key(8, [3; 32], [4; 16]).apply_keystream(&mut whole);
+
+	let mut pieces = PLAINTEXT.to_vec();
+	let mut rng = ChaCha::new_key(8, [3; 32], [4; 16]);
+	for chunk in pieces.chunks_mut(7) {
+		rng.apply_keystream(chunk);
+	}
+	assert_eq!(pieces, whole);
+}
+
+/// The keystream is the generator's output, which is little-endian by default.
+#[test]
+#[cfg(not(feature = "native-endian"))]
+fn keystream_is_rng_output() {
+	use nanorand::RNG;
+
+	let mut rng = ChaCha::new_key(20, [5; 32], [6; 16]);
+	let mut keystream = [0u8; 200];
+	rng.apply_keystream(&mut keystream);
+
+	let mut expected_rng = ChaCha::new_key(20, [5; 32], [6; 16]);
+	let mut expected = [0u8; 256];
+	expected_rng.fill_bytes(&mut expected);
+	assert_eq!(keystream[..], expected[..200]);
+
+	// The rest of the partially-used block is kept for the next call, while rand() starts on a new block.
+	let mut rest = [0u8; 56];
+	rng.clone().apply_keystream(&mut rest);
+	assert_eq!(rest[..], expected[200..]);
+	assert_eq!(rng.rand(), expected_rng.rand());
+}
+
+#[test]
+fn seeking_stitches_together() {
+	let key = [7; 32];
+	let nonce = [9; 16];
+	let mut linear = [0u8; 1000];
+	ChaCha::new_key(20, key, nonce).fill_bytes(&mut linear);
+
+	// Segments starting mid-block, and ending mid-word.
+	let mut stitched = [0u8; 1000];
+	for &(start, end) in [(0, 100), (100, 262), (260, 1000)].iter() {
+		let mut worker = ChaCha::new_key(20, key, nonce);
+		worker.set_word_pos(start as u128 / 4);
+		assert_eq!(worker.word_pos(), start as u128 / 4);
+		worker.fill_bytes(&mut stitched[start..end]);
+	}
+	assert_eq!(stitched[..], linear[..]);
+}
+
+#[test]
+fn seeking_rand_and_keystream() {
+	let mut linear = [0u8; 256];
+	ChaCha::new_key(8, [1; 32], [2; 16]).fill_bytes(&mut linear);
+
+	let mut rng = ChaCha::new_key(8, [1; 32], [2; 16]);
+	rng.set_word_pos(5);
+	assert_eq!(rng.rand()[..], linear[20..84]);
+	assert_eq!(rng.word_pos(), 32);
+	assert_eq!(rng.rand()[..], linear[128..192]);
+
+	rng.set_word_pos(0);
+	assert_eq!(rng.rand()[..], linear[..64]);
+
+	// apply_keystream lines up with the same positions, and seeking discards its leftovers.
+	rng.apply_keystream(&mut [0u8; 3]);
+	rng.set_word_pos(3);
+	let mut keystream = [0u8; 40];
+	rng.apply_keystream(&mut keystream);
+	if !cfg!(feature = "native-endian") {
+		assert_eq!(keystream[..], linear[12..52]);
+	}
+}
+
+#[test]
+fn word_pos_advances_by_blocks() {
+	let mut rng = ChaCha::new_key(20, [1; 32], [2; 16]);
+	assert_eq!(rng.word_pos(), 0);
+	rng.fill_bytes(&mut [0u8; 10]);
+	assert_eq!(rng.word_pos(), 16);
+	rng.rand();
+	assert_eq!(rng.word_pos(), 32);
+	rng.set_word_pos((1 << 36) + 17);
+	assert_eq!(rng.word_pos(), 17);
+}
@@ -0,0 +1,334 @@
+use nanorand::{WyRand, RNG};
+
+#[test]
+fn choose_empty() {
+	let mut rng = WyRand::new_seed(42);
+	let empty: [u8; 0] = [];
+	assert_eq!(rng.choose(&empty), None);
+	let mut empty: [u8; 0] = [];
+	assert_eq!(rng.choose_mut(&mut empty), None);
+	// No randomness is used for an empty slice.
+	assert_eq!(rng, WyRand::new_seed(42));
+}
+
+#[test]
+fn choose_single() {
+	let mut rng = WyRand::new_seed(42);
+	for _ in 0..10 {
+		assert_eq!(rng.choose(&[7]), Some(&7));
+	}
+	let mut single = [7];
+	*rng.choose_mut(&mut single).unwrap() += 1;
+	assert_eq!(single, [8]);
+}
+
+#[test]
+fn choose_is_uniform() {
+	let mut rng = WyRand::new_seed(42);
+	let items = [0usize, 1, 2, 3, 4];
+	let mut counts = [0u32; 5];
+	for _ in 0..50000 {
+		counts[*rng.choose(&items).unwrap()] += 1;
+	}
+	for &count in counts.iter() {
+		assert!((9500..10500).contains(&count), "{:?}", counts);
+	}
+
+	let mut hits = [0u32; 5];
+	for _ in 0..50000 {
+		*rng.choose_mut(&mut hits).unwrap() += 1;
+	}
+	for &count in hits.iter() {
+		assert!((9500..10500).contains(&count), "{:?}", hits);
+	}
+}
+
+#[test]
+fn choose_is_deterministic() {
+	let items: Vec<u32> = (0..100).collect();
+	let picks = |seed| {
+		let mut rng = WyRand::new_seed(seed);
+		(0..10)
+			.map(|_| *rng.choose(&items).unwrap())
+			.collect::<Vec<u32>>()
+	};
+	assert_eq!(picks(1), picks(1));
+	assert_ne!(picks(1), picks(2));
+}
+
+#[test]
+fn sample_indices_are_distinct() {
+	let mut rng = WyRand::new_seed(42);
+	for &(len, amount) in [(10, 3), (10, 5), (100, 10), (100, 99), (1_000_000, 20)].iter() {
+		for _ in 0..100 {
+			let indices = rng.sample_indices(len, amount);
+			assert_eq!(indices.len(), amount);
+			assert!(indices.iter().all(|&idx| idx < len));
+			let mut sorted = indices.clone();
+			sorted.sort_unstable();
+			sorted.dedup();
+			assert_eq!(sorted.len(), amount);
+		}
+	}
+}
+
+#[test]
+fn sample_indices_boundaries() {
+	let mut rng = WyRand::new_seed(42);
+	assert!(rng.sample_indices(0, 0).is_empty());
+	assert!(rng.sample_indices(0, 5).is_empty());
+	assert!(rng.sample_indices(10, 0).is_empty());
+	for &amount in [10, 11, 1000].iter() {
+		let mut all = rng.sample_indices(10, amount);
+		all.sort_unstable();
+		assert_eq!(all, (0..10).collect::<Vec<usize>>());
+	}
+	let items = ["a", "b", "c"];
+	let mut chosen = rng.choose_multiple(&items, 5);
+	chosen.sort_unstable();
+	assert_eq!(chosen, [&"a", &"b", &"c"]);
+	assert!(rng.choose_multiple(&items, 0).is_empty());
+	let empty: [u8; 0] = [];
+	assert!(rng.choose_multiple(&empty, 3).is_empty());
+}
+
+#[test]
+fn sample_indices_are_uniform() {
+	// Both algorithms: 3 out of 10 uses Floyd's, and 5 out of 10 uses a partial shuffle.
+	let mut rng = WyRand::new_seed(42);
+	for &amount in [3, 5].iter() {
+		let mut included = [0u32; 10];
+		let mut first = [0u32; 10];
+		for _ in 0..100000 {
+			let indices = rng.sample_indices(10, amount);
+			indices.iter().for_each(|&idx| included[idx] += 1);
+			first[indices[0]] += 1;
+		}
+		let expected = 100000 * amount as u32 / 10;
+		for &count in included.iter() {
+			assert!(count.abs_diff(expected) < expected / 20, "{:?}", included);
+		}
+		// The order is random too, so any index is as likely to come first.
+		for &count in first.iter() {
+			assert!(count.abs_diff(10000) < 500, "{:?}", first);
+		}
+	}
+}
+
+#[test]
+fn weighted_choose_frequencies() {
+	let mut rng = WyRand::new_seed(42);
+	let items = [("a", 1.0), ("b", 2.0), ("c", 0.0), ("d", 7.0)];
+	let mut counts = [0u32; 4];
+	for _ in 0..100000 {
+		let picked = rng.weighted_choose(&items, |&(_, weight)| weight).unwrap();
+		let idx = items.iter().position(|item| item == picked).unwrap();
+		counts[idx] += 1;
+	}
+	assert_eq!(counts[2], 0);
+	for (&count, expected) in counts.iter().zip([10000, 20000, 0, 70000]) {
+		assert!(count.abs_diff(expected) <= expected / 20, "{:?}", counts);
+	}
+}
+
+#[test]
+fn weighted_choose_edge_cases() {
+	let mut rng = WyRand::new_seed(42);
+	let empty: [f64; 0] = [];
+	assert_eq!(rng.weighted_choose(&empty, |&w| w), None);
+	assert_eq!(rng.weighted_choose(&[0.0, 0.0, 0.0], |&w| w), None);
+	for _ in 0..100 {
+		assert_eq!(rng.weighted_choose(&[0.0, 3.5, 0.0], |&w| w), Some(&3.5));
+	}
+}
+
+#[test]
+#[should_panic(expected = "Weights must be finite and not negative")]
+fn weighted_choose_negative_panics() {
+	WyRand::new_seed(42).weighted_choose(&[1.0, -1.0], |&w| w);
+}
+
+#[test]
+#[should_panic(expected = "Weights must be finite and not negative")]
+fn weighted_choose_nan_panics() {
+	WyRand::new_seed(42).weighted_choose(&[1.0, f64::NAN], |&w| w);
+}
+
+#[test]
+fn weighted_sample_first_picks() {
+	let mut rng = WyRand::new_seed(42);
+	let weights = [1.0, 2.0, 0.0, 7.0];
+	let mut first = [0u32; 4];
+	let mut included = [0u32; 4];
+	for _ in 0..100000 {
+		let picked = rng.weighted_sample_without_replacement(&weights, 2);
+		assert_eq!(picked.len(), 2);
+		assert_ne!(picked[0], picked[1]);
+		first[picked[0]] += 1;
+		picked.iter().for_each(|&idx| included[idx] += 1);
+	}
+	assert_eq!(included[2], 0);
+	for (&count, expected) in first.iter().zip([10000, 20000, 0, 70000]) {
+		assert!(count.abs_diff(expected) <= expected / 20, "{:?}", first);
+	}
+	// The second pick is from what's left: index 0 is picked second after 1 with probability 0.2 * 1/8,
+	// and after 3 with probability 0.7 * 1/3, so it's included about 10% + 2.5% + 23.3% of the time.
+	assert!(included[0].abs_diff(35833) < 1000, "{:?}", included);
+}
+
+#[test]
+fn weighted_sample_boundaries() {
+	let mut rng = WyRand::new_seed(42);
+	let weights = [0.0, 4.0, 0.0, 1e-300, 2.0];
+	assert!(rng
+		.weighted_sample_without_replacement(&weights, 0)
+		.is_empty());
+	for _ in 0..100 {
+		let mut picked = rng.weighted_sample_without_replacement(&weights, 10);
+		assert_eq!(picked.len(), 3);
+		// Such a tiny weight always comes last.
+		assert_eq!(picked[2], 3);
+		picked.sort_unstable();
+		assert_eq!(picked, [1, 3, 4]);
+	}
+	assert!(rng
+		.weighted_sample_without_replacement(&[0.0, 0.0], 1)
+		.is_empty());
+	assert!(rng.weighted_sample_without_replacement(&[], 1).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Weights must be finite and not negative")]
+fn weighted_sample_nan_panics() {
+	WyRand::new_seed(42).weighted_sample_without_replacement(&[1.0, f64::NAN], 1);
+}
+
+#[test]
+fn sample_iter_short_iterators() {
+	let mut rng = WyRand::new_seed(42);
+	let mut sample = rng.sample_iter(0..5, 10);
+	sample.sort_unstable();
+	assert_eq!(sample, [0, 1, 2, 3, 4]);
+	assert_eq!(rng.sample_iter(0..5, 5).len(), 5);
+	assert!(rng.sample_iter(core::iter::empty::<u8>(), 3).is_empty());
+
+	// With k == 0, nothing is taken from the iterator.
+	let mut iter = 0..5;
+	assert!(rng.sample_iter(iter.by_ref(), 0).is_empty());
+	assert_eq!(iter.next(), Some(0));
+}
+
+#[test]
+fn sample_iter_is_uniform() {
+	// 100 out of 10,000, so every block of 1,000 should be picked 10 times per run.
+	let mut rng = WyRand::new_seed(42);
+	let mut blocks = [0u32; 10];
+	let mut first_items = 0;
+	for _ in 0..5000 {
+		let sample = rng.sample_iter(0..10_000_u32, 100);
+		assert_eq!(sample.len(), 100);
+		let mut sorted = sample.clone();
+		sorted.sort_unstable();
+		sorted.dedup();
+		assert_eq!(sorted.len(), 100);
+		for &item in sample.iter() {
+			blocks[item as usize / 1000] += 1;
+			first_items += (item < 100) as u32;
+		}
+	}
+	assert!(
+		blocks.iter().all(|&c| c.abs_diff(50000) < 1500),
+		"{:?}",
+		blocks
+	);
+	// The items that fill the reservoir up front shouldn't be over-represented.
+	assert!(first_items.abs_diff(5000) < 400, "{}", first_items);
+}
+
+#[test]
+fn sample_range_unique_is_distinct() {
+	let mut rng = WyRand::new_seed(42);
+	for _ in 0..100 {
+		let mut ids = rng.sample_range_unique(0_u64, 1_000_000_000_000_000_000, 10);
+		assert_eq!(ids.len(), 10);
+		assert!(ids.iter().all(|&id| id < 1_000_000_000_000_000_000));
+		ids.sort_unstable();
+		ids.dedup();
+		assert_eq!(ids.len(), 10);
+
+		let mut small = rng.sample_range_unique(-5_i8, 5, 9);
+		assert!(small.iter().all(|n| (-5..5).contains(n)));
+		small.sort_unstable();
+		small.dedup();
+		assert_eq!(small.len(), 9);
+
+		let wide = rng.sample_range_unique(i128::MIN, i128::MAX, 3);
+		assert!(wide[0] != wide[1] && wide[1] != wide[2] && wide[0] != wide[2]);
+	}
+}
+
+#[test]
+fn sample_range_unique_boundaries() {
+	let mut rng = WyRand::new_seed(42);
+	assert!(rng.sample_range_unique(0_u32, 100, 0).is_empty());
+	assert!(rng.sample_range_unique(7_u32, 7, 3).is_empty());
+	for k in [5, 6, 1000, usize::MAX] {
+		let mut all = rng.sample_range_unique(250_u8, u8::MAX, k);
+		all.sort_unstable();
+		assert_eq!(all, [250, 251, 252, 253, 254]);
+	}
+}
+
+#[test]
+fn sample_range_unique_is_uniform() {
+	// Each of the 10 pairs within 0..5 should come up equally often.
+	let mut rng = WyRand::new_seed(42);
+	let mut counts = [[0u32; 5]; 5];
+	for _ in 0..100000 {
+		let mut pair = rng.sample_range_unique(0_usize, 5, 2);
+		pair.sort_unstable();
+		counts[pair[0]][pair[1]] += 1;
+	}
+	for (low, row) in counts.iter().enumerate() {
+		for (high, &count) in row.iter().enumerate() {
+			if low < high {
+				assert!(count.abs_diff(10000) < 500, "{:?}", counts);
+			} else {
+				assert_eq!(count, 0);
+			}
+		}
+	}
+}
+
+#[test]
+fn random_index_bounds() {
+	let mut rng = WyRand::new_seed(42);
+	assert_eq!(rng.random_index(0), None);
+	// No randomness is used when there's no index to pick.
+	assert_eq!(rng, WyRand::new_seed(42));
+	for _ in 0..100 {
+		assert_eq!(rng.random_index(1), Some(0));
+		assert!(rng.random_index(3).unwrap() < 3);
+	}
+	// The same index as a ranged usize, so shuffle and choose didn't change.
+	let mut expected = WyRand::new_seed(7);
+	let mut rng = WyRand::new_seed(7);
+	for len in 1..100 {
+		assert_eq!(rng.random_index(len), Some(expected.generate_range(0..len)));
+	}
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn random_index_above_u32() {
+	let mut rng = WyRand::new_seed(42);
+	let len = (1_usize << 32) + 1;
+	let mut upper_half = 0;
+	for _ in 0..10000 {
+		let idx = rng.random_index(len).unwrap();
+		assert!(idx < len);
+		upper_half += (idx > len / 2) as u32;
+	}
+	// Indices are spread evenly across the whole range, not bunched up by a reduction to 32 bits.
+	assert!(upper_half.abs_diff(5000) < 300, "{} / 10000", upper_half);
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "chrono")]
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+use nanorand::{RandomRange, WyRand, RNG};
+
+#[test]
+fn random_timestamps_are_in_the_default_range() {
+	let mut rng = WyRand::new_seed(42);
+	let epoch = Utc.timestamp_opt(0, 0).unwrap();
+	let end = Utc.with_ymd_and_hms(2100, 1, 1, 0, 0, 0).unwrap();
+	let mut after_2050 = 0;
+	for _ in 0..10000 {
+		let timestamp: DateTime<Utc> = rng.generate();
+		assert!(epoch <= timestamp && timestamp < end, "{}", timestamp);
+		after_2050 += (timestamp.timestamp() >= 2_524_608_000) as u32;
+	}
+	// 50 of the 130 years are after 2050.
+	assert!(after_2050.abs_diff(3846) < 300, "{} / 10000", after_2050);
+}
+
+#[test]
+fn ranged_timestamps() {
+	let mut rng = WyRand::new_seed(42);
+	let lower = Utc.with_ymd_and_hms(2024, 2, 28, 23, 59, 59).unwrap();
+	let upper = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 1).unwrap();
+	let mut saw_leap_day = false;
+	for _ in 0..10000 {
+		let timestamp = rng.generate_range(lower..upper);
+		assert!(lower <= timestamp && timestamp < upper, "{}", timestamp);
+		saw_leap_day |= (timestamp.month(), timestamp.day()) == (2, 29);
+		let timestamp = rng.generate_range(lower..=upper);
+		assert!(lower <= timestamp && timestamp <= upper, "{}", timestamp);
+	}
+	assert!(saw_leap_day);
+	assert_eq!(DateTime::<Utc>::random_range(&mut rng, lower, lower), lower);
+}
+
+#[test]
+fn ranged_timestamps_before_the_epoch() {
+	let mut rng = WyRand::new_seed(42);
+	let lower = Utc.with_ymd_and_hms(1900, 1, 1, 0, 0, 0).unwrap();
+	let upper = Utc.with_ymd_and_hms(1969, 12, 31, 23, 59, 59).unwrap()
+		+ chrono::Duration::nanoseconds(999_999_999);
+	for _ in 0..10000 {
+		let timestamp = rng.generate_range(lower..=upper);
+		assert!(lower <= timestamp && timestamp <= upper, "{}", timestamp);
+	}
+	// The widest possible range still gives valid timestamps.
+	for _ in 0..1000 {
+		let timestamp: DateTime<Utc> = rng.generate_range(..);
+		assert!(DateTime::<Utc>::MIN_UTC <= timestamp && timestamp <= DateTime::<Utc>::MAX_UTC);
+	}
+}
+
+#[test]
+#[should_panic(expected = "nothing but the excluded value")]
+fn timestamp_range_except_single_value() {
+	let mut rng = WyRand::new_seed(42);
+	let lower = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+	let upper = lower + chrono::Duration::nanoseconds(1);
+	DateTime::<Utc>::random_range_except(&mut rng, lower, upper, lower);
+}
@@ -0,0 +1,54 @@
+use nanorand::{adapter::CombinedRng, Pcg64, SeedableRNG, WyRand, RNG};
+
+#[test]
+fn combined_is_xor_of_both() {
+	let mut rng = CombinedRng::new(WyRand::new_seed(1), WyRand::new_seed(2));
+	let mut a = WyRand::new_seed(1);
+	let mut b = WyRand::new_seed(2);
+	for _ in 0..100 {
+		let (x, y) = (a.generate::<u64>(), b.generate::<u64>());
+		let combined = rng.generate::<u64>();
+		assert_eq!(combined, x ^ y);
+		assert_ne!(combined, x);
+		assert_ne!(combined, y);
+	}
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+fn combined_differing_sizes() {
+	use nanorand::ChaCha;
+
+	// ChaCha's 64 byte output takes 8 of WyRand's.
+	let mut rng = CombinedRng::new(ChaCha::new(8), WyRand::new_seed(2));
+	let (mut a, _) = rng.clone().into_inner();
+	let mut b = WyRand::new_seed(2);
+	let combined = rng.rand();
+	let expected = a.rand();
+	for (i, chunk) in combined.chunks(8).enumerate() {
+		let wy = b.rand();
+		for j in 0..8 {
+			assert_eq!(chunk[j], expected[i * 8 + j] ^ wy[j]);
+		}
+	}
+}
+
+#[test]
+fn combined_seeding_is_deterministic() {
+	let mut first = CombinedRng::<WyRand, Pcg64>::from_seeds([1; 8], [2; 16]);
+	let mut second = CombinedRng::new(WyRand::new(), Pcg64::new());
+	second.reseed([1; 8], [2; 16]);
+	for _ in 0..100 {
+		assert_eq!(first.generate::<u64>(), second.generate::<u64>());
+	}
+
+	let mut rng = CombinedRng::<WyRand, WyRand>::from_seeds([1; 8], [2; 8]);
+	let mut a = WyRand::from_seed([1; 8]);
+	let mut b = WyRand::from_seed([2; 8]);
+	for _ in 0..100 {
+		assert_eq!(
+			rng.generate::<u64>(),
+			a.generate::<u64>() ^ b.generate::<u64>()
+		);
+	}
+}
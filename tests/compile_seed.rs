@@ -0,0 +1,36 @@
+#![cfg(feature = "derive")]
+use nanorand::{compile_seed, WyRand, RNG};
+
+static SEEDED: WyRand = WyRand::new_seed(compile_seed!());
+const FIRST: u64 = compile_seed!();
+const SECOND: u64 = compile_seed!();
+const BYTES: [u8; 16] = compile_seed!(16);
+const ODD: [u8; 3] = compile_seed!(3);
+const EMPTY: [u8; 0] = compile_seed!(0);
+
+#[test]
+fn seeds_differ_between_invocations() {
+	assert_ne!(FIRST, SECOND);
+	assert_ne!(BYTES, compile_seed!(16));
+	assert_eq!(ODD.len(), 3);
+	assert!(EMPTY.is_empty());
+}
+
+#[test]
+fn seeds_are_fixed_within_a_build() {
+	let seed = || compile_seed!();
+	assert_eq!(seed(), seed());
+	assert_eq!(
+		SEEDED.clone().generate::<u64>(),
+		SEEDED.clone().generate::<u64>()
+	);
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+fn const_key() {
+	static CHACHA: nanorand::ChaCha =
+		nanorand::ChaCha::new_key(8, compile_seed!(32), compile_seed!(16));
+	let mut rng = CHACHA.clone();
+	rng.generate::<u64>();
+}
@@ -0,0 +1,49 @@
+use nanorand::{Pcg64, WyRand, Xoroshiro128, RNG};
+use std::sync::Mutex;
+
+static WYRAND: Mutex<WyRand> = Mutex::new(WyRand::new_seed(0xDEADBEEF));
+static PCG64: Pcg64 = Pcg64::new_seed(0xDEADBEEF);
+static XOROSHIRO: Xoroshiro128 = Xoroshiro128::new_seed(0xDEADBEEF);
+#[cfg(feature = "chacha")]
+static CHACHA: nanorand::ChaCha = nanorand::ChaCha::new_key(20, [7; 32], [3; 16]);
+
+fn assert_same_outputs<R: RNG>(mut a: R, mut b: R) {
+	for _ in 0..16 {
+		assert_eq!(a.generate::<u64>(), b.generate::<u64>());
+	}
+}
+
+#[test]
+fn static_wyrand() {
+	let mut rng = WYRAND.lock().unwrap();
+	let mut expected = WyRand::new_seed(0xDEADBEEF);
+	for _ in 0..16 {
+		assert_eq!(rng.generate::<u64>(), expected.generate::<u64>());
+	}
+}
+
+#[test]
+fn static_generators() {
+	assert_same_outputs(PCG64.clone(), Pcg64::new_seed(0xDEADBEEF));
+	assert_same_outputs(XOROSHIRO.clone(), Xoroshiro128::new_seed(0xDEADBEEF));
+	#[cfg(feature = "chacha")]
+	assert_same_outputs(
+		CHACHA.clone(),
+		nanorand::ChaCha::new_key(20, [7; 32], [3; 16]),
+	);
+}
+
+const FIXED: WyRand = WyRand::from_seed_const(7);
+static FIXED_STATIC: WyRand = WyRand::from_seed_const(7);
+
+#[test]
+fn from_seed_const() {
+	let mut from_const = FIXED;
+	let mut from_static = FIXED_STATIC.clone();
+	let mut expected = WyRand::new_seed(7);
+	for _ in 0..16 {
+		let value = expected.generate::<u64>();
+		assert_eq!(from_const.generate::<u64>(), value);
+		assert_eq!(from_static.generate::<u64>(), value);
+	}
+}
@@ -0,0 +1,52 @@
+use nanorand::{adapter::CountingRng, WyRand, RNG};
+
+#[test]
+fn counted_generation() {
+	let mut rng = WyRand::new_seed(42);
+	let mut expected = WyRand::new_seed(42);
+
+	let (value, calls) = rng.generate_counted::<u64>();
+	assert_eq!(value, expected.generate::<u64>());
+	assert_eq!(calls, 1);
+
+	let (_, calls) = rng.generate_counted::<u8>();
+	assert_eq!(calls, 1);
+	let (_, calls) = rng.generate_counted::<u128>();
+	assert_eq!(calls, 2);
+	expected.generate::<u8>();
+	expected.generate::<u128>();
+
+	// The counted generation advances the generator as usual.
+	assert_eq!(rng.generate::<u64>(), expected.generate::<u64>());
+}
+
+#[test]
+fn rejection_sampling_counts() {
+	let mut rng = WyRand::new_seed(42);
+	let mut total = 0;
+	for _ in 0..1000 {
+		let (c, calls) = rng.generate_counted::<char>();
+		assert!(char::from_u32(c as u32).is_some());
+		assert!(calls >= 1);
+		total += calls;
+	}
+	// Only about 1 in 4000 u32s is a valid char, so this takes thousands of tries per char on average.
+	assert!(total > 100_000, "{} calls", total);
+}
+
+#[test]
+fn counting_adapter() {
+	let mut rng = CountingRng::new(WyRand::new_seed(42));
+	let mut expected = WyRand::new_seed(42);
+	let mut bytes = [0u8; 20];
+	rng.fill_bytes(&mut bytes);
+	assert_eq!(rng.calls(), 3);
+	let mut expected_bytes = [0u8; 20];
+	expected.fill_bytes(&mut expected_bytes);
+	assert_eq!(bytes, expected_bytes);
+
+	rng.reset();
+	assert_eq!(rng.calls(), 0);
+	rng.generate_range(0_u32..10);
+	assert_eq!(rng.calls(), 1);
+}
@@ -0,0 +1,65 @@
+use nanorand::{adapter::BufferedRng, Pcg64, WyRand, Xoroshiro128};
+
+// Structs embedding a generator should be able to derive `Debug`.
+#[derive(Debug)]
+#[allow(dead_code)]
+struct Simulation {
+	wyrand: WyRand,
+	pcg64: Pcg64,
+	xoroshiro: Xoroshiro128,
+	buffered: BufferedRng<WyRand>,
+	#[cfg(feature = "chacha")]
+	chacha: nanorand::ChaCha,
+}
+
+fn simulation() -> Simulation {
+	Simulation {
+		wyrand: WyRand::new_seed(42),
+		pcg64: Pcg64::new_seed(42),
+		xoroshiro: Xoroshiro128::new_seed(42),
+		buffered: BufferedRng::new(WyRand::new_seed(42)),
+		#[cfg(feature = "chacha")]
+		chacha: nanorand::ChaCha::new_key(8, [0xAB; 32], [0xCD; 16]),
+	}
+}
+
+#[test]
+#[cfg(not(feature = "debug-state"))]
+fn debug_is_opaque() {
+	let debug = format!("{:?}", simulation());
+	assert!(debug.contains("wyrand: WyRand { .. }"), "{}", debug);
+	assert!(debug.contains("pcg64: Pcg64 { .. }"), "{}", debug);
+	assert!(
+		debug.contains("xoroshiro: Xoroshiro128 { .. }"),
+		"{}",
+		debug
+	);
+	assert!(
+		debug.contains("BufferedRng { rng: WyRand { .. }, remaining: 0 }"),
+		"{}",
+		debug
+	);
+}
+
+#[test]
+#[cfg(feature = "debug-state")]
+fn debug_prints_state() {
+	let debug = format!("{:?}", simulation());
+	assert!(debug.contains("WyRand { seed: 42 }"), "{}", debug);
+	assert!(debug.contains("Pcg64 { seed: 42,"), "{}", debug);
+	assert!(debug.contains("Xoroshiro128 { state: ["), "{}", debug);
+}
+
+#[test]
+#[cfg(feature = "chacha")]
+fn chacha_debug_is_redacted() {
+	let rng = nanorand::ChaCha::new_key(8, [0xAB; 32], [0xCD; 16]);
+	assert_eq!(format!("{:?}", rng), "ChaCha { rounds: 8, .. }");
+	let debug = format!("{:?}", simulation());
+	assert!(
+		debug.contains("chacha: ChaCha { rounds: 8, .. }"),
+		"{}",
+		debug
+	);
+	assert!(!debug.contains(&0xABABABABu32.to_string()), "{}", debug);
+}
@@ -0,0 +1,34 @@
+use nanorand::{default_rng, DefaultRng, SeedableRNG, RNG};
+
+fn roll_dice<R: RNG>(rng: &mut R, count: usize) -> Vec<u8> {
+	(0..count).map(|_| rng.generate_range(1_u8..=6)).collect()
+}
+
+#[test]
+fn default_rng_is_usable_generically() {
+	let rolls = roll_dice(&mut default_rng(), 100);
+	assert!(rolls.iter().all(|roll| (1..=6).contains(roll)));
+
+	let mut seeded = DefaultRng::seed_from_u64(42);
+	let rolls = roll_dice(&mut seeded, 100);
+	assert_eq!(rolls, roll_dice(&mut DefaultRng::seed_from_u64(42), 100));
+
+	#[cfg(feature = "tls")]
+	{
+		let mut tls = nanorand::tls_rng();
+		let _: &mut DefaultRng = &mut tls;
+		assert!(roll_dice(&mut *tls, 10)
+			.iter()
+			.all(|roll| (1..=6).contains(roll)));
+	}
+}
+
+#[test]
+fn default_rng_follows_the_features() {
+	#[cfg(feature = "default-chacha")]
+	let _: nanorand::ChaCha = default_rng();
+	#[cfg(feature = "default-pcg64")]
+	let _: nanorand::Pcg64 = default_rng();
+	#[cfg(not(any(feature = "default-pcg64", feature = "default-chacha")))]
+	let _: nanorand::WyRand = default_rng();
+}
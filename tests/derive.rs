@@ -0,0 +1,86 @@
+#![cfg(feature = "derive")]
+use nanorand::{RandomGen, WyRand, RNG};
+
+#[derive(RandomGen, Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+	North,
+	East,
+	South,
+	West,
+}
+
+const DIRECTIONS: [Direction; 4] = [
+	Direction::North,
+	Direction::East,
+	Direction::South,
+	Direction::West,
+];
+
+#[derive(RandomGen, Debug)]
+struct Move {
+	direction: Direction,
+	steps: u8,
+	running: bool,
+}
+
+#[derive(RandomGen, Debug)]
+struct Pair<T>(T, T);
+
+#[derive(RandomGen, Debug)]
+enum Event {
+	Idle,
+	Moved(Move),
+	Scored { points: u32 },
+}
+
+#[test]
+fn derived_enum_is_uniform() {
+	let mut rng = WyRand::new_seed(42);
+	let mut counts = [0u32; 4];
+	for _ in 0..40000 {
+		counts[rng.generate::<Direction>() as usize] += 1;
+	}
+	assert!(
+		counts.iter().all(|&c| (9500..10500).contains(&c)),
+		"{:?}",
+		counts
+	);
+}
+
+#[test]
+fn derived_struct_generates_fields() {
+	let mut rng = WyRand::new_seed(42);
+	let mut expected = WyRand::new_seed(42);
+	for _ in 0..100 {
+		let generated: Move = rng.generate();
+		assert_eq!(
+			generated.direction,
+			DIRECTIONS[expected.generate_range(0_usize..4)]
+		);
+		assert_eq!(generated.steps, expected.generate::<u8>());
+		assert_eq!(generated.running, expected.generate::<bool>());
+	}
+
+	let pair: Pair<u16> = rng.generate();
+	let _ = (pair.0, pair.1);
+}
+
+#[test]
+fn derived_enum_with_fields() {
+	let mut rng = WyRand::new_seed(42);
+	let mut seen = [false; 3];
+	for _ in 0..100 {
+		match rng.generate::<Event>() {
+			Event::Idle => seen[0] = true,
+			Event::Moved(m) => {
+				let _ = (m.direction, m.steps, m.running);
+				seen[1] = true;
+			}
+			Event::Scored { points } => {
+				let _ = points;
+				seen[2] = true;
+			}
+		}
+	}
+	assert_eq!(seen, [true; 3]);
+}
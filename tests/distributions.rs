@@ -0,0 +1,465 @@
+use nanorand::{distributions::*, WyRand, RNG};
+
+#[test]
+fn dice() {
+	let mut rng = WyRand::new_seed(42);
+	let mut saw_max = false;
+	for _ in 0..10000 {
+		let sum = roll_dice(&mut rng, 3, 20);
+		assert!((3..=60).contains(&sum));
+		saw_max |= roll_dice(&mut rng, 1, 20) == 20;
+		assert_eq!(roll_dice(&mut rng, 4, 1), 4);
+	}
+	assert!(saw_max);
+	assert_eq!(roll_dice(&mut rng, 0, 6), 0);
+
+	let rolls = roll_dice_detailed(&mut rng, 100, 6);
+	assert_eq!(rolls.len(), 100);
+	assert!(rolls.iter().all(|roll| (1..=6).contains(roll)));
+}
+
+#[test]
+fn dice_sum_past_u32() {
+	let mut rng = WyRand::new_seed(42);
+	// A thousand rolls averaging half of u32::MAX add up to far more than fits in a u32.
+	assert!(roll_dice(&mut rng, 1000, u32::MAX) > u64::from(u32::MAX));
+}
+
+#[test]
+#[should_panic]
+fn dice_without_sides() {
+	let mut rng = WyRand::new_seed(42);
+	roll_dice(&mut rng, 1, 0);
+}
+
+#[test]
+fn sign() {
+	let mut rng = WyRand::new_seed(42);
+	let mut positive = 0;
+	for _ in 0..10000 {
+		let sign: i32 = random_sign(&mut rng);
+		assert!(sign == 1 || sign == -1);
+		if sign == 1 {
+			positive += 1;
+		}
+	}
+	assert!((4800..5200).contains(&positive), "{} positive", positive);
+
+	for _ in 0..100 {
+		let sign: f64 = random_sign(&mut rng);
+		assert!(sign == 1.0 || sign == -1.0);
+		let sign: i8 = random_sign(&mut rng);
+		assert!(sign == 1 || sign == -1);
+	}
+}
+
+#[test]
+fn bool_with_probability() {
+	let mut rng = WyRand::new_seed(42);
+	let mut untouched = WyRand::new_seed(42);
+	for p in [0.0, -1.0, f64::NAN, f64::NEG_INFINITY].iter() {
+		assert!(!random_bool(&mut rng, *p));
+	}
+	for p in [1.0, 2.0, f64::INFINITY].iter() {
+		assert!(random_bool(&mut rng, *p));
+	}
+	// Certain outcomes don't use up any of the RNG's output.
+	assert_eq!(rng.generate::<u64>(), untouched.generate::<u64>());
+
+	let hits = (0..100000).filter(|_| random_bool(&mut rng, 0.25)).count();
+	assert!((24000..26000).contains(&hits), "{} hits", hits);
+}
+
+fn triangular_mean(rng: &mut WyRand, low: f64, high: f64, mode: f64) -> f64 {
+	let samples = 100000;
+	let sum: f64 = (0..samples)
+		.map(|_| {
+			let x = random_triangular(rng, low, high, mode);
+			assert!(
+				(low..=high).contains(&x),
+				"{} is outside {}..={}",
+				x,
+				low,
+				high
+			);
+			x
+		})
+		.sum();
+	sum / samples as f64
+}
+
+#[test]
+fn triangular() {
+	let mut rng = WyRand::new_seed(42);
+	for &(low, high, mode) in [
+		(0.0, 1.0, 0.5),
+		(-10.0, 30.0, 25.0),
+		(5.0, 6.0, 5.0),
+		(0.0, 9.0, 9.0),
+	]
+	.iter()
+	{
+		let mean = triangular_mean(&mut rng, low, high, mode);
+		let expected = (low + mode + high) / 3.0;
+		assert!(
+			(mean - expected).abs() < (high - low) * 0.01,
+			"mean {} is too far from {}",
+			mean,
+			expected
+		);
+	}
+	assert_eq!(random_triangular(&mut rng, 2.0, 2.0, 2.0), 2.0);
+
+	// Values near the mode come up more often than values near the ends.
+	let near_mode = (0..10000)
+		.filter(|_| (random_triangular(&mut rng, 0.0, 1.0, 0.5) - 0.5).abs() < 0.1)
+		.count();
+	let near_low = (0..10000)
+		.filter(|_| random_triangular(&mut rng, 0.0, 1.0, 0.5) < 0.1)
+		.count();
+	assert!(
+		near_mode > near_low * 3,
+		"{} near the mode, {} near the low end",
+		near_mode,
+		near_low
+	);
+}
+
+#[test]
+#[should_panic]
+fn triangular_mode_out_of_range() {
+	random_triangular(&mut WyRand::new_seed(42), 0.0, 1.0, 1.5);
+}
+
+#[test]
+fn unit_vectors_have_unit_length() {
+	let mut rng = WyRand::new_seed(42);
+	for _ in 0..10000 {
+		let (x, y) = random_unit_vector_2d(&mut rng);
+		assert!((x.hypot(y) - 1.0).abs() < 1e-9);
+		let (x, y, z) = random_unit_vector_3d(&mut rng);
+		assert!(((x * x + y * y + z * z).sqrt() - 1.0).abs() < 1e-9);
+	}
+}
+
+#[test]
+fn unit_vectors_are_uniform() {
+	let mut rng = WyRand::new_seed(42);
+	let samples = 100000;
+	let mut sum_2d = (0.0, 0.0);
+	let mut sum_3d = (0.0, 0.0, 0.0);
+	// Each octant of the sphere should get an eighth of the points, which uniform angles wouldn't give.
+	let mut octants = [0u32; 8];
+	let mut polar = 0;
+	for _ in 0..samples {
+		let (x, y) = random_unit_vector_2d(&mut rng);
+		sum_2d = (sum_2d.0 + x, sum_2d.1 + y);
+		let (x, y, z) = random_unit_vector_3d(&mut rng);
+		sum_3d = (sum_3d.0 + x, sum_3d.1 + y, sum_3d.2 + z);
+		octants[(x > 0.0) as usize | ((y > 0.0) as usize) << 1 | ((z > 0.0) as usize) << 2] += 1;
+		if z.abs() > 0.9 {
+			polar += 1;
+		}
+	}
+	let n = samples as f64;
+	for mean in [
+		sum_2d.0 / n,
+		sum_2d.1 / n,
+		sum_3d.0 / n,
+		sum_3d.1 / n,
+		sum_3d.2 / n,
+	]
+	.iter()
+	{
+		assert!(mean.abs() < 0.01, "mean {} is too far from 0", mean);
+	}
+	for &count in octants.iter() {
+		assert!((11500..13500).contains(&count), "{:?}", octants);
+	}
+	// The caps above |z| = 0.9 are a tenth of the sphere's surface.
+	assert!(
+		(9000..11000).contains(&polar),
+		"{} points near the poles",
+		polar
+	);
+}
+
+#[test]
+fn weighted_index_skewed() {
+	let mut rng = WyRand::new_seed(42);
+	let weights = [1.0, 0.0, 9.0, 90.0];
+	let table = WeightedIndex::new(&weights).unwrap();
+	let mut counts = [0u32; 4];
+	for _ in 0..200000 {
+		counts[table.sample(&mut rng)] += 1;
+	}
+	assert_eq!(counts[1], 0);
+	for (&count, &weight) in counts.iter().zip(weights.iter()) {
+		let expected = 2000.0 * weight;
+		assert!(
+			(count as f64 - expected).abs() <= expected * 0.05,
+			"{:?}",
+			counts
+		);
+	}
+}
+
+#[test]
+fn weighted_index_orders_of_magnitude() {
+	let mut rng = WyRand::new_seed(42);
+	// The huge weights dominate, and the tiny ones are negligible, but neither overflows nor goes NaN.
+	let weights = [1e-300, f64::MAX, 1.0, f64::MAX / 3.0, 1e300];
+	let table = WeightedIndex::new(&weights).unwrap();
+	let mut counts = [0u32; 5];
+	for _ in 0..100000 {
+		counts[table.sample(&mut rng)] += 1;
+	}
+	assert_eq!(counts[0] + counts[2], 0, "{:?}", counts);
+	assert!(counts[1].abs_diff(75000) < 1000, "{:?}", counts);
+	assert!(counts[3].abs_diff(25000) < 1000, "{:?}", counts);
+
+	// Likewise when every weight is tiny.
+	let table = WeightedIndex::new(&[1e-310, 3e-310]).unwrap();
+	let ones = (0..100000).filter(|_| table.sample(&mut rng) == 1).count();
+	assert!(ones.abs_diff(75000) < 1000, "{}", ones);
+
+	let table = WeightedIndex::new(&[0.0, 5.0, 0.0]).unwrap();
+	assert!((0..1000).all(|_| table.sample(&mut rng) == 1));
+}
+
+#[test]
+fn weighted_index_errors() {
+	assert_eq!(WeightedIndex::new(&[]), Err(WeightedError::NoWeights));
+	assert_eq!(WeightedIndex::new(&[0.0, 0.0]), Err(WeightedError::AllZero));
+	assert_eq!(
+		WeightedIndex::new(&[1.0, -1.0]),
+		Err(WeightedError::InvalidWeight(1))
+	);
+	assert_eq!(
+		WeightedIndex::new(&[f64::NAN]),
+		Err(WeightedError::InvalidWeight(0))
+	);
+	assert_eq!(
+		WeightedIndex::new(&[1.0, 2.0, f64::INFINITY]),
+		Err(WeightedError::InvalidWeight(2))
+	);
+	assert_eq!(
+		WeightedError::InvalidWeight(3).to_string(),
+		"the weight at index 3 is negative, NaN, or infinite"
+	);
+}
+
+#[test]
+fn bitset_lengths() {
+	let mut rng = WyRand::new_seed(42);
+	for n in [0, 1, 7, 8, 9, 63, 64, 65, 1000] {
+		let bytes = random_bitset(&mut rng, n);
+		assert_eq!(bytes.len(), n.div_ceil(8));
+		if !n.is_multiple_of(8) {
+			assert_eq!(bytes[n / 8] >> (n % 8), 0, "bits past {} are set", n);
+		}
+		assert_eq!(random_bits(&mut rng, n).len(), n);
+	}
+}
+
+#[test]
+fn bitset_is_half_set() {
+	let mut rng = WyRand::new_seed(42);
+	let ones: u32 = random_bitset(&mut rng, 100000)
+		.iter()
+		.map(|byte| byte.count_ones())
+		.sum();
+	assert!(ones.abs_diff(50000) < 1000, "{} / 100000", ones);
+	let bits = random_bits(&mut rng, 100000);
+	let ones = bits.iter().filter(|&&bit| bit).count();
+	assert!(ones.abs_diff(50000) < 1000, "{} / 100000", ones);
+	// Every position is set about half of the time, not just the total.
+	let mut per_position = [0u32; 13];
+	for _ in 0..10000 {
+		for (count, bit) in per_position.iter_mut().zip(random_bits(&mut rng, 13)) {
+			*count += bit as u32;
+		}
+	}
+	assert!(
+		per_position.iter().all(|&c| c.abs_diff(5000) < 300),
+		"{:?}",
+		per_position
+	);
+}
+
+#[test]
+fn bits_match_the_bitset() {
+	let bytes = random_bitset(&mut WyRand::new_seed(7), 20);
+	let bits = random_bits(&mut WyRand::new_seed(7), 20);
+	for (idx, &bit) in bits.iter().enumerate() {
+		assert_eq!(bit, bytes[idx / 8] & (1 << (idx % 8)) != 0);
+	}
+}
+
+/// The mean and variance of `samples` draws from the Poisson distribution with mean `lambda`.
+fn poisson_moments(rng: &mut WyRand, lambda: f64, samples: usize) -> (f64, f64) {
+	let draws: Vec<f64> = (0..samples)
+		.map(|_| random_poisson(rng, lambda) as f64)
+		.collect();
+	let mean = draws.iter().sum::<f64>() / samples as f64;
+	let variance = draws.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (samples - 1) as f64;
+	(mean, variance)
+}
+
+#[test]
+fn poisson_moments_match_lambda() {
+	let mut rng = WyRand::new_seed(42);
+	// Both sides of the switch between Knuth's algorithm and PTRS.
+	for &lambda in [0.01, 0.5, 3.0, 9.9, 10.0, 25.0, 400.0, 1e6].iter() {
+		let (mean, variance) = poisson_moments(&mut rng, lambda, 100000);
+		// The standard error of the mean is sqrt(lambda / samples), so this is over 6 of them.
+		let tolerance = 0.02 * lambda.sqrt();
+		assert!(
+			(mean - lambda).abs() < tolerance,
+			"mean {} for lambda {}",
+			mean,
+			lambda
+		);
+		assert!(
+			(variance / lambda - 1.0).abs() < 0.05,
+			"variance {} for lambda {}",
+			variance,
+			lambda
+		);
+	}
+}
+
+#[test]
+fn poisson_small_lambda_probabilities() {
+	// P(0) = e^-2, and P(2) = 2 e^-2.
+	let mut rng = WyRand::new_seed(42);
+	let mut counts = [0u32; 3];
+	for _ in 0..100000 {
+		let k = random_poisson(&mut rng, 2.0);
+		if k < 3 {
+			counts[k as usize] += 1;
+		}
+	}
+	let p0 = (-2.0_f64).exp();
+	assert!(
+		(counts[0] as f64 / 100000.0 - p0).abs() < 0.005,
+		"{:?}",
+		counts
+	);
+	assert!(
+		(counts[2] as f64 / 100000.0 - 2.0 * p0).abs() < 0.005,
+		"{:?}",
+		counts
+	);
+}
+
+#[test]
+#[should_panic(expected = "Lambda must be above 0 and finite")]
+fn poisson_zero_lambda() {
+	random_poisson(&mut WyRand::new_seed(42), 0.0);
+}
+
+#[test]
+#[should_panic(expected = "Lambda must be above 0 and finite")]
+fn poisson_nan_lambda() {
+	random_poisson(&mut WyRand::new_seed(42), f64::NAN);
+}
+
+#[test]
+fn binomial_moments() {
+	let mut rng = WyRand::new_seed(42);
+	// Covers running each trial, inverting the CDF, BTRD, and the flip for p above 0.5.
+	for &(n, p) in [
+		(10, 0.3),
+		(16, 0.9),
+		(100, 0.05),
+		(1000, 0.002),
+		(100, 0.5),
+		(1000, 0.3),
+		(1_000_000, 0.031),
+		(1 << 40, 0.75),
+	]
+	.iter()
+	{
+		let samples = 50000;
+		let draws: Vec<f64> = (0..samples)
+			.map(|_| {
+				let k = random_binomial(&mut rng, n, p);
+				assert!(k <= n);
+				k as f64
+			})
+			.collect();
+		let mean = draws.iter().sum::<f64>() / samples as f64;
+		let variance = draws.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (samples - 1) as f64;
+		let expected_variance = n as f64 * p * (1.0 - p);
+		// Over 6 standard errors of the mean.
+		let tolerance = 0.03 * expected_variance.sqrt();
+		assert!(
+			(mean - n as f64 * p).abs() < tolerance,
+			"mean {} for n {} and p {}",
+			mean,
+			n,
+			p
+		);
+		assert!(
+			(variance / expected_variance - 1.0).abs() < 0.05,
+			"variance {} for n {} and p {}",
+			variance,
+			n,
+			p
+		);
+	}
+}
+
+#[test]
+fn binomial_edges() {
+	let mut rng = WyRand::new_seed(42);
+	assert_eq!(random_binomial(&mut rng, 0, 0.5), 0);
+	assert_eq!(random_binomial(&mut rng, 1000, 0.0), 0);
+	assert_eq!(random_binomial(&mut rng, 1000, 1.0), 1000);
+	assert_eq!(random_binomial(&mut rng, u64::MAX, 1.0), u64::MAX);
+	// Every outcome of a small binomial comes up about as often as it should: 1, 3, 3, and 1 in 8.
+	let mut counts = [0u32; 4];
+	for _ in 0..80000 {
+		counts[random_binomial(&mut rng, 3, 0.5) as usize] += 1;
+	}
+	for (&count, expected) in counts.iter().zip([10000, 30000, 30000, 10000]) {
+		assert!(count.abs_diff(expected) < 800, "{:?}", counts);
+	}
+}
+
+#[test]
+fn binomial_btrd_probabilities() {
+	// Near the mode, in the shoulders, and out in the tails, where BTRD takes its different acceptance paths.
+	let mut rng = WyRand::new_seed(42);
+	let mut counts = [0u32; 101];
+	for _ in 0..1_000_000 {
+		counts[random_binomial(&mut rng, 100, 0.3) as usize] += 1;
+	}
+	for &(k, p) in [
+		(15, 0.0002476585738435941_f64),
+		(20, 0.007575644925726609),
+		(30, 0.08678386475342761),
+		(40, 0.008490168837486435),
+		(47, 0.0001384544550993995),
+	]
+	.iter()
+	{
+		let expected = p * 1_000_000.0;
+		// Over 5 standard deviations.
+		let tolerance = 5.0 * expected.sqrt();
+		assert!(
+			(counts[k] as f64 - expected).abs() < tolerance,
+			"{} came up {} times, instead of about {}",
+			k,
+			counts[k],
+			expected
+		);
+	}
+}
+
+#[test]
+#[should_panic(expected = "The probability must be within 0.0..=1.0")]
+fn binomial_bad_probability() {
+	random_binomial(&mut WyRand::new_seed(42), 10, 1.5);
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "std")]
+use nanorand::{WyRand, RNG};
+use std::time::Duration;
+
+#[test]
+fn random_duration_nanos_valid() {
+	let mut rng = WyRand::new();
+	for _ in 0..10000 {
+		let d: Duration = rng.generate();
+		assert!(d.subsec_nanos() < 1_000_000_000);
+	}
+}
+
+#[test]
+fn ranged_duration() {
+	let mut rng = WyRand::new();
+	let lower = Duration::from_millis(1500);
+	let upper = Duration::new(3, 250_000_000);
+	for _ in 0..10000 {
+		let d = rng.generate_range(lower..upper);
+		assert!(lower <= d && d < upper, "{:?} out of range", d);
+		assert!(d.subsec_nanos() < 1_000_000_000);
+
+		let d = rng.generate_range(lower..=upper);
+		assert!(lower <= d && d <= upper, "{:?} out of range", d);
+	}
+}
+
+#[test]
+fn ranged_duration_tiny_and_huge() {
+	let mut rng = WyRand::new();
+	let d = Duration::from_nanos(999_999_999);
+	assert_eq!(rng.generate_range(d..=d), d);
+	assert_eq!(rng.generate_range(d..d), d);
+	for _ in 0..1000 {
+		let d = rng.generate_range(Duration::from_secs(u64::MAX - 1)..);
+		assert!(d >= Duration::from_secs(u64::MAX - 1));
+		assert!(d.subsec_nanos() < 1_000_000_000);
+	}
+}
@@ -0,0 +1,50 @@
+use nanorand::{DynRng, Pcg64, WyRand, Xoroshiro128, RNG};
+
+fn rng_by_name(name: &str, seed: u64) -> Box<dyn DynRng> {
+	match name {
+		"wyrand" => WyRand::new_seed(seed).into_boxed(),
+		"pcg64" => Pcg64::new_seed(seed as u128).into_boxed(),
+		"xoroshiro" => Xoroshiro128::new_seed(seed).into_boxed(),
+		_ => panic!("unknown RNG {}", name),
+	}
+}
+
+#[test]
+fn pick_rng_at_runtime() {
+	let mut wyrand = rng_by_name("wyrand", 42);
+	let mut expected = WyRand::new_seed(42);
+	for _ in 0..100 {
+		assert_eq!(wyrand.generate::<u64>(), expected.generate::<u64>());
+		assert_eq!(
+			wyrand.generate_range(1_u8..=6),
+			expected.generate_range(1_u8..=6)
+		);
+	}
+
+	let mut pcg = rng_by_name("pcg64", 42);
+	let mut expected = Pcg64::new_seed(42);
+	for _ in 0..100 {
+		assert_eq!(pcg.generate::<u64>(), expected.generate::<u64>());
+	}
+
+	// Both handles have the same type, so they can be swapped.
+	let mut rngs = [rng_by_name("wyrand", 1), rng_by_name("xoroshiro", 1)];
+	rngs.swap(0, 1);
+	let mut expected = Xoroshiro128::new_seed(1);
+	assert_eq!(rngs[0].generate::<u32>(), expected.generate::<u32>());
+}
+
+#[test]
+fn dyn_ref() {
+	let mut rng = WyRand::new_seed(42);
+	let mut erased: &mut dyn DynRng = &mut rng;
+	let mut bytes = [0u8; 20];
+	erased.fill_bytes(&mut bytes);
+	let value: u16 = erased.generate();
+
+	let mut expected = WyRand::new_seed(42);
+	let mut expected_bytes = [0u8; 20];
+	expected.fill_bytes(&mut expected_bytes);
+	assert_eq!(bytes, expected_bytes);
+	assert_eq!(value, expected.generate::<u16>());
+}
@@ -0,0 +1,44 @@
+use nanorand::{Pcg64, SeedableRNG, WyRand, Xoroshiro128, RNG};
+
+// The first output of each generator, as a number.
+const WYRAND_42: u64 = 0xae4a7cbfdda9b434;
+const XOROSHIRO_42: u64 = 0xe88af6caef1d3c23;
+
+#[test]
+#[cfg(not(feature = "native-endian"))]
+fn outputs_are_little_endian() {
+	assert_eq!(WyRand::new_seed(42).rand(), WYRAND_42.to_le_bytes());
+	assert_eq!(
+		Xoroshiro128::new_seed(42).rand(),
+		XOROSHIRO_42.to_le_bytes()
+	);
+
+	// Seeds are read as little-endian too.
+	assert_eq!(
+		WyRand::from_seed(42u64.to_le_bytes()).rand(),
+		WYRAND_42.to_le_bytes()
+	);
+	assert_eq!(
+		Pcg64::from_seed(42u128.to_le_bytes()).rand(),
+		Pcg64::new_seed(42).rand()
+	);
+}
+
+#[test]
+#[cfg(feature = "native-endian")]
+fn outputs_are_native_endian() {
+	assert_eq!(WyRand::new_seed(42).rand(), WYRAND_42.to_ne_bytes());
+	assert_eq!(
+		Xoroshiro128::new_seed(42).rand(),
+		XOROSHIRO_42.to_ne_bytes()
+	);
+
+	assert_eq!(
+		WyRand::from_seed(42u64.to_ne_bytes()).rand(),
+		WYRAND_42.to_ne_bytes()
+	);
+	assert_eq!(
+		Pcg64::from_seed(42u128.to_ne_bytes()).rand(),
+		Pcg64::new_seed(42).rand()
+	);
+}
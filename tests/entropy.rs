@@ -0,0 +1,14 @@
+use nanorand::entropy::entropy_from_system;
+
+#[test]
+fn system_entropy_differs() {
+	let a = entropy_from_system(32);
+	let b = entropy_from_system(32);
+	assert_eq!(a.len(), 32);
+	assert_ne!(a, b);
+	assert_ne!(a, vec![42; 32]);
+
+	for &amt in [0, 1, 7, 8, 9, 33].iter() {
+		assert_eq!(entropy_from_system(amt).len(), amt);
+	}
+}
@@ -0,0 +1,90 @@
+use nanorand::{entropy::try_entropy_from_system, Pcg64, WyRand, Xoroshiro128};
+
+#[cfg(not(nanorand_test_entropy))]
+#[test]
+fn try_new_succeeds() {
+	use nanorand::RNG;
+
+	assert_eq!(try_entropy_from_system(100).unwrap().len(), 100);
+	assert_eq!(try_entropy_from_system(3).unwrap().len(), 3);
+	#[cfg(all(unix, not(feature = "getrandom")))]
+	{
+		use nanorand::entropy::unix;
+
+		assert_eq!(unix::entropy_from_system(16).len(), 16);
+		assert_eq!(unix::try_entropy_from_system(16).unwrap().len(), 16);
+	}
+	WyRand::try_new().unwrap().generate::<u64>();
+	Pcg64::try_new().unwrap().generate::<u64>();
+	Xoroshiro128::try_new().unwrap().generate::<u64>();
+	#[cfg(feature = "chacha")]
+	nanorand::ChaCha::try_new(8).unwrap().generate::<u64>();
+}
+
+#[cfg(not(nanorand_test_entropy))]
+#[test]
+fn checked_entropy_passes() {
+	use nanorand::entropy::try_checked_entropy_from_system;
+
+	assert_eq!(try_checked_entropy_from_system(3).unwrap().len(), 3);
+	assert_eq!(try_checked_entropy_from_system(0).unwrap().len(), 0);
+	assert_eq!(try_checked_entropy_from_system(64).unwrap().len(), 64);
+	assert!(WyRand::new_checked().is_ok());
+}
+
+// The rest need `RUSTFLAGS="--cfg nanorand_test_entropy"`, which swaps the system entropy source for a stand-in,
+// and `NANORAND_TEST_ENTROPY=fail` to make it always fail, or `NANORAND_TEST_ENTROPY=constant` to make it always give the same bytes.
+#[cfg(nanorand_test_entropy)]
+fn test_entropy_is(mode: &str) -> bool {
+	std::env::var("NANORAND_TEST_ENTROPY").unwrap() == mode
+}
+
+#[cfg(nanorand_test_entropy)]
+#[test]
+fn try_new_reports_the_backend() {
+	if !test_entropy_is("fail") {
+		return;
+	}
+	let err = try_entropy_from_system(8).unwrap_err();
+	assert_eq!(err.backend(), "the failing test backend");
+	assert_eq!(
+		err.to_string(),
+		"failed to obtain entropy from the failing test backend"
+	);
+	let _: &dyn std::error::Error = &err;
+
+	assert_eq!(WyRand::try_new().unwrap_err(), err);
+	assert_eq!(Pcg64::try_new().unwrap_err(), err);
+	assert_eq!(Xoroshiro128::try_new().unwrap_err(), err);
+	#[cfg(feature = "chacha")]
+	assert_eq!(nanorand::ChaCha::try_new(8).unwrap_err(), err);
+}
+
+#[cfg(nanorand_test_entropy)]
+#[test]
+fn new_panics_with_the_backend() {
+	if !test_entropy_is("fail") {
+		return;
+	}
+	let panic = std::panic::catch_unwind(WyRand::new).unwrap_err();
+	assert_eq!(
+		panic.downcast_ref::<String>().unwrap(),
+		"Couldn't seed WyRand: failed to obtain entropy from the failing test backend"
+	);
+}
+
+#[cfg(nanorand_test_entropy)]
+#[test]
+fn checked_entropy_catches_a_stuck_source() {
+	if !test_entropy_is("constant") {
+		return;
+	}
+	let err = WyRand::new_checked().unwrap_err();
+	assert!(err.is_degenerate());
+	assert_eq!(
+		err.to_string(),
+		"the entropy from the system entropy source looks stuck"
+	);
+	// The unchecked constructors don't notice.
+	assert!(WyRand::try_new().is_ok());
+}
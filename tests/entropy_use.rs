@@ -0,0 +1,76 @@
+use nanorand::{WyRand, RNG};
+
+/// An RNG with 4 byte outputs, smaller than most of the integer types.
+struct SmallRng(WyRand);
+
+impl RNG for SmallRng {
+	type Output = [u8; 4];
+	const OUTPUT_SIZE: usize = 4;
+
+	fn rand(&mut self) -> Self::Output {
+		let mut out = [0u8; 4];
+		out.copy_from_slice(&self.0.rand()[..4]);
+		out
+	}
+}
+
+#[test]
+fn u128_uses_all_bits() {
+	let mut rng = WyRand::new();
+	let mut above_u64 = 0;
+	let mut top_bit = 0;
+	for _ in 0..10000 {
+		let n = rng.generate::<u128>();
+		if n > u64::MAX as u128 {
+			above_u64 += 1;
+		}
+		if n >> 127 == 1 {
+			top_bit += 1;
+		}
+	}
+	// Values at or below u64::MAX have a 2^-64 chance, and the top bit is set half of the time.
+	assert_eq!(above_u64, 10000);
+	assert!(
+		(4500..5500).contains(&top_bit),
+		"top bit set {} times",
+		top_bit
+	);
+}
+
+#[test]
+fn small_outputs_fill_large_integers() {
+	let mut rng = SmallRng(WyRand::new());
+	let mut high_bits = 0;
+	for _ in 0..10000 {
+		if rng.generate::<u64>() >> 32 != 0 {
+			high_bits += 1;
+		}
+	}
+	assert!(high_bits > 9990);
+
+	for _ in 0..10000 {
+		let n = rng.generate_range(0..(u64::MAX / 3 * 2));
+		assert!(n < u64::MAX / 3 * 2);
+	}
+	let mut high_bits = 0;
+	for _ in 0..10000 {
+		if rng.generate_range(0..u64::MAX - 5) >> 32 != 0 {
+			high_bits += 1;
+		}
+	}
+	assert!(high_bits > 9990);
+}
+
+#[test]
+fn small_types_use_one_output() {
+	let mut rng = WyRand::new_seed(42);
+	let mut expected = WyRand::new_seed(42);
+	for _ in 0..100 {
+		let out = expected.rand();
+		assert_eq!(rng.generate::<u8>(), out[0]);
+	}
+	for _ in 0..100 {
+		let out = expected.rand();
+		assert_eq!(rng.generate::<u16>(), u16::from_le_bytes([out[0], out[1]]));
+	}
+}
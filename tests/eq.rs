@@ -0,0 +1,52 @@
+use nanorand::{Pcg64, WyRand, Xoroshiro128, RNG};
+use std::collections::HashSet;
+
+macro_rules! eq_tests {
+	($($rng:ty),*) => {
+		$(
+			let mut a = <$rng>::new_seed(42);
+			let mut b = <$rng>::new_seed(42);
+			assert_eq!(a, b);
+			a.generate::<u64>();
+			assert_ne!(a, b);
+			b.generate::<u64>();
+			assert_eq!(a, b);
+			assert_ne!(<$rng>::new_seed(1), <$rng>::new_seed(2));
+
+			let set: HashSet<$rng> = (0..10).map(<$rng>::new_seed).collect();
+			assert_eq!(set.len(), 10);
+			assert!(set.contains(&<$rng>::new_seed(3)));
+		)*
+	};
+}
+
+#[test]
+fn equal_states() {
+	eq_tests!(WyRand, Xoroshiro128);
+	let mut a = Pcg64::new_seed(42);
+	let mut b = Pcg64::new_seed(42);
+	assert_eq!(a, b);
+	a.generate::<u64>();
+	assert_ne!(a, b);
+	b.generate::<u64>();
+	assert_eq!(a, b);
+	assert_ne!(Pcg64::new_seed(1), Pcg64::new_seed(2));
+	let set: HashSet<Pcg64> = (0..10).map(Pcg64::new_seed).collect();
+	assert_eq!(set.len(), 10);
+}
+
+#[test]
+#[cfg(feature = "chacha")]
+fn chacha_equality() {
+	use nanorand::ChaCha;
+	let mut a = ChaCha::new_key(20, [1; 32], [2; 16]);
+	let mut b = ChaCha::new_key(20, [1; 32], [2; 16]);
+	assert_eq!(a, b);
+	assert_ne!(a, ChaCha::new_key(8, [1; 32], [2; 16]));
+	assert_ne!(a, ChaCha::new_key(20, [3; 32], [2; 16]));
+	a.apply_keystream(&mut [0; 10]);
+	assert_ne!(a, b);
+	b.apply_keystream(&mut [0; 4]);
+	b.apply_keystream(&mut [0; 6]);
+	assert_eq!(a, b);
+}
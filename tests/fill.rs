@@ -0,0 +1,365 @@
+use nanorand::{
+	adapter::{CountingRng, FnRng},
+	Pcg64, WyRand, Xoroshiro128, RNG,
+};
+
+macro_rules! fill_matches_bytes {
+	($rng:expr, $($ty:ty),*) => {
+		$(
+			for &len in [0, 1, 3, 64, 65, 1000].iter() {
+				let mut fast = vec![0 as $ty; len];
+				let mut rng = $rng;
+				rng.fill(&mut fast);
+
+				let mut bytes = vec![0u8; len * std::mem::size_of::<$ty>()];
+				let mut rng = $rng;
+				rng.fill_bytes(&mut bytes);
+				let slow = bytes
+					.chunks_exact(std::mem::size_of::<$ty>())
+					.map(|b| {
+						let mut le = [0u8; std::mem::size_of::<$ty>()];
+						le.copy_from_slice(b);
+						<$ty>::from_le_bytes(le)
+					})
+					.collect::<Vec<$ty>>();
+				assert_eq!(fast, slow);
+			}
+		)*
+	};
+}
+
+#[test]
+fn fill_integers_in_bulk() {
+	fill_matches_bytes!(WyRand::new_seed(42), u8, u16, u32, u64, u128, i32, i64);
+	fill_matches_bytes!(Pcg64::new_seed(42), u32, u64);
+	fill_matches_bytes!(Xoroshiro128::new_seed(42), u16, u128);
+}
+
+#[test]
+fn fill_other_types() {
+	let mut rng = WyRand::new_seed(42);
+	let mut chars = ['\0'; 100];
+	rng.fill(&mut chars);
+
+	let mut expected = WyRand::new_seed(42);
+	for c in chars.iter() {
+		assert_eq!(*c, expected.generate::<char>());
+	}
+}
+
+#[test]
+fn fill_floats() {
+	let mut rng = WyRand::new_seed(42);
+	let mut floats = [0f64; 1000];
+	rng.fill(&mut floats);
+	assert!(floats.iter().all(|f| (0.0..1.0).contains(f)));
+
+	// WyRand outputs exactly one u64, so the bulk path lines up with generating one at a time.
+	let mut expected = WyRand::new_seed(42);
+	for f in floats.iter() {
+		assert_eq!(*f, expected.generate::<f64>());
+	}
+	let mut ints = [0u64; 1000];
+	WyRand::new_seed(42).fill(&mut ints);
+	for (i, f) in ints.iter().zip(floats.iter()) {
+		assert_eq!((i >> 11) as f64 / (1u64 << 53) as f64, *f);
+	}
+
+	let mut floats = [0f32; 333];
+	WyRand::new_seed(42).fill(&mut floats);
+	let mut ints = [0u32; 333];
+	WyRand::new_seed(42).fill(&mut ints);
+	for (i, f) in ints.iter().zip(floats.iter()) {
+		assert!((0.0..1.0).contains(f));
+		assert_eq!((i >> 8) as f32 / (1u32 << 24) as f32, *f);
+	}
+	let mean = floats.iter().sum::<f32>() / floats.len() as f32;
+	assert!((0.4..0.6).contains(&mean));
+}
+
+#[test]
+fn generate_vec_matches_generate() {
+	let mut rng = WyRand::new_seed(42);
+	let mut expected = WyRand::new_seed(42);
+	let words: Vec<u32> = rng.generate_vec(100);
+	assert_eq!(words.len(), 100);
+	for word in words {
+		assert_eq!(word, expected.generate::<u32>());
+	}
+	let chars: Vec<char> = rng.generate_vec(20);
+	let bools: Vec<bool> = rng.generate_vec(20);
+	let floats: Vec<f64> = rng.generate_vec(20);
+	for &c in chars.iter() {
+		assert_eq!(c, expected.generate::<char>());
+	}
+	for &b in bools.iter() {
+		assert_eq!(b, expected.generate::<bool>());
+	}
+	for &f in floats.iter() {
+		assert_eq!(f, expected.generate::<f64>());
+	}
+	assert_eq!(rng, expected);
+}
+
+#[test]
+fn generate_vec_bytes_are_bulk() {
+	let bytes: Vec<u8> = WyRand::new_seed(42).generate_vec(1000);
+	let mut expected = [0u8; 1000];
+	WyRand::new_seed(42).fill_bytes(&mut expected);
+	assert_eq!(bytes, expected);
+	let signed: Vec<i8> = WyRand::new_seed(42).generate_vec(1000);
+	assert!(signed
+		.iter()
+		.zip(expected.iter())
+		.all(|(&s, &b)| s as u8 == b));
+}
+
+#[test]
+fn generate_vec_capacity() {
+	let mut rng = WyRand::new_seed(42);
+	let empty: Vec<u64> = rng.generate_vec(0);
+	assert!(empty.is_empty());
+	let empty: Vec<u8> = rng.generate_vec(0);
+	assert!(empty.is_empty());
+	// Nothing is generated for an empty vector.
+	assert_eq!(rng, WyRand::new_seed(42));
+	// Allocated once, at exactly the requested length.
+	let big: Vec<u64> = rng.generate_vec(100_000);
+	assert_eq!((big.len(), big.capacity()), (100_000, 100_000));
+	let big: Vec<u8> = rng.generate_vec(1_000_003);
+	assert_eq!((big.len(), big.capacity()), (1_000_003, 1_000_003));
+}
+
+#[test]
+fn generate_collect_matches_generate() {
+	let mut rng = WyRand::new_seed(42);
+	let mut expected = WyRand::new_seed(42);
+	let bytes: Vec<u8> = rng.generate_collect(10);
+	for byte in bytes {
+		assert_eq!(byte, expected.generate::<u8>());
+	}
+	let text = rng.generate_collect::<String, char>(5);
+	assert_eq!(text.chars().count(), 5);
+	for c in text.chars() {
+		assert_eq!(c, expected.generate::<char>());
+	}
+	let set: std::collections::BTreeSet<u64> = rng.generate_collect(50);
+	assert_eq!(set.len(), 50);
+	let none: Vec<u16> = rng.generate_collect(0);
+	assert!(none.is_empty());
+}
+
+#[test]
+fn fill_range_is_uniform() {
+	let mut rng = WyRand::new_seed(42);
+	let mut rolls = vec![0_u8; 60000];
+	rng.fill_range(&mut rolls, 0, 6);
+	let mut counts = [0u32; 6];
+	rolls.iter().for_each(|&roll| counts[roll as usize] += 1);
+	let chi_square: f64 = counts
+		.iter()
+		.map(|&c| (c as f64 - 10000.0).powi(2) / 10000.0)
+		.sum();
+	// 99.9th percentile for 5 degrees of freedom.
+	assert!(
+		chi_square < 20.52,
+		"chi-square {} for {:?}",
+		chi_square,
+		counts
+	);
+
+	// A range that rejects a lot: 256 % 129 = 127, so nearly half of all candidates are thrown away.
+	let mut values = vec![0_u8; 129 * 200];
+	rng.fill_range(&mut values, 100, 229);
+	let mut counts = [0u32; 129];
+	values.iter().for_each(|&v| counts[v as usize - 100] += 1);
+	let chi_square: f64 = counts
+		.iter()
+		.map(|&c| (c as f64 - 200.0).powi(2) / 200.0)
+		.sum();
+	// 99.9th percentile for 128 degrees of freedom.
+	assert!(chi_square < 183.0, "chi-square {}", chi_square);
+
+	let mut wide = [0_u16; 1000];
+	rng.fill_range(&mut wide, 1000, 2000);
+	assert!(wide.iter().all(|w| (1000..2000).contains(w)));
+	let mut signed = [0_i8; 1000];
+	rng.fill_range(&mut signed, -3, 3);
+	assert!(signed.iter().all(|s| (-3..3).contains(s)));
+	let mut floats = [0.0_f64; 100];
+	rng.fill_range(&mut floats, 0.5, 1.5);
+	assert!(floats.iter().all(|f| (0.5..1.5).contains(f)));
+}
+
+#[test]
+fn fill_range_reuses_outputs() {
+	let mut rng = CountingRng::new(WyRand::new_seed(42));
+	let mut rolls = [0_u8; 1000];
+	rng.fill_range(&mut rolls, 0, 6);
+	// 8 candidates per output, and only 4 in 256 are rejected.
+	assert!(rng.calls() < 130, "{} outputs", rng.calls());
+	let mut words = [0_u32; 1000];
+	rng.fill_range(&mut words, 0, 6);
+	assert!(rng.calls() < 130 + 510, "{} outputs", rng.calls());
+}
+
+#[test]
+fn fill_range_empty() {
+	let mut rng = WyRand::new_seed(42);
+	let mut values = [1_u8; 10];
+	rng.fill_range(&mut values, 7, 7);
+	assert_eq!(values, [7; 10]);
+	let mut nothing: [u64; 0] = [];
+	rng.fill_range(&mut nothing, 0, 10);
+	// Neither of those needed any randomness.
+	assert_eq!(rng, WyRand::new_seed(42));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "must not be above its upper bound")]
+fn fill_range_inverted_debug_panics() {
+	WyRand::new_seed(42).fill_range(&mut [0_u8; 4], 6, 0);
+}
+
+#[test]
+fn alphanumeric_string_length_and_alphabet() {
+	let mut rng = WyRand::new_seed(42);
+	for len in [0, 1, 7, 8, 9, 32, 1000] {
+		let token = rng.alphanumeric_string(len);
+		assert_eq!(token.len(), len);
+		assert!(
+			token.bytes().all(|byte| byte.is_ascii_alphanumeric()),
+			"{}",
+			token
+		);
+	}
+}
+
+#[test]
+fn alphanumeric_covers_the_alphabet() {
+	let mut rng = WyRand::new_seed(42);
+	let mut buffer = [0_u8; 62 * 1000];
+	rng.fill_alphanumeric(&mut buffer);
+	let mut counts = [0_u32; 128];
+	for byte in buffer {
+		counts[byte as usize] += 1;
+	}
+	let used = (0..128_u8).filter(|byte| counts[*byte as usize] > 0);
+	assert!(used.clone().all(|byte| byte.is_ascii_alphanumeric()));
+	assert_eq!(used.count(), 62);
+	// Each character should be about as likely as any other.
+	let chi_square: f64 = counts
+		.iter()
+		.filter(|&&count| count > 0)
+		.map(|&count| (count as f64 - 1000.0).powi(2) / 1000.0)
+		.sum();
+	assert!(chi_square < 110.0, "chi-square {}", chi_square);
+}
+
+#[test]
+fn alphanumeric_is_deterministic() {
+	let token = WyRand::new_seed(42).alphanumeric_string(32);
+	assert_eq!(token, "Mro1ueRqy0NOzCx4OQjKTUVxbGDMmrJ4");
+	let mut buffer = [0_u8; 32];
+	WyRand::new_seed(42).fill_alphanumeric(&mut buffer);
+	assert_eq!(buffer, token.as_bytes());
+}
+
+fn decode_hex(hex: &str) -> Vec<u8> {
+	assert_eq!(hex.len() % 2, 0);
+	(0..hex.len())
+		.step_by(2)
+		.map(|idx| u8::from_str_radix(&hex[idx..idx + 2], 16).unwrap())
+		.collect()
+}
+
+fn decode_base64url(encoded: &str) -> Vec<u8> {
+	let alphabet = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+	let mut bits = 0_u32;
+	let mut bit_count = 0;
+	let mut decoded = Vec::new();
+	for c in encoded.chars() {
+		bits = bits << 6 | alphabet.find(c).unwrap() as u32;
+		bit_count += 6;
+		if bit_count >= 8 {
+			bit_count -= 8;
+			decoded.push((bits >> bit_count) as u8);
+		}
+	}
+	// Leftover bits must be zero, as they would be for a canonical encoding.
+	assert_eq!(bits & ((1 << bit_count) - 1), 0);
+	decoded
+}
+
+#[test]
+fn hex_string_round_trips() {
+	let mut rng = WyRand::new_seed(42);
+	for bytes in [0, 1, 2, 3, 7, 8, 9, 16, 100] {
+		let mut expected = vec![0; bytes];
+		rng.clone().fill_bytes(&mut expected);
+		let hex = rng.hex_string(bytes);
+		assert_eq!(hex.len(), 2 * bytes);
+		assert!(
+			hex.bytes().all(|c| matches!(c, b'0'..=b'9' | b'a'..=b'f')),
+			"{}",
+			hex
+		);
+		assert_eq!(decode_hex(&hex), expected);
+	}
+	assert_eq!(decode_hex("00ff7f80"), [0x00, 0xFF, 0x7F, 0x80]);
+}
+
+#[test]
+fn base64url_string_round_trips() {
+	let mut rng = WyRand::new_seed(42);
+	for (bytes, len) in [
+		(0, 0),
+		(1, 2),
+		(2, 3),
+		(3, 4),
+		(4, 6),
+		(5, 7),
+		(16, 22),
+		(24, 32),
+		(100, 134),
+	] {
+		let mut expected = vec![0; bytes];
+		rng.clone().fill_bytes(&mut expected);
+		let encoded = rng.base64url_string(bytes);
+		assert_eq!(encoded.len(), len);
+		assert!(
+			encoded
+				.bytes()
+				.all(|c| c.is_ascii_alphanumeric() || c == b'-' || c == b'_'),
+			"{}",
+			encoded
+		);
+		assert_eq!(decode_base64url(&encoded), expected);
+	}
+}
+
+#[test]
+fn base64url_uses_the_whole_alphabet() {
+	let encoded = WyRand::new_seed(42).base64url_string(3000);
+	let mut seen = [false; 128];
+	for c in encoded.bytes() {
+		seen[c as usize] = true;
+	}
+	assert_eq!(seen.iter().filter(|&&seen| seen).count(), 64);
+	assert!(seen[b'-' as usize] && seen[b'_' as usize]);
+}
+
+#[test]
+fn token_encodings_match_rfc_4648() {
+	// The test vectors from RFC 4648 section 10, with the bytes of "foobar" coming straight from the generator.
+	let mut rng = FnRng::new(|| u64::from_le_bytes(*b"foobar!!"));
+	let vectors = ["", "Zg", "Zm8", "Zm9v", "Zm9vYg", "Zm9vYmE", "Zm9vYmFy"];
+	for (bytes, expected) in vectors.iter().enumerate() {
+		assert_eq!(rng.base64url_string(bytes), *expected);
+	}
+	assert_eq!(rng.hex_string(6), "666f6f626172");
+	let mut rng = FnRng::new(|| u64::from_le_bytes([0xFB, 0xFF, 0xBF, 0, 0, 0, 0, 0]));
+	// These would be "+/+/" in standard base64.
+	assert_eq!(rng.base64url_string(3), "-_-_");
+}
@@ -0,0 +1,90 @@
+use nanorand::{random_range_f32, random_range_f64, RandomRange, WyRand, RNG};
+
+/// The float right after the positive `x`, without `f64::next_up`, which needs Rust 1.86.
+fn next_up(x: f64) -> f64 {
+	f64::from_bits(x.to_bits() + 1)
+}
+
+/// Which of `lower`, the float right after it, and the float after that, come up.
+fn hits(lower_inclusive: bool, upper_inclusive: bool) -> [bool; 3] {
+	let mut rng = WyRand::new_seed(42);
+	let values = [1.0_f64, next_up(1.0), next_up(next_up(1.0))];
+	let mut seen = [false; 3];
+	for _ in 0..1000 {
+		let x = random_range_f64(
+			&mut rng,
+			values[0],
+			values[2],
+			lower_inclusive,
+			upper_inclusive,
+		);
+		let idx = values.iter().position(|&v| v == x).expect("out of bounds");
+		seen[idx] = true;
+	}
+	seen
+}
+
+#[test]
+fn endpoint_inclusivity() {
+	assert_eq!(hits(true, true), [true, true, true]);
+	assert_eq!(hits(true, false), [true, true, false]);
+	assert_eq!(hits(false, true), [false, true, true]);
+	assert_eq!(hits(false, false), [false, true, false]);
+}
+
+#[test]
+fn float_ranges_stay_in_bounds() {
+	let mut rng = WyRand::new_seed(42);
+	for _ in 0..10000 {
+		let x = random_range_f64(&mut rng, -3.5, 7.25, false, false);
+		assert!(x > -3.5 && x < 7.25);
+		let x = random_range_f32(&mut rng, 0.0, 1e-30, true, true);
+		assert!((0.0..=1e-30).contains(&x));
+		let x = random_range_f32(&mut rng, 100.0, 100.5, false, true);
+		assert!(x > 100.0 && x <= 100.5);
+		let x = rng.generate_range(0.5_f64..2.0);
+		assert!((0.5..2.0).contains(&x));
+		let x = rng.generate_range(-1.0_f32..=1.0);
+		assert!((-1.0..=1.0).contains(&x));
+		let x: f64 = rng.generate_range(..);
+		assert!(x.is_finite());
+	}
+}
+
+#[test]
+fn float_range_distribution() {
+	let mut rng = WyRand::new_seed(42);
+	let mut buckets = [0u32; 10];
+	for _ in 0..100000 {
+		let x = f64::random_range(&mut rng, 10.0, 20.0);
+		buckets[(x - 10.0) as usize] += 1;
+	}
+	assert!(
+		buckets.iter().all(|&b| (9500..10500).contains(&b)),
+		"{:?}",
+		buckets
+	);
+}
+
+#[test]
+fn empty_float_ranges() {
+	let mut rng = WyRand::new_seed(42);
+	assert_eq!(random_range_f64(&mut rng, 2.0, 2.0, false, false), 2.0);
+	assert_eq!(
+		random_range_f64(&mut rng, 2.0, next_up(2.0), false, false),
+		2.0
+	);
+	assert_eq!(f32::random_range(&mut rng, 5.0, 5.0), 5.0);
+}
+
+#[test]
+#[should_panic]
+fn infinite_bounds() {
+	random_range_f64(&mut WyRand::new_seed(42), 0.0, f64::INFINITY, true, false);
+}
+
+#[test]
+#[should_panic]
+fn nan_bounds() {
+	random_range_f32(&mut WyRand::new_seed(42), f32::NAN, 1.0, true, false);
+}
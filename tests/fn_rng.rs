@@ -0,0 +1,54 @@
+use nanorand::{adapter::FnRng, WyRand, RNG};
+
+#[test]
+fn outputs_come_from_the_closure() {
+	let mut counter = 0_u64;
+	let mut rng = FnRng::new(|| {
+		counter += 1;
+		counter
+	});
+	assert_eq!(rng.generate::<u64>(), 1);
+	// A u128 takes two outputs, as little-endian.
+	assert_eq!(rng.generate::<u128>(), 2 | 3 << 64);
+	assert_eq!(rng.generate::<u8>(), 4);
+	assert_eq!(counter, 4);
+}
+
+#[test]
+fn matches_the_wrapped_generator() {
+	let mut inner = WyRand::new_seed(42);
+	let mut rng = FnRng::new(move || inner.generate::<u64>());
+	let mut expected = WyRand::new_seed(42);
+
+	assert_eq!(rng.generate::<u32>(), expected.generate::<u32>());
+	assert_eq!(
+		rng.generate_range(1_u8..=6),
+		expected.generate_range(1_u8..=6)
+	);
+	let mut shuffled: Vec<u32> = (0..100).collect();
+	let mut expected_shuffled = shuffled.clone();
+	rng.shuffle(&mut shuffled);
+	expected.shuffle(&mut expected_shuffled);
+	assert_eq!(shuffled, expected_shuffled);
+
+	assert_eq!(format!("{:?}", rng), "FnRng { .. }");
+}
+
+#[test]
+fn injected_sequence_shuffle() {
+	// Shuffling is deterministic for a given sequence, and still gives a permutation.
+	let sequence = || {
+		let mut n = 0_u64;
+		FnRng::new(move || {
+			n = n.wrapping_add(0x9e3779b97f4a7c15);
+			n
+		})
+	};
+	let mut items: Vec<u32> = (0..20).collect();
+	let mut again = items.clone();
+	sequence().shuffle(&mut items);
+	sequence().shuffle(&mut again);
+	assert_eq!(items, again);
+	items.sort_unstable();
+	assert_eq!(items, (0..20).collect::<Vec<u32>>());
+}
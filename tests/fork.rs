@@ -0,0 +1,52 @@
+use nanorand::{Pcg64, SeedableRNG, WyRand, Xoroshiro128, RNG};
+
+fn outputs<R: RNG>(rng: &mut R) -> Vec<u64> {
+	(0..16).map(|_| rng.generate()).collect()
+}
+
+fn check_fork<R: SeedableRNG>(seed: R::Seed)
+where
+	R::Seed: Clone,
+{
+	let mut parent = R::from_seed(seed.clone());
+	let mut first = parent.fork();
+	let mut second = parent.fork();
+	let first_outputs = outputs(&mut first);
+	let second_outputs = outputs(&mut second);
+	let parent_outputs = outputs(&mut parent);
+	assert_ne!(first_outputs, second_outputs);
+	assert_ne!(first_outputs, parent_outputs);
+	assert_ne!(second_outputs, parent_outputs);
+
+	// The whole tree is reproducible from the root seed.
+	let mut parent = R::from_seed(seed);
+	assert_eq!(outputs(&mut parent.fork()), first_outputs);
+	assert_eq!(outputs(&mut parent.fork()), second_outputs);
+	assert_eq!(outputs(&mut parent), parent_outputs);
+}
+
+#[test]
+fn forked_children() {
+	check_fork::<WyRand>([42; 8]);
+	check_fork::<Pcg64>([42; 16]);
+	check_fork::<Xoroshiro128>([42; 16]);
+	#[cfg(feature = "chacha")]
+	check_fork::<nanorand::ChaCha>([42; 32]);
+}
+
+#[test]
+fn forking_advances_parent() {
+	let mut forked = WyRand::new_seed(42);
+	let mut untouched = WyRand::new_seed(42);
+	forked.fork();
+	assert_ne!(outputs(&mut forked), outputs(&mut untouched));
+}
+
+#[test]
+fn grandchildren() {
+	let mut root = WyRand::new_seed(7);
+	let mut child = root.fork();
+	let grandchild = outputs(&mut child.fork());
+	let mut root = WyRand::new_seed(7);
+	assert_eq!(outputs(&mut root.fork().fork()), grandchild);
+}
@@ -0,0 +1,46 @@
+use nanorand::{iter::IntoRandIter, WyRand, RNG};
+
+#[test]
+fn iter_matches_generate() {
+	let mut rng = WyRand::new_seed(42);
+	let mut expected = WyRand::new_seed(42);
+	let values: Vec<u64> = rng.iter().take(1000).collect();
+	for value in values {
+		assert_eq!(value, expected.generate::<u64>());
+	}
+	// The borrowed RNG continues where the iterator stopped.
+	assert_eq!(rng.generate::<u64>(), expected.generate::<u64>());
+}
+
+#[test]
+fn iter_adapters() {
+	let mut rng = WyRand::new();
+	let odd: Vec<u8> = rng.iter::<u8>().filter(|n| n % 2 == 1).take(100).collect();
+	assert_eq!(odd.len(), 100);
+	assert!(odd.iter().all(|n| n % 2 == 1));
+
+	let floats = rng.iter::<f64>();
+	for (i, f) in (0..100).zip(floats) {
+		assert!((0.0..1.0).contains(&f), "{} at {} out of range", f, i);
+	}
+	assert_eq!(rng.iter::<u32>().size_hint(), (usize::MAX, None));
+}
+
+fn owned_iter(seed: u64) -> impl Iterator<Item = u16> + 'static {
+	WyRand::new_seed(seed).into_rand_iter()
+}
+
+#[test]
+fn into_rand_iter_owned() {
+	let mut expected = WyRand::new_seed(7);
+	for value in owned_iter(7).take(100) {
+		assert_eq!(value, expected.generate::<u16>());
+	}
+
+	let mut iter: IntoRandIter<WyRand, u64> = WyRand::new_seed(7).into_rand_iter();
+	iter.next();
+	let mut rng = iter.into_inner();
+	let mut expected = WyRand::new_seed(7);
+	expected.generate::<u64>();
+	assert_eq!(rng.generate::<u64>(), expected.generate::<u64>());
+}
@@ -0,0 +1,27 @@
+// This needs `RUSTFLAGS="--cfg nanorand_test_entropy"` and `NANORAND_TEST_ENTROPY=constant`,
+// so the stand-in entropy source can count how many times it's asked for entropy.
+#![cfg(nanorand_test_entropy)]
+use nanorand::{entropy::test_entropy_fetches, WyRand, RNG};
+use std::collections::HashSet;
+
+// This is the only test in this file, so nothing else fetches entropy while it's counting.
+#[test]
+fn new_fetches_entropy_once() {
+	if std::env::var("NANORAND_TEST_ENTROPY").unwrap() != "constant" {
+		return;
+	}
+	let before = test_entropy_fetches();
+	let outputs: HashSet<u64> = (0..1000)
+		.map(|n| {
+			let mut rng = if n % 2 == 0 {
+				WyRand::new()
+			} else {
+				WyRand::default()
+			};
+			rng.generate::<u64>()
+		})
+		.collect();
+	assert_eq!(test_entropy_fetches() - before, 1);
+	// Every generator still gets its own seed.
+	assert_eq!(outputs.len(), 1000);
+}
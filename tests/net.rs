@@ -0,0 +1,56 @@
+use nanorand::{net::*, WyRand, RNG};
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+#[test]
+fn random_addresses() {
+	let mut rng = WyRand::new_seed(42);
+	let v4: Vec<Ipv4Addr> = rng.iter().take(100).collect();
+	assert!(v4.iter().any(|addr| addr.octets()[0] >= 128));
+	assert!(v4.windows(2).any(|pair| pair[0] != pair[1]));
+	let v6: Vec<Ipv6Addr> = rng.iter().take(100).collect();
+	assert!(v6.iter().any(|addr| addr.segments()[0] >= 0x8000));
+	assert!(v6.windows(2).any(|pair| pair[0] != pair[1]));
+}
+
+#[test]
+fn ipv4_subnets() {
+	let mut rng = WyRand::new_seed(42);
+	let mut hosts = [false; 256];
+	for _ in 0..10000 {
+		// Host bits in the network address are ignored.
+		let addr = ipv4_in_subnet(&mut rng, Ipv4Addr::new(192, 168, 1, 77), 24);
+		assert_eq!(addr.octets()[..3], [192, 168, 1]);
+		hosts[addr.octets()[3] as usize] = true;
+
+		let addr = ipv4_in_subnet(&mut rng, Ipv4Addr::new(10, 0, 0, 0), 8);
+		assert_eq!(addr.octets()[0], 10);
+		let addr = ipv4_in_subnet(&mut rng, Ipv4Addr::new(172, 16, 0, 0), 12);
+		assert_eq!(
+			u32::from(addr) >> 20,
+			u32::from(Ipv4Addr::new(172, 16, 0, 0)) >> 20
+		);
+	}
+	assert!(hosts.iter().all(|&seen| seen));
+	let addr = Ipv4Addr::new(1, 2, 3, 4);
+	assert_eq!(ipv4_in_subnet(&mut rng, addr, 32), addr);
+	ipv4_in_subnet(&mut rng, addr, 0);
+}
+
+#[test]
+fn ipv6_subnets() {
+	let mut rng = WyRand::new_seed(42);
+	let network = Ipv6Addr::new(0x2001, 0xdb8, 0xabcd, 0, 0, 0, 0, 0);
+	for _ in 0..1000 {
+		let addr = ipv6_in_subnet(&mut rng, network, 48);
+		assert_eq!(addr.segments()[..3], network.segments()[..3]);
+		let addr = ipv6_in_subnet(&mut rng, network, 50);
+		assert_eq!(u128::from(addr) >> 78, u128::from(network) >> 78);
+	}
+	assert_eq!(ipv6_in_subnet(&mut rng, network, 128), network);
+}
+
+#[test]
+#[should_panic]
+fn prefix_too_long() {
+	ipv4_in_subnet(&mut WyRand::new_seed(42), Ipv4Addr::LOCALHOST, 33);
+}
@@ -0,0 +1,106 @@
+use nanorand::{
+	adapter::{BufferedRng, CombinedRng, CountingRng, FnRng},
+	DynRng, Pcg64, WyRand, Xoroshiro128, RNG,
+};
+
+fn assert_output_size<R: RNG>(mut rng: R) {
+	for _ in 0..4 {
+		assert_eq!(rng.rand().as_ref().len(), R::OUTPUT_SIZE);
+	}
+}
+
+#[test]
+fn output_sizes_match_outputs() {
+	assert_eq!(WyRand::OUTPUT_SIZE, 8);
+	assert_output_size(WyRand::new_seed(42));
+	assert_output_size(Pcg64::new_seed(42));
+	assert_output_size(Xoroshiro128::new_seed(42));
+	assert_output_size(BufferedRng::new(WyRand::new_seed(42)));
+	assert_output_size(CountingRng::new(Pcg64::new_seed(42)));
+	assert_output_size(&mut WyRand::new_seed(42));
+	assert_output_size(FnRng::new(|| 42));
+	assert_output_size(CombinedRng::new(WyRand::new_seed(1), Pcg64::new_seed(2)));
+	assert_output_size(&mut Xoroshiro128::new_seed(42) as &mut dyn DynRng);
+	assert_output_size(Pcg64::new_seed(42).into_boxed());
+	#[cfg(feature = "std")]
+	assert_output_size(nanorand::adapter::ReseedingRng::new(
+		WyRand::new_seed(42),
+		16,
+	));
+	#[cfg(feature = "chacha")]
+	{
+		assert_eq!(nanorand::ChaCha::OUTPUT_SIZE, 64);
+		assert_output_size(nanorand::ChaCha::new(8));
+		assert_output_size(CombinedRng::new(
+			nanorand::ChaCha::new(8),
+			WyRand::new_seed(2),
+		));
+	}
+}
+
+#[test]
+fn output_size_defaults_to_the_output_array() {
+	struct Quad(u32);
+
+	impl RNG for Quad {
+		type Output = [u8; 4];
+
+		fn rand(&mut self) -> Self::Output {
+			self.0 = self.0.wrapping_mul(0x2c9277b5).wrapping_add(1);
+			self.0.to_le_bytes()
+		}
+	}
+
+	assert_eq!(Quad::OUTPUT_SIZE, 4);
+	assert_output_size(Quad(42));
+}
+
+#[test]
+fn crypto_flags() {
+	assert_eq!((WyRand::OUTPUT_SIZE, WyRand::IS_CRYPTO), (8, false));
+	let insecure = [
+		Pcg64::IS_CRYPTO,
+		Xoroshiro128::IS_CRYPTO,
+		<&mut WyRand>::IS_CRYPTO,
+		BufferedRng::<WyRand>::IS_CRYPTO,
+	];
+	assert_eq!(insecure, [false; 4]);
+	#[cfg(feature = "chacha")]
+	{
+		use nanorand::{ChaCha, CryptoRNG};
+
+		fn secure_bytes<R: CryptoRNG>(rng: &mut R) -> [u8; 16] {
+			assert!(R::IS_CRYPTO);
+			rng.generate_bytes()
+		}
+
+		assert_eq!((ChaCha::OUTPUT_SIZE, ChaCha::IS_CRYPTO), (64, true));
+		let mut rng = ChaCha::new(20);
+		secure_bytes(&mut rng);
+		secure_bytes(&mut &mut rng);
+		secure_bytes(&mut CountingRng::new(ChaCha::new(8)));
+		secure_bytes(&mut BufferedRng::new(ChaCha::new(8)));
+	}
+}
+
+#[test]
+#[cfg(not(feature = "native-endian"))]
+fn wyrand_stream_unchanged() {
+	// Recorded before `OUTPUT_SIZE` was added, so the stream mustn't change.
+	let mut rng = WyRand::new_seed(7);
+	let words: [u64; 3] = [rng.generate(), rng.generate(), rng.generate()];
+	assert_eq!(
+		words,
+		[0xe21b87e1e24a18c1, 0xdaf7cca9fc31c738, 0x62c3f9e67112e858]
+	);
+	let bytes: [u8; 11] = rng.generate_bytes();
+	assert_eq!(bytes, [215, 118, 148, 87, 128, 58, 236, 62, 16, 242, 20]);
+	let ranged: [u32; 4] = [
+		rng.generate_range(0..1000),
+		rng.generate_range(0..1000),
+		rng.generate_range(5..=9),
+		rng.generate_range(0..u32::MAX),
+	];
+	assert_eq!(ranged, [658, 169, 5, 3625027382]);
+	assert_eq!(rng.generate_range(100_u16..200), 150);
+}
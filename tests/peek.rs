@@ -0,0 +1,34 @@
+use nanorand::{Pcg64, PeekableRNG, WyRand, Xoroshiro128, RNG};
+
+fn check_peek<R: PeekableRNG + Clone>(mut rng: R)
+where
+	R::Output: PartialEq + core::fmt::Debug,
+{
+	let mut untouched = rng.clone();
+	for _ in 0..1000 {
+		let peeked = rng.peek();
+		assert_eq!(rng.peek(), peeked);
+		assert_eq!(rng.rand(), peeked);
+		// Peeking mustn't change the stream.
+		assert_eq!(untouched.rand(), peeked);
+	}
+}
+
+#[test]
+fn peek_matches_rand() {
+	check_peek(WyRand::new_seed(42));
+	check_peek(Pcg64::new_seed(42));
+	check_peek(Xoroshiro128::new_seed(42));
+}
+
+fn check_by_ref<R: PeekableRNG>(rng: R) -> R::Output {
+	rng.peek()
+}
+
+#[test]
+fn peek_through_mut_ref() {
+	let mut rng = WyRand::new_seed(42);
+	let peeked = check_by_ref(&mut rng);
+	assert_eq!(rng, WyRand::new_seed(42));
+	assert_eq!(rng.rand(), peeked);
+}
@@ -0,0 +1,52 @@
+#![cfg(target_os = "linux")]
+use nanorand::{default_rng, tls_rng, WyRand, RNG};
+use std::{
+	convert::TryInto,
+	io::{Read, Write},
+	os::unix::net::UnixStream,
+};
+
+extern "C" {
+	fn fork() -> i32;
+	fn waitpid(pid: i32, status: *mut i32, options: i32) -> i32;
+	fn _exit(status: i32) -> !;
+}
+
+fn outputs() -> [u64; 4] {
+	[
+		WyRand::new().generate(),
+		WyRand::default().generate(),
+		default_rng().generate(),
+		tls_rng().generate(),
+	]
+}
+
+#[test]
+fn new_generators_differ_after_fork() {
+	// Seed the process-wide master generator before forking, so the child starts out with the same one.
+	WyRand::new();
+	let (mut parent_end, mut child_end) = UnixStream::pair().unwrap();
+
+	// The thread-local generator is created after the fork, in both processes, as this test runs on its own thread.
+	let pid = unsafe { fork() };
+	assert!(pid >= 0);
+	if pid == 0 {
+		let bytes: Vec<u8> = outputs().iter().flat_map(|n| n.to_le_bytes()).collect();
+		let sent = child_end.write_all(&bytes).is_ok();
+		unsafe { _exit(if sent { 0 } else { 1 }) };
+	}
+	let ours = outputs();
+	let mut theirs = [0_u8; 32];
+	parent_end.read_exact(&mut theirs).unwrap();
+	let mut status = 0;
+	assert_eq!(unsafe { waitpid(pid, &mut status, 0) }, pid);
+	assert_eq!(
+		(status >> 8) & 0xff,
+		0,
+		"the child couldn't send its outputs"
+	);
+
+	for (ours, theirs) in ours.iter().zip(theirs.chunks_exact(8)) {
+		assert_ne!(*ours, u64::from_le_bytes(theirs.try_into().unwrap()));
+	}
+}
@@ -0,0 +1,668 @@
+use core::{ops::Bound, time::Duration};
+use nanorand::{adapter::FnRng, RandomGen, RandomRange, WyRand, RNG};
+
+macro_rules! range_edge_tests {
+	($($ty:ty),*) => {
+		$(
+			let mut rng = WyRand::new_seed(42);
+			for n in [0 as $ty, 1, 5, 100, <$ty>::MAX] {
+				assert_eq!(<$ty>::random_range(&mut rng, n, n), n);
+			}
+			// Inverted ranges panic in debug builds, and are swapped in release builds.
+			if !cfg!(debug_assertions) {
+				for _ in 0..1000 {
+					let x = <$ty>::random_range(&mut rng, 10, 3);
+					assert!((3..10).contains(&x), "{} is outside of 3..10", x);
+					let x = <$ty>::random_range(&mut rng, <$ty>::MAX, 0);
+					assert!(x < <$ty>::MAX);
+				}
+			}
+		)*
+	};
+}
+
+#[test]
+fn empty_and_inverted_ranges() {
+	range_edge_tests!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+}
+
+#[test]
+fn ranges_stay_in_bounds() {
+	let mut rng = WyRand::new_seed(42);
+	for _ in 0..10000 {
+		let x = rng.generate_range(100_u8..200);
+		assert!((100..200).contains(&x));
+		let x = rng.generate_range(1_u32..7);
+		assert!((1..7).contains(&x));
+		let x = rng.generate_range(u64::MAX - 10..u64::MAX);
+		assert!((u64::MAX - 10..u64::MAX).contains(&x));
+	}
+}
+
+macro_rules! near_max_tests {
+	($($ty:ty),*) => {
+		$(
+			let mut rng = WyRand::new_seed(42);
+			let mut seen = [false; 6];
+			for _ in 0..1000 {
+				// Every value of the range gets hit, without overflowing past the maximum.
+				let x = rng.generate_range((<$ty>::MAX - 5)..<$ty>::MAX);
+				assert!(((<$ty>::MAX - 5)..<$ty>::MAX).contains(&x), "{} is out of bounds", x);
+				seen[(x - (<$ty>::MAX - 5)) as usize] = true;
+				let x = rng.generate_range((<$ty>::MAX - 5)..=<$ty>::MAX);
+				assert!(x >= <$ty>::MAX - 5);
+				seen[(x - (<$ty>::MAX - 5)) as usize] = true;
+				// Ranges wider than half the type are the most likely to overflow if the span is added back carelessly.
+				let x = rng.generate_range((<$ty>::MAX / 3)..<$ty>::MAX);
+				assert!(((<$ty>::MAX / 3)..<$ty>::MAX).contains(&x), "{} is out of bounds", x);
+				let x = rng.generate_range(1..<$ty>::MAX);
+				assert!((1..<$ty>::MAX).contains(&x), "{} is out of bounds", x);
+			}
+			assert!(seen.iter().all(|&s| s), "{:?}", seen);
+		)*
+	};
+}
+
+#[test]
+fn ranges_near_max() {
+	near_max_tests!(u8, u16, u32, u64, u128, usize);
+}
+
+#[test]
+fn char_ranges() {
+	let mut rng = WyRand::new_seed(42);
+	assert_eq!(rng.generate_range('q'..'q'), 'q');
+	for _ in 0..1000 {
+		let c = char::random_range(&mut rng, 'c', 'x');
+		assert!(('c'..'x').contains(&c));
+	}
+	if !cfg!(debug_assertions) {
+		let c = char::random_range(&mut rng, 'x', 'c');
+		assert!(('c'..'x').contains(&c));
+	}
+}
+
+#[test]
+fn char_ascii_subrange() {
+	let mut rng = WyRand::new_seed(42);
+	let mut seen = [false; 26];
+	for _ in 0..10000 {
+		let c = char::random_range_inclusive(&mut rng, 'a', 'z');
+		assert!(c.is_ascii_lowercase());
+		seen[(c as u8 - b'a') as usize] = true;
+	}
+	assert!(seen.iter().all(|&s| s), "{:?}", seen);
+}
+
+#[test]
+fn char_range_across_surrogates() {
+	let mut rng = WyRand::new_seed(42);
+	// Two chars below the surrogates, and two above them.
+	let (below, above) = ('\u{D7FE}', '\u{E001}');
+	let mut counts = [0u32; 4];
+	for _ in 0..40000 {
+		let c = char::random_range_inclusive(&mut rng, below, above);
+		match c {
+			'\u{D7FE}' => counts[0] += 1,
+			'\u{D7FF}' => counts[1] += 1,
+			'\u{E000}' => counts[2] += 1,
+			'\u{E001}' => counts[3] += 1,
+			_ => panic!("{:?} is outside of the range", c),
+		}
+	}
+	assert!(
+		counts.iter().all(|&c| c.abs_diff(10000) < 500),
+		"{:?}",
+		counts
+	);
+	for _ in 0..1000 {
+		let c = char::random_range(&mut rng, '\u{D7FF}', '\u{E000}');
+		assert_eq!(c, '\u{D7FF}');
+		let c = char::random_range_except(&mut rng, below, above, '\u{E000}');
+		assert!(c != '\u{E000}' && (below..=above).contains(&c));
+	}
+	let c = char::random_range_inclusive(&mut rng, '\u{10FFFE}', char::MAX);
+	assert!(c >= '\u{10FFFE}');
+}
+
+#[test]
+fn i32_ranges() {
+	let mut rng = WyRand::new_seed(42);
+	let ranges = [
+		(-10, 10),
+		(0, 1),
+		(-1, 0),
+		(i32::MIN, i32::MAX),
+		(i32::MIN, 0),
+		(0, i32::MAX),
+		(1_000_000, 1_000_100),
+		(-100_000, 70_000),
+	];
+	for &(lower, upper) in ranges.iter() {
+		let mut min = i32::MAX;
+		let mut max = i32::MIN;
+		for _ in 0..10000 {
+			let x = i32::random_range(&mut rng, lower, upper);
+			assert!(
+				(lower..upper).contains(&x),
+				"{} is outside of {}..{}",
+				x,
+				lower,
+				upper
+			);
+			min = min.min(x);
+			max = max.max(x);
+		}
+		if (upper as i64 - lower as i64) <= 100 {
+			assert_eq!(min, lower);
+			assert_eq!(max, upper - 1);
+		} else {
+			let quarter = (upper as i64 - lower as i64) / 4;
+			assert!((min as i64) < lower as i64 + quarter);
+			assert!((max as i64) > upper as i64 - quarter);
+		}
+	}
+}
+
+#[test]
+fn signed_edge_ranges() {
+	let mut rng = WyRand::new_seed(42);
+	assert_eq!(i8::random_range(&mut rng, -5, -5), -5);
+	assert_eq!(i64::random_range(&mut rng, i64::MIN, i64::MIN), i64::MIN);
+	for _ in 0..1000 {
+		let x = i8::random_range(&mut rng, -5, 5);
+		assert!((-5..5).contains(&x));
+		let x = i16::random_range(&mut rng, i16::MIN, i16::MAX);
+		assert!(x < i16::MAX);
+		let x = i64::random_range(&mut rng, i64::MIN, i64::MAX);
+		assert!(x < i64::MAX);
+		if !cfg!(debug_assertions) {
+			let x = i8::random_range(&mut rng, 5, -5);
+			assert!((-5..5).contains(&x));
+			let x = i64::random_range(&mut rng, i64::MAX, i64::MIN);
+			assert!(x < i64::MAX);
+		}
+		let x = isize::random_range(&mut rng, -3, 3);
+		assert!((-3..3).contains(&x));
+	}
+}
+
+#[test]
+fn u128_ranges() {
+	let mut rng = WyRand::new_seed(42);
+	let above_u64 = u64::MAX as u128 + 1;
+	let mut saw_high = false;
+	for _ in 0..10000 {
+		let x = u128::random_range(&mut rng, above_u64, above_u64 * 4);
+		assert!((above_u64..above_u64 * 4).contains(&x));
+		saw_high |= x >= above_u64 * 3;
+		let x = u128::random_range(&mut rng, u128::MAX - 5, u128::MAX);
+		assert!((u128::MAX - 5..u128::MAX).contains(&x));
+		let x = i128::random_range(&mut rng, i128::MIN, i128::MAX);
+		assert!(x < i128::MAX);
+		let x = i128::random_range(&mut rng, -7, 7);
+		assert!((-7..7).contains(&x));
+	}
+	assert!(saw_high);
+}
+
+#[test]
+fn u128_ranges_are_unbiased() {
+	let mut rng = WyRand::new_seed(42);
+	// A modulo or truncating reduction puts two thirds of these below the midpoint.
+	let range = (u128::MAX / 3) * 2;
+	let below_half = (0..10000)
+		.filter(|_| u128::random_range(&mut rng, 0, range) < range / 2)
+		.count();
+	assert!((4700..5300).contains(&below_half), "{} / 10000", below_half);
+
+	let mut counts = [0u32; 6];
+	for _ in 0..60000 {
+		counts[u128::random_range(&mut rng, 0, 6) as usize] += 1;
+	}
+	let chi_square: f64 = counts
+		.iter()
+		.map(|&c| (c as f64 - 10000.0).powi(2) / 10000.0)
+		.sum();
+	// 99.9th percentile for 5 degrees of freedom.
+	assert!(
+		chi_square < 20.52,
+		"chi-square {} for {:?}",
+		chi_square,
+		counts
+	);
+}
+
+#[test]
+fn u128_ranges_use_a_full_width_product() {
+	// Each u128 is built from two u64 outputs, low half first.
+	let draws = [
+		// 2^127 * 6 is exactly 3 * 2^128, so the low half is 0, which is rejected as biased.
+		0_u64,
+		1 << 63,
+		// 0.75 * 2^128 * 6 is 4.5 * 2^128, so this lands on 4, and is kept.
+		0,
+		3 << 62,
+	];
+	let mut draws = draws.iter().copied();
+	let mut rng = FnRng::new(|| draws.next().expect("Drew more than expected"));
+	assert_eq!(u128::random_range(&mut rng, 0, 6), 4);
+	assert_eq!(draws.next(), None);
+
+	// Only the high half of a 256-bit product gets this right; truncating to 128 bits would give 0.
+	let mut draws = [u64::MAX, u64::MAX].iter().copied();
+	let mut rng = FnRng::new(|| draws.next().unwrap());
+	let range = u128::MAX - 1;
+	assert_eq!(u128::random_range(&mut rng, 0, range), range - 1);
+}
+
+#[test]
+fn i128_wide_ranges_are_unbiased() {
+	let mut rng = WyRand::new_seed(42);
+	// A range of 1.5 * 2^127 across 0, where a modulo reduction would make the bottom third twice as likely.
+	let lower = i128::MIN / 2;
+	let upper = i128::MAX;
+	let third = upper.wrapping_sub(lower) as u128 / 3;
+	let mut counts = [0u32; 3];
+	for _ in 0..30000 {
+		let x = i128::random_range(&mut rng, lower, upper);
+		assert!((lower..upper).contains(&x));
+		let bucket = (x.wrapping_sub(lower) as u128 / third) as usize;
+		counts[bucket.min(2)] += 1;
+	}
+	assert!(
+		counts.iter().all(|&c| (9500..10500).contains(&c)),
+		"{:?}",
+		counts
+	);
+
+	// The same for an inclusive range ending at the maximum, which takes a different path.
+	let mut above_half = 0;
+	for _ in 0..10000 {
+		let x = u128::random_range_inclusive(&mut rng, u128::MAX / 4, u128::MAX);
+		above_half += (x > u128::MAX / 8 * 5) as u32;
+	}
+	assert!((4700..5300).contains(&above_half), "{} / 10000", above_half);
+}
+
+macro_rules! inclusive_max_tests {
+	($($ty:ty),*) => {
+		$(
+			let mut rng = WyRand::new_seed(42);
+			let mut saw_max = false;
+			for _ in 0..1000 {
+				let x = <$ty>::random_range_inclusive(&mut rng, <$ty>::MAX - 3, <$ty>::MAX);
+				assert!(x >= <$ty>::MAX - 3);
+				saw_max |= x == <$ty>::MAX;
+				if !cfg!(debug_assertions) {
+					let x = <$ty>::random_range_inclusive(&mut rng, <$ty>::MAX, <$ty>::MAX - 3);
+					assert!(x >= <$ty>::MAX - 3);
+				}
+				<$ty>::random_range_inclusive(&mut rng, <$ty>::MIN, <$ty>::MAX);
+			}
+			assert!(saw_max);
+			assert_eq!(<$ty>::random_range_inclusive(&mut rng, <$ty>::MAX, <$ty>::MAX), <$ty>::MAX);
+		)*
+	};
+}
+
+#[test]
+fn inclusive_ranges_reach_max() {
+	inclusive_max_tests!(u8, i8, u64, i128);
+}
+
+#[test]
+fn inclusive_ranges() {
+	let mut rng = WyRand::new_seed(42);
+	let mut counts = [0u32; 6];
+	for _ in 0..6000 {
+		let roll = u8::random_range_inclusive(&mut rng, 1, 6);
+		assert!((1..=6).contains(&roll));
+		counts[roll as usize - 1] += 1;
+	}
+	assert!(counts.iter().all(|&c| c > 800), "{:?}", counts);
+
+	let mut saw_min = false;
+	for _ in 0..1000 {
+		let x = i8::random_range_inclusive(&mut rng, i8::MIN, i8::MIN + 2);
+		assert!((i8::MIN..=i8::MIN + 2).contains(&x));
+		saw_min |= x == i8::MIN;
+		let c = char::random_range_inclusive(&mut rng, 'a', 'c');
+		assert!(('a'..='c').contains(&c));
+	}
+	assert!(saw_min);
+}
+
+/// A type with only five values, which only implements the steps between them, and gets the default inclusive ranges.  
+/// It leaves out `MIN` and `MAX`, so ranges of it need both bounds.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+struct Level(u8);
+
+impl<R: RNG> RandomGen<R> for Level {
+	fn random(r: &mut R) -> Self {
+		Level(u8::random_range(r, 0, 5))
+	}
+}
+
+impl<R: RNG> RandomRange<R> for Level {
+	fn random_range(r: &mut R, lower: Self, upper: Self) -> Self {
+		Level(u8::random_range(r, lower.0, upper.0))
+	}
+
+	fn successor(&self) -> Option<Self> {
+		Some(Level(self.0 + 1)).filter(|level| level.0 <= 4)
+	}
+
+	fn predecessor(&self) -> Option<Self> {
+		self.0.checked_sub(1).map(Level)
+	}
+}
+
+#[test]
+fn default_inclusive_ranges() {
+	let mut rng = WyRand::new_seed(42);
+	for (lower, upper) in [(0, 2), (2, 4), (0, 4), (3, 3), (4, 4)] {
+		let mut seen = [false; 5];
+		for _ in 0..1000 {
+			let level = Level::random_range_inclusive(&mut rng, Level(lower), Level(upper));
+			assert!((lower..=upper).contains(&level.0), "{:?}", level);
+			seen[level.0 as usize] = true;
+		}
+		assert!(seen[lower as usize..=upper as usize]
+			.iter()
+			.all(|&seen| seen));
+	}
+	assert_eq!(rng.generate_range(Level(4)..=Level(4)), Level(4));
+}
+
+#[test]
+#[should_panic(expected = "Ranges without an end bound need RandomRange::MAX!")]
+fn unbounded_range_without_max() {
+	WyRand::new_seed(42).generate_range(Level(2)..);
+}
+
+#[test]
+fn range_syntax() {
+	let mut rng = WyRand::new_seed(42);
+	let mut saw_six = false;
+	for _ in 0..1000 {
+		let roll: u8 = rng.generate_range(1..=6);
+		assert!((1..=6).contains(&roll));
+		saw_six |= roll == 6;
+		let idx = rng.generate_range(0..10_usize);
+		assert!(idx < 10);
+		assert!(rng.generate_range(250_u8..) >= 250);
+		assert!(rng.generate_range(..=-120_i8) <= -120);
+		assert!(rng.generate_range(..3_u16) < 3);
+		rng.generate_range::<u64>(..);
+		let c = rng.generate_range('a'..='e');
+		assert!(('a'..='e').contains(&c));
+	}
+	assert!(saw_six);
+	assert_eq!(rng.generate_range(7_u32..7), 7);
+	assert_eq!(rng.generate_range(u128::MAX..), u128::MAX);
+}
+
+#[test]
+#[should_panic]
+fn excluded_start_bound_panics() {
+	let mut rng = WyRand::new_seed(42);
+	rng.generate_range::<u32>((Bound::Excluded(1), Bound::Excluded(5)));
+}
+
+#[test]
+fn range_except() {
+	let mut rng = WyRand::new_seed(42);
+	let mut counts = [0u32; 10];
+	for _ in 0..90000 {
+		let x = usize::random_range_except(&mut rng, 0, 10, 4);
+		counts[x] += 1;
+	}
+	assert_eq!(counts[4], 0);
+	let chi_square: f64 = counts
+		.iter()
+		.enumerate()
+		.filter(|&(i, _)| i != 4)
+		.map(|(_, &c)| (c as f64 - 10000.0).powi(2) / 10000.0)
+		.sum();
+	// 99.9th percentile for 8 degrees of freedom.
+	assert!(
+		chi_square < 26.12,
+		"chi-square {} for {:?}",
+		chi_square,
+		counts
+	);
+
+	for _ in 0..1000 {
+		assert_ne!(i32::random_range_except(&mut rng, -2, 3, -2), -2);
+		assert_ne!(
+			u8::random_range_except(&mut rng, 0, u8::MAX, u8::MAX - 1),
+			u8::MAX - 1
+		);
+		assert_ne!(u128::random_range_except(&mut rng, 5, 7, 6), 6);
+		assert_ne!(char::random_range_except(&mut rng, 'a', 'c', 'b'), 'b');
+		let x = u64::random_range_except(&mut rng, 3, 6, 100);
+		assert!((3..6).contains(&x));
+	}
+	assert_eq!(u16::random_range_except(&mut rng, 8, 10, 8), 9);
+	assert_eq!(i8::random_range_except(&mut rng, -1, 1, 0), -1);
+}
+
+#[test]
+#[should_panic]
+fn range_except_nothing_left() {
+	let mut rng = WyRand::new_seed(42);
+	u32::random_range_except(&mut rng, 3, 4, 3);
+}
+
+#[test]
+#[should_panic(expected = "nothing but the excluded value")]
+fn range_except_empty_range_of_the_excluded_value() {
+	let mut rng = WyRand::new_seed(42);
+	u32::random_range_except(&mut rng, 3, 3, 3);
+}
+
+#[test]
+#[should_panic(expected = "nothing but the excluded value")]
+fn range_except_empty_char_range_of_the_excluded_value() {
+	let mut rng = WyRand::new_seed(42);
+	char::random_range_except(&mut rng, 'a', 'a', 'a');
+}
+
+#[test]
+#[should_panic(expected = "nothing but the excluded value")]
+fn range_except_empty_float_range_of_the_excluded_value() {
+	let mut rng = WyRand::new_seed(42);
+	f64::random_range_except(&mut rng, 1.0, 1.0, 1.0);
+}
+
+#[test]
+#[should_panic(expected = "nothing but the excluded value")]
+fn range_except_single_float() {
+	let mut rng = WyRand::new_seed(42);
+	let after = f64::from_bits(1.0_f64.to_bits() + 1);
+	f64::random_range_except(&mut rng, 1.0, after, 1.0);
+}
+
+#[test]
+#[should_panic(expected = "nothing but the excluded value")]
+fn range_except_single_duration() {
+	let mut rng = WyRand::new_seed(42);
+	Duration::random_range_except(
+		&mut rng,
+		Duration::from_nanos(1),
+		Duration::from_nanos(2),
+		Duration::from_nanos(1),
+	);
+}
+
+#[test]
+#[should_panic(expected = "nothing but the excluded value")]
+fn range_except_single_value_with_successor() {
+	let mut rng = WyRand::new_seed(42);
+	Level::random_range_except(&mut rng, Level(1), Level(2), Level(1));
+}
+
+#[test]
+fn range_except_steps_past() {
+	let mut rng = WyRand::new_seed(42);
+	for _ in 0..1000 {
+		assert_eq!(
+			Duration::random_range_except(
+				&mut rng,
+				Duration::from_nanos(1),
+				Duration::from_nanos(3),
+				Duration::from_nanos(1),
+			),
+			Duration::from_nanos(2)
+		);
+		let after = f64::from_bits(1.0_f64.to_bits() + 1);
+		assert_eq!(f64::random_range_except(&mut rng, 1.0, after, 2.0), 1.0);
+		assert_eq!(
+			Level::random_range_except(&mut rng, Level(1), Level(3), Level(1)),
+			Level(2)
+		);
+	}
+}
+
+#[test]
+fn range_except_empty_range() {
+	let mut rng = WyRand::new_seed(42);
+	// An empty range still gives its lower bound, as long as that isn't excluded.
+	assert_eq!(u32::random_range_except(&mut rng, 3, 3, 4), 3);
+	assert_eq!(f64::random_range_except(&mut rng, 1.0, 1.0, 2.0), 1.0);
+}
+
+#[test]
+fn constant_time_ranges() {
+	use nanorand::RandomRangeCt;
+
+	let mut rng = WyRand::new_seed(42);
+	let mut counts = [0u32; 6];
+	for _ in 0..60000 {
+		let x = u32::random_range_ct(&mut rng, 10, 16);
+		assert!((10..16).contains(&x));
+		counts[x as usize - 10] += 1;
+		let x = u64::random_range_ct(&mut rng, u64::MAX - 6, u64::MAX);
+		assert!((u64::MAX - 6..u64::MAX).contains(&x));
+		let x = u8::random_range_ct(&mut rng, 3, 200);
+		assert!((3..200).contains(&x));
+	}
+	let chi_square: f64 = counts
+		.iter()
+		.map(|&c| (c as f64 - 10000.0).powi(2) / 10000.0)
+		.sum();
+	assert!(
+		chi_square < 20.52,
+		"chi-square {} for {:?}",
+		chi_square,
+		counts
+	);
+
+	let mut counts = [0u32; 3];
+	let third = u64::MAX / 3;
+	for _ in 0..30000 {
+		counts[(u64::random_range_ct(&mut rng, 0, third * 3) / third) as usize] += 1;
+	}
+	assert!(
+		counts.iter().all(|&c| (9500..10500).contains(&c)),
+		"{:?}",
+		counts
+	);
+	assert_eq!(u16::random_range_ct(&mut rng, 9, 9), 9);
+}
+
+/// Lemire's method for [`u64`], multiplying within [`u128`] the way it always used to.
+fn reference_u64_range(rng: &mut WyRand, range: u64) -> u64 {
+	let t = range.wrapping_neg() % range;
+	loop {
+		let m = rng.generate::<u64>() as u128 * range as u128;
+		if m as u64 >= t {
+			break (m >> 64) as u64;
+		}
+	}
+}
+
+#[test]
+fn u64_small_ranges_unchanged() {
+	for &range in &[
+		3_u64,
+		10,
+		1000,
+		12345,
+		u32::MAX as u64 - 1,
+		u32::MAX as u64,
+		u32::MAX as u64 + 3,
+	] {
+		let mut rng = WyRand::new_seed(range);
+		let mut expected = WyRand::new_seed(range);
+		for _ in 0..10000 {
+			assert_eq!(
+				rng.generate_range(0..range),
+				reference_u64_range(&mut expected, range)
+			);
+		}
+	}
+
+	let mut rng = WyRand::new_seed(42);
+	let mut counts = [0_u32; 7];
+	for _ in 0..70000 {
+		counts[rng.generate_range(0_u64..7) as usize] += 1;
+	}
+	for &count in counts.iter() {
+		assert!((9400..10600).contains(&count), "{:?} isn't uniform", counts);
+	}
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "must not be above its upper bound")]
+fn inverted_range_debug_panics() {
+	u32::random_range(&mut WyRand::new_seed(42), 10, 3);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "must not be above its upper bound")]
+fn inverted_signed_range_debug_panics() {
+	i64::random_range(&mut WyRand::new_seed(42), 0, -1);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "must not be above its upper bound")]
+fn inverted_inclusive_range_debug_panics() {
+	#[allow(clippy::reversed_empty_ranges)]
+	WyRand::new_seed(42).generate_range(6_u8..=1);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "must not be above its upper bound")]
+fn inverted_u128_range_debug_panics() {
+	u128::random_range_inclusive(&mut WyRand::new_seed(42), u128::MAX, 0);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "must not be above its upper bound")]
+fn inverted_char_range_debug_panics() {
+	char::random_range(&mut WyRand::new_seed(42), 'z', 'a');
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "must not be above its upper bound")]
+fn inverted_ct_range_debug_panics() {
+	use nanorand::RandomRangeCt;
+
+	u8::random_range_ct(&mut WyRand::new_seed(42), 200, 3);
+}
+
+#[test]
+fn empty_ranges_dont_panic() {
+	let mut rng = WyRand::new_seed(42);
+	assert_eq!(rng.generate_range(5_u16..5), 5);
+	assert_eq!(rng.generate_range(-3_i32..-3), -3);
+	assert_eq!(rng.generate_range(5_u16..=5), 5);
+}
@@ -0,0 +1,60 @@
+use nanorand::{adapter::ReadRng, RandError, WyRand, RNG};
+use std::io::{self, Read};
+
+#[test]
+fn read_fills_the_buffer() {
+	let mut reader = ReadRng::new(WyRand::new_seed(42));
+	let mut buf = [0u8; 100];
+	assert_eq!(reader.read(&mut buf).unwrap(), 100);
+	assert!(buf.iter().any(|&byte| byte != 0));
+	// It's the same as filling the bytes directly.
+	let mut expected = [0u8; 100];
+	WyRand::new_seed(42).fill_bytes(&mut expected);
+	assert_eq!(buf[..], expected[..]);
+}
+
+#[test]
+fn copy_a_fixed_amount() {
+	let mut out = Vec::new();
+	let reader = ReadRng::new(WyRand::new_seed(42));
+	let copied = io::copy(&mut reader.take(10000), &mut out).unwrap();
+	assert_eq!(copied, 10000);
+	assert_eq!(out.len(), 10000);
+	let zeros = out.iter().filter(|&&byte| byte == 0).count();
+	assert!(zeros < 100, "{} zero bytes", zeros);
+
+	let mut reader = ReadRng::new(WyRand::new_seed(42));
+	let mut exact = vec![0u8; 37];
+	reader.read_exact(&mut exact).unwrap();
+	assert_eq!(exact[..], out[..37]);
+	reader.into_inner().generate::<u64>();
+}
+
+struct Failing;
+
+impl RNG for Failing {
+	type Output = [u8; 8];
+
+	fn rand(&mut self) -> Self::Output {
+		panic!("Failing can only be used through try_rand")
+	}
+
+	fn try_rand(&mut self) -> Result<Self::Output, RandError> {
+		Err(RandError::new("Failing"))
+	}
+
+	fn try_fill_bytes(&mut self, _dest: &mut [u8]) -> Result<(), RandError> {
+		Err(RandError::new("Failing"))
+	}
+}
+
+#[test]
+fn read_reports_generator_failure() {
+	let mut reader = ReadRng::new(Failing);
+	let err = reader.read(&mut [0u8; 16]).unwrap_err();
+	assert_eq!(err.kind(), io::ErrorKind::Other);
+	assert_eq!(
+		err.into_inner().unwrap().downcast::<RandError>().unwrap(),
+		Box::new(RandError::new("Failing"))
+	);
+}
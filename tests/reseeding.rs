@@ -0,0 +1,111 @@
+#![cfg(feature = "std")]
+use nanorand::{adapter::ReseedingRng, WyRand, RNG};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn reseeds_after_threshold() {
+	let mut pure = WyRand::new_seed(42);
+	let mut rng = ReseedingRng::new(WyRand::new_seed(42), 16);
+
+	// The first two outputs come before the threshold, so they're exactly the wrapped RNG's.
+	assert_eq!(rng.generate::<u64>(), pure.generate::<u64>());
+	assert_eq!(rng.generate::<u64>(), pure.generate::<u64>());
+
+	// After that, the state comes from system entropy, so the sequences diverge.
+	let reseeded: Vec<u64> = (0..4).map(|_| rng.generate()).collect();
+	let expected: Vec<u64> = (0..4).map(|_| pure.generate()).collect();
+	assert_ne!(reseeded, expected);
+}
+
+#[test]
+fn fill_bytes_reseeds_mid_buffer() {
+	let mut pure = WyRand::new_seed(42);
+	let mut rng = ReseedingRng::new(WyRand::new_seed(42), 20);
+
+	let mut bytes = [0u8; 64];
+	rng.fill_bytes(&mut bytes);
+	let mut expected = [0u8; 64];
+	pure.fill_bytes(&mut expected);
+
+	assert_eq!(bytes[..20], expected[..20]);
+	assert_ne!(bytes[20..], expected[20..]);
+}
+
+#[test]
+fn manual_reseed_is_forwarded() {
+	let mut rng = ReseedingRng::new(WyRand::new(), 1024);
+	rng.reseed(7u64.to_le_bytes());
+	let mut expected = WyRand::new_seed(7);
+	for _ in 0..100 {
+		assert_eq!(rng.generate::<u64>(), expected.generate::<u64>());
+	}
+}
+
+#[test]
+#[should_panic]
+fn zero_threshold_panics() {
+	ReseedingRng::new(WyRand::new(), 0);
+}
+
+static STUB_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+/// Counts its calls, and returns a different seed each time.
+fn counting_entropy(amt: usize) -> Vec<u8> {
+	let call = STUB_CALLS.fetch_add(1, Ordering::SeqCst) as u8 + 1;
+	vec![call; amt]
+}
+
+#[test]
+fn reseeds_at_the_threshold() {
+	let mut rng = ReseedingRng::with_entropy_source(WyRand::new_seed(42), 24, counting_entropy);
+	let mut pure = WyRand::new_seed(42);
+	for _ in 0..3 {
+		assert_eq!(rng.generate::<u64>(), pure.generate::<u64>());
+	}
+	assert_eq!(STUB_CALLS.load(Ordering::SeqCst), 0);
+
+	// The 4th output crosses the threshold, so it comes from the first stub seed.
+	let mut reseeded = WyRand::new_seed(u64::from_le_bytes([1; 8]));
+	for _ in 0..3 {
+		assert_eq!(rng.generate::<u64>(), reseeded.generate::<u64>());
+	}
+	assert_eq!(STUB_CALLS.load(Ordering::SeqCst), 1);
+
+	// Reseeding early restarts the count.
+	rng.reseed_now();
+	let mut reseeded = WyRand::new_seed(u64::from_le_bytes([2; 8]));
+	for _ in 0..3 {
+		assert_eq!(rng.generate::<u64>(), reseeded.generate::<u64>());
+	}
+	assert_eq!(STUB_CALLS.load(Ordering::SeqCst), 2);
+	rng.generate::<u64>();
+	assert_eq!(STUB_CALLS.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn reseeds_after_fork() {
+	extern "C" {
+		fn fork() -> i32;
+		fn waitpid(pid: i32, status: *mut i32, options: i32) -> i32;
+		fn _exit(status: i32) -> !;
+	}
+
+	let mut rng = ReseedingRng::new(WyRand::new_seed(42), usize::MAX);
+	let mut pure = WyRand::new_seed(42);
+	assert_eq!(rng.generate::<u64>(), pure.generate::<u64>());
+
+	let pid = unsafe { fork() };
+	assert!(pid >= 0);
+	if pid == 0 {
+		// The child's RNG should have been reseeded, and differ from the parent's sequence.
+		let diverged = rng.generate::<u64>() != pure.generate::<u64>();
+		unsafe { _exit(if diverged { 0 } else { 1 }) };
+	}
+	let mut status = 0;
+	assert_eq!(unsafe { waitpid(pid, &mut status, 0) }, pid);
+	assert_eq!((status >> 8) & 0xff, 0, "the child didn't reseed");
+
+	// The parent isn't affected.
+	assert_eq!(rng.generate::<u64>(), pure.generate::<u64>());
+}
@@ -0,0 +1,305 @@
+use nanorand::{Pcg64, SeedableRNG, WyRand, Xoroshiro128, RNG};
+
+fn sequence<R: RNG>(rng: &mut R) -> Vec<u64> {
+	(0..16).map(|_| rng.generate()).collect()
+}
+
+#[test]
+fn from_seed_matches_new_seed() {
+	let mut seed = [0u8; 8];
+	seed[0] = 42;
+	let bytes = if cfg!(feature = "native-endian") {
+		42u64.to_ne_bytes()
+	} else {
+		seed
+	};
+	assert_eq!(
+		sequence(&mut WyRand::from_seed(bytes)),
+		sequence(&mut WyRand::new_seed(42))
+	);
+
+	let bytes = if cfg!(feature = "native-endian") {
+		42u128.to_ne_bytes()
+	} else {
+		42u128.to_le_bytes()
+	};
+	assert_eq!(
+		sequence(&mut Pcg64::from_seed(bytes)),
+		sequence(&mut Pcg64::new_seed(42))
+	);
+}
+
+#[test]
+fn reseed_restarts_the_sequence() {
+	fn check<R: SeedableRNG>(seed: R::Seed, other: R::Seed)
+	where
+		R::Seed: Clone,
+	{
+		let mut rng = R::from_seed(seed.clone());
+		let first = sequence(&mut rng);
+		rng.reseed(other);
+		assert_ne!(sequence(&mut rng), first);
+		rng.reseed(seed);
+		assert_eq!(sequence(&mut rng), first);
+	}
+
+	check::<WyRand>([1; 8], [2; 8]);
+	check::<Pcg64>([1; 16], [2; 16]);
+	check::<Xoroshiro128>([1; 16], [2; 16]);
+}
+
+#[test]
+fn xoroshiro_zero_seed_isnt_stuck() {
+	let mut rng = Xoroshiro128::from_seed([0; 16]);
+	assert_eq!(sequence(&mut rng), sequence(&mut Xoroshiro128::new_seed(0)));
+	let mut rng = Xoroshiro128::from_seed([0; 16]);
+	assert!(sequence(&mut rng).iter().any(|&n| n != 0));
+}
+
+#[test]
+#[cfg(feature = "chacha")]
+fn chacha_from_32_byte_seed() {
+	use nanorand::ChaCha;
+
+	let mut rng = ChaCha::from_seed([7; 32]);
+	let mut expected = ChaCha::new_key(20, [7; 32], [0; 16]);
+	assert_eq!(rng.rand(), expected.rand());
+
+	// Reseeding keeps the number of rounds.
+	let mut rng = ChaCha::new_key(8, [1; 32], [0; 16]);
+	rng.reseed([7; 32]);
+	assert_eq!(rng.rand(), ChaCha::new_key(8, [7; 32], [0; 16]).rand());
+}
+
+#[test]
+#[cfg(not(feature = "native-endian"))]
+fn seed_from_str_is_pinned() {
+	// FNV-1a of the string, expanded with SplitMix64.
+	assert_eq!(
+		WyRand::new_seed_str("my reproducible run").state(),
+		0x468fb1ef5af3c11a_u64.to_le_bytes()
+	);
+	assert_eq!(
+		WyRand::from_seed_str("").state(),
+		0xc3817c016ba4ff30_u64.to_le_bytes()
+	);
+	let mut expected = [0u8; 16];
+	expected[..8].copy_from_slice(&0x5f29c2aadd9b8527_u64.to_le_bytes());
+	expected[8..].copy_from_slice(&0xff84f1bdb6d3884f_u64.to_le_bytes());
+	assert_eq!(Xoroshiro128::from_seed_str("a").state(), expected);
+}
+
+#[test]
+fn seed_from_str() {
+	let mut a = Pcg64::from_seed_str("run 1");
+	let mut b = Pcg64::from_seed_str("run 1");
+	let mut c = Pcg64::from_seed_str("run 2");
+	let mut empty = Pcg64::from_seed_str("");
+	for _ in 0..100 {
+		let x = a.generate::<u64>();
+		assert_eq!(x, b.generate::<u64>());
+		assert_ne!(x, c.generate::<u64>());
+		assert_ne!(x, empty.generate::<u64>());
+	}
+	#[cfg(feature = "chacha")]
+	assert_ne!(
+		nanorand::ChaCha::from_seed_str("run 1").generate::<u64>(),
+		nanorand::ChaCha::from_seed_str("run 2").generate::<u64>()
+	);
+}
+
+#[test]
+fn seed_from_u64_is_deterministic() {
+	fn check<R: SeedableRNG>() {
+		for &seed in [0, 1, 42, u64::MAX].iter() {
+			assert_eq!(
+				sequence(&mut R::seed_from_u64(seed)),
+				sequence(&mut R::seed_from_u64(seed))
+			);
+		}
+		assert_ne!(
+			sequence(&mut R::seed_from_u64(1)),
+			sequence(&mut R::seed_from_u64(2))
+		);
+	}
+
+	check::<WyRand>();
+	check::<Pcg64>();
+	check::<Xoroshiro128>();
+	#[cfg(feature = "chacha")]
+	check::<nanorand::ChaCha>();
+}
+
+#[test]
+fn seed_from_u64_is_not_degenerate() {
+	for &seed in [0, 1, u64::MAX].iter() {
+		assert_ne!(WyRand::seed_from_u64(seed).state(), [0; 8]);
+		assert_ne!(Pcg64::seed_from_u64(seed).state(), [0; 16]);
+		assert_ne!(Xoroshiro128::seed_from_u64(seed).state(), [0; 16]);
+	}
+	#[cfg(feature = "chacha")]
+	assert_ne!(
+		nanorand::ChaCha::seed_from_u64(0).generate::<u64>(),
+		nanorand::ChaCha::from_seed([0; 32]).generate::<u64>()
+	);
+}
+
+#[test]
+#[cfg(not(feature = "native-endian"))]
+fn seed_from_u64_is_pinned() {
+	// The first outputs of SplitMix64, started from 0.
+	assert_eq!(
+		WyRand::seed_from_u64(0).state(),
+		0xe220a8397b1dcdaf_u64.to_le_bytes()
+	);
+	let mut expected = [0u8; 16];
+	expected[..8].copy_from_slice(&0xe220a8397b1dcdaf_u64.to_le_bytes());
+	expected[8..].copy_from_slice(&0x6e789e6aa1b965f4_u64.to_le_bytes());
+	assert_eq!(Pcg64::seed_from_u64(0).state(), expected);
+	assert_eq!(Xoroshiro128::seed_from_u64(0).state(), expected);
+	// Xoroshiro128's own constructor expands its seed the same way.
+	assert_eq!(Xoroshiro128::seed_from_u64(42), Xoroshiro128::new_seed(42));
+	assert_eq!(
+		WyRand::from_seed_str("a"),
+		WyRand::seed_from_u64(0xaf63dc4c8601ec8c)
+	);
+}
+
+#[test]
+fn all_zero_seeds_are_not_dead() {
+	fn check<R: SeedableRNG>() {
+		let is_alive = |rng: &mut R| sequence(rng).iter().any(|&x| x != 0);
+		assert!(is_alive(&mut R::from_seed(R::Seed::default())));
+		let mut rng = R::seed_from_u64(1);
+		rng.reseed(R::Seed::default());
+		assert!(is_alive(&mut rng));
+	}
+
+	check::<WyRand>();
+	check::<Pcg64>();
+	check::<Xoroshiro128>();
+	#[cfg(feature = "chacha")]
+	check::<nanorand::ChaCha>();
+}
+
+#[test]
+fn mix_entropy_keeps_existing_state() {
+	let mut a = WyRand::new_seed(1);
+	let mut b = WyRand::new_seed(2);
+	a.mix_entropy([7; 8]);
+	b.mix_entropy([7; 8]);
+	// The same entropy mixed into different states gives different states.
+	assert_ne!(sequence(&mut a), sequence(&mut b));
+
+	let mut mixed = Xoroshiro128::new_seed(42);
+	let mut again = Xoroshiro128::new_seed(42);
+	mixed.mix_entropy([9; 16]);
+	again.mix_entropy([9; 16]);
+	assert_eq!(sequence(&mut mixed), sequence(&mut again));
+}
+
+#[test]
+fn reseed_from_system_changes_stream() {
+	let mut rng = Pcg64::new_seed(42);
+	let mut untouched = Pcg64::new_seed(42);
+	rng.reseed_from_system();
+	assert_ne!(sequence(&mut rng), sequence(&mut untouched));
+
+	let mut rng = WyRand::new_seed(42);
+	let mut untouched = WyRand::new_seed(42);
+	rng.reseed_from_system();
+	assert_ne!(sequence(&mut rng), sequence(&mut untouched));
+}
+
+#[test]
+#[should_panic]
+fn mix_zero_entropy() {
+	WyRand::new_seed(42).mix_entropy([0; 8]);
+}
+
+#[test]
+fn from_seed_slice_uses_every_byte() {
+	// Seeds differing only past the generator's seed length still give different streams.
+	let mut seed = [1u8; 16];
+	let first = sequence(&mut WyRand::from_seed_slice(&seed));
+	seed[9] = 2;
+	assert_ne!(sequence(&mut WyRand::from_seed_slice(&seed)), first);
+
+	let key: Vec<u8> = (0..40).collect();
+	let base = sequence(&mut Xoroshiro128::from_seed_slice(&key));
+	for idx in 0..key.len() {
+		let mut changed = key.clone();
+		changed[idx] ^= 1;
+		assert_ne!(sequence(&mut Xoroshiro128::from_seed_slice(&changed)), base);
+	}
+
+	// The order of the chunks matters, too.
+	let mut swapped = key.clone();
+	swapped.rotate_left(16);
+	assert_ne!(sequence(&mut Xoroshiro128::from_seed_slice(&swapped)), base);
+}
+
+#[test]
+fn from_seed_slice_fits_short_seeds() {
+	// A seed of the right length is used as-is.
+	assert_eq!(
+		sequence(&mut Pcg64::from_seed_slice(&[3; 16])),
+		sequence(&mut Pcg64::from_seed([3; 16]))
+	);
+	// A shorter seed is expanded, rather than zero-padded.
+	assert_ne!(
+		sequence(&mut Pcg64::from_seed_slice(&[3; 8])),
+		sequence(&mut Pcg64::from_seed([
+			3, 3, 3, 3, 3, 3, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0
+		]))
+	);
+	assert_ne!(
+		sequence(&mut Pcg64::from_seed_slice(&[0; 8])),
+		sequence(&mut Pcg64::from_seed_slice(&[0; 9]))
+	);
+	assert_eq!(
+		sequence(&mut Xoroshiro128::from_seed_slice(&[])),
+		sequence(&mut Xoroshiro128::from_seed_slice(&[]))
+	);
+	#[cfg(feature = "chacha")]
+	assert_ne!(
+		nanorand::ChaCha::from_seed_slice(&[1; 16]).generate::<u64>(),
+		nanorand::ChaCha::from_seed_slice(&[1; 17]).generate::<u64>()
+	);
+}
+
+#[test]
+fn reseed_from_slice_restarts_the_sequence() {
+	let key = [9u8; 24];
+	let mut rng = WyRand::new_seed(1);
+	rng.reseed_from_slice(&key);
+	assert_eq!(
+		sequence(&mut rng),
+		sequence(&mut WyRand::from_seed_slice(&key))
+	);
+}
+
+#[test]
+#[allow(deprecated)]
+fn rand_with_seed_uses_every_byte() {
+	let mut seed = [1u8; 16];
+	let first = WyRand::rand_with_seed(&seed);
+	assert_eq!(first, WyRand::from_seed_slice(&seed).rand());
+	seed[9] = 2;
+	assert_ne!(WyRand::rand_with_seed(&seed), first);
+	assert_eq!(
+		WyRand::rand_with_seed(&[5; 8]),
+		WyRand::from_seed([5; 8]).rand()
+	);
+	assert_ne!(
+		Xoroshiro128::rand_with_seed(&seed),
+		Xoroshiro128::rand_with_seed(&seed[..15])
+	);
+}
+
+#[test]
+#[allow(deprecated)]
+#[should_panic(expected = "Only WyRand and Xoroshiro128 can generate from a seed alone!")]
+fn rand_with_seed_panics_for_other_generators() {
+	Pcg64::rand_with_seed(&[1; 16]);
+}
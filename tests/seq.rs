@@ -0,0 +1,252 @@
+use nanorand::{seq::*, WyRand};
+
+#[test]
+fn permutation_contains_each_index_once() {
+	let mut rng = WyRand::new_seed(42);
+	for n in [0, 1, 2, 7, 100, 1000].iter().copied() {
+		let mut perm = permutation(&mut rng, n);
+		assert_eq!(perm.len(), n);
+		perm.sort_unstable();
+		assert!(perm.iter().copied().eq(0..n));
+	}
+}
+
+#[test]
+fn permutation_depends_on_seed() {
+	let first = permutation(&mut WyRand::new_seed(1), 50);
+	let second = permutation(&mut WyRand::new_seed(2), 50);
+	assert_ne!(first, second);
+	assert_eq!(first, permutation(&mut WyRand::new_seed(1), 50));
+}
+
+#[test]
+fn permutation_is_uniform() {
+	// All 6 orderings of 0..3 should come up about equally often.
+	let mut rng = WyRand::new_seed(42);
+	let mut counts = std::collections::HashMap::new();
+	for _ in 0..60000 {
+		*counts.entry(permutation(&mut rng, 3)).or_insert(0) += 1;
+	}
+	assert_eq!(counts.len(), 6);
+	for (perm, count) in counts {
+		assert!(
+			(9000..11000).contains(&count),
+			"{:?} came up {} times",
+			perm,
+			count
+		);
+	}
+}
+
+#[test]
+fn lazy_permutation_yields_each_index_once() {
+	let mut rng = WyRand::new_seed(42);
+	for n in [0, 1, 2, 3, 4, 5, 17, 64, 65, 1000, 4097, 100_000] {
+		let order = Permutation::new(&mut rng, n);
+		assert_eq!(order.len(), n);
+		let mut seen = vec![false; n];
+		let mut count = 0;
+		for idx in order {
+			assert!(!seen[idx], "{} came up twice for n = {}", idx, n);
+			seen[idx] = true;
+			count += 1;
+		}
+		assert_eq!(count, n);
+	}
+}
+
+#[test]
+fn lazy_permutation_is_exact_size() {
+	let mut order = Permutation::new(&mut WyRand::new_seed(42), 10);
+	order.next();
+	order.next();
+	assert_eq!(order.len(), 8);
+	assert_eq!(order.by_ref().count(), 8);
+	assert_eq!(order.len(), 0);
+	assert_eq!(order.next(), None);
+}
+
+#[test]
+fn lazy_permutation_depends_on_seed() {
+	let order = |seed| Permutation::new(&mut WyRand::new_seed(seed), 1000).collect::<Vec<usize>>();
+	assert_eq!(order(1), order(1));
+	assert_ne!(order(1), order(2));
+	assert_ne!(order(1), (0..1000).collect::<Vec<usize>>());
+}
+
+#[test]
+fn lazy_permutation_spreads_indices() {
+	// Where index 0 lands, over many seeds, should be roughly uniform.
+	let mut counts = [0u32; 10];
+	for seed in 0..20000 {
+		let pos = Permutation::new(&mut WyRand::new_seed(seed), 10)
+			.position(|idx| idx == 0)
+			.unwrap();
+		counts[pos] += 1;
+	}
+	assert!(
+		counts.iter().all(|&c| c.abs_diff(2000) < 200),
+		"{:?}",
+		counts
+	);
+}
+
+#[test]
+fn shuffled_leaves_the_original() {
+	let mut rng = WyRand::new_seed(42);
+	let original: Vec<String> = (0..20).map(|n| n.to_string()).collect();
+	let before = original.clone();
+	let copy = shuffled(&mut rng, &original);
+	assert_eq!(original, before);
+	assert_ne!(copy, original);
+	let mut sorted = copy.clone();
+	sorted.sort_unstable();
+	let mut expected = original.clone();
+	expected.sort_unstable();
+	assert_eq!(sorted, expected);
+	let empty: [u8; 0] = [];
+	assert!(shuffled(&mut rng, &empty).is_empty());
+}
+
+#[test]
+fn reservoir_sample_short_iterators() {
+	let mut rng = WyRand::new_seed(42);
+	assert!(reservoir_sample(&mut rng, 0..10, 0).is_empty());
+	assert_eq!(reservoir_sample(&mut rng, 0..3, 5), [0, 1, 2]);
+	assert_eq!(reservoir_sample(&mut rng, 0..5, 5), [0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn reservoir_sample_is_uniform() {
+	let mut rng = WyRand::new_seed(42);
+	let mut counts = [0u32; 20];
+	for _ in 0..20000 {
+		let mut picked = reservoir_sample(&mut rng, 0..20usize, 5);
+		assert_eq!(picked.len(), 5);
+		for item in picked.iter() {
+			counts[*item] += 1;
+		}
+		picked.sort_unstable();
+		picked.dedup();
+		assert_eq!(picked.len(), 5);
+	}
+	// Each item should be picked a quarter of the time, so about 5000 times.
+	for (item, count) in counts.iter().enumerate() {
+		assert!(
+			(4600..5400).contains(count),
+			"{} was picked {} times",
+			item,
+			count
+		);
+	}
+}
+
+#[test]
+fn choose_weighted_choices() {
+	let mut rng = WyRand::new_seed(42);
+	let empty: [(u8, u32); 0] = [];
+	assert_eq!(choose_weighted(&mut rng, &empty), None);
+	assert_eq!(choose_weighted(&mut rng, &[(1, 0), (2, 0)]), None);
+	for _ in 0..1000 {
+		assert_eq!(
+			choose_weighted(&mut rng, &[(1, 0), (2, 5), (3, 0)]),
+			Some(&2)
+		);
+	}
+	let heavy = [(0u8, u32::MAX), (1, u32::MAX)];
+	let ones = (0..1000)
+		.filter(|_| choose_weighted(&mut rng, &heavy) == Some(&1))
+		.count();
+	assert!((400..600).contains(&ones), "{} ones", ones);
+}
+
+#[test]
+fn fill_weighted_proportions() {
+	let mut rng = WyRand::new_seed(42);
+	let mut data = vec!['?'; 100000];
+	fill_weighted(&mut rng, &mut data, &[('A', 70), ('B', 30), ('C', 0)]);
+	let a = data.iter().filter(|&&c| c == 'A').count();
+	let b = data.iter().filter(|&&c| c == 'B').count();
+	assert_eq!(a + b, data.len());
+	assert!((69000..71000).contains(&a), "{} As", a);
+
+	let mut nothing: [char; 0] = [];
+	fill_weighted(&mut rng, &mut nothing, &[]);
+}
+
+#[test]
+#[should_panic]
+fn fill_weighted_without_weights() {
+	let mut rng = WyRand::new_seed(42);
+	fill_weighted(&mut rng, &mut [0u8; 4], &[(1, 0)]);
+}
+
+#[test]
+fn take_random_from_empty() {
+	let mut rng = WyRand::new_seed(42);
+	let mut items: Vec<u32> = Vec::new();
+	assert_eq!(take_random(&mut rng, &mut items), None);
+}
+
+#[test]
+fn take_random_drains_a_permutation() {
+	let mut rng = WyRand::new_seed(42);
+	let mut items: Vec<u32> = (0..100).collect();
+	let mut drained = Vec::new();
+	while let Some(item) = take_random(&mut rng, &mut items) {
+		drained.push(item);
+	}
+	assert!(items.is_empty());
+	assert_ne!(drained, (0..100).collect::<Vec<u32>>());
+	drained.sort_unstable();
+	assert_eq!(drained, (0..100).collect::<Vec<u32>>());
+}
+
+#[test]
+fn take_random_is_uniform() {
+	let mut rng = WyRand::new_seed(42);
+	let mut counts = [0u32; 4];
+	for _ in 0..40000 {
+		let mut items = vec![0, 1, 2, 3];
+		counts[take_random(&mut rng, &mut items).unwrap()] += 1;
+	}
+	for &count in counts.iter() {
+		assert!((9000..11000).contains(&count), "{:?}", counts);
+	}
+}
+
+#[test]
+fn charset_ascii() {
+	let mut rng = WyRand::new_seed(42);
+	let charset = "abcdef0123456789";
+	for len in [0, 1, 16, 1000].iter().copied() {
+		let s = random_from_charset(&mut rng, charset, len);
+		assert_eq!(s.len(), len);
+		assert!(s.chars().all(|c| charset.contains(c)));
+	}
+	// With enough characters, every one of the charset comes up.
+	let s = random_from_charset(&mut rng, charset, 1000);
+	assert!(charset.chars().all(|c| s.contains(c)));
+}
+
+#[test]
+fn charset_unicode() {
+	let mut rng = WyRand::new_seed(42);
+	let charset = "aé日本🦀";
+	let s = random_from_charset(&mut rng, charset, 500);
+	assert_eq!(s.chars().count(), 500);
+	assert!(s.chars().all(|c| charset.contains(c)));
+	assert!(charset.chars().all(|c| s.contains(c)));
+}
+
+#[test]
+fn charset_empty() {
+	let mut rng = WyRand::new_seed(42);
+	assert_eq!(random_from_charset(&mut rng, "", 0), "");
+}
+
+#[test]
+#[should_panic]
+fn charset_empty_panics() {
+	random_from_charset(&mut WyRand::new_seed(42), "", 1);
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "serde")]
+
+use nanorand::{Pcg64, WyRand, Xoroshiro128, RNG};
+use serde::{de::DeserializeOwned, Serialize};
+
+fn assert_resumes<R: RNG + Serialize + DeserializeOwned>(mut rng: R) {
+	for _ in 0..100 {
+		rng.generate::<u64>();
+	}
+	let checkpoint = serde_json::to_string(&rng).unwrap();
+	let mut resumed: R = serde_json::from_str(&checkpoint).unwrap();
+	for _ in 0..1000 {
+		assert_eq!(resumed.generate::<u64>(), rng.generate::<u64>());
+	}
+}
+
+#[test]
+fn round_trip_mid_stream() {
+	assert_resumes(WyRand::new_seed(42));
+	assert_resumes(Pcg64::new_seed(42));
+	assert_resumes(Xoroshiro128::new_seed(42));
+}
+
+#[test]
+fn serializes_state_not_seed() {
+	let mut rng = WyRand::new_seed(42);
+	let before = serde_json::to_string(&rng).unwrap();
+	rng.generate::<u64>();
+	assert_ne!(serde_json::to_string(&rng).unwrap(), before);
+}
+
+#[test]
+fn rejects_zero_xoroshiro_state() {
+	let err = serde_json::from_str::<Xoroshiro128>(r#"{"state":[0,0]}"#).unwrap_err();
+	assert!(err.to_string().contains("all zeroes"), "{}", err);
+	assert!(serde_json::from_str::<Xoroshiro128>(r#"{"state":[0,1]}"#).is_ok());
+}
@@ -0,0 +1,110 @@
+use nanorand::{WyRand, RNG};
+
+#[test]
+fn shuffle_is_a_permutation() {
+	let mut rng = WyRand::new_seed(42);
+	let mut deck: Vec<u32> = (0..52).collect();
+	rng.shuffle(&mut deck);
+	assert_ne!(deck, (0..52).collect::<Vec<u32>>());
+	deck.sort_unstable();
+	assert_eq!(deck, (0..52).collect::<Vec<u32>>());
+}
+
+#[test]
+fn shuffle_short_slices() {
+	let mut rng = WyRand::new_seed(42);
+	let mut empty: [u8; 0] = [];
+	rng.shuffle(&mut empty);
+	let mut single = [7];
+	rng.shuffle(&mut single);
+	assert_eq!(single, [7]);
+	// Neither of those needed any randomness.
+	assert_eq!(rng, WyRand::new_seed(42));
+}
+
+#[test]
+fn shuffle_is_deterministic() {
+	let shuffled = |seed| {
+		let mut deck: Vec<u32> = (0..52).collect();
+		WyRand::new_seed(seed).shuffle(&mut deck);
+		deck
+	};
+	assert_eq!(shuffled(1), shuffled(1));
+	assert_ne!(shuffled(1), shuffled(2));
+}
+
+#[test]
+fn shuffle_is_uniform() {
+	// Where the first card ends up, over many seeded runs, should be uniform across all 52 positions.
+	const RUNS: u64 = 52000;
+	let mut counts = [0u32; 52];
+	for seed in 0..RUNS {
+		let mut deck: Vec<u32> = (0..52).collect();
+		WyRand::new_seed(seed).shuffle(&mut deck);
+		counts[deck.iter().position(|&card| card == 0).unwrap()] += 1;
+	}
+	let expected = RUNS as f64 / 52.0;
+	let chi_square: f64 = counts
+		.iter()
+		.map(|&count| (count as f64 - expected).powi(2) / expected)
+		.sum();
+	// The critical value for 51 degrees of freedom, at p = 0.001.
+	assert!(
+		chi_square < 87.97,
+		"chi-square {} for {:?}",
+		chi_square,
+		counts
+	);
+}
+
+#[test]
+fn partial_shuffle_splits() {
+	let mut rng = WyRand::new_seed(42);
+	let mut deck: Vec<u32> = (0..52).collect();
+	let (hand, rest) = rng.partial_shuffle(&mut deck, 5);
+	assert_eq!((hand.len(), rest.len()), (5, 47));
+	deck.sort_unstable();
+	assert_eq!(deck, (0..52).collect::<Vec<u32>>());
+
+	let (all, rest) = rng.partial_shuffle(&mut deck, 100);
+	assert_eq!((all.len(), rest.len()), (52, 0));
+	deck.sort_unstable();
+	assert_eq!(deck, (0..52).collect::<Vec<u32>>());
+}
+
+#[test]
+fn partial_shuffle_short_slices() {
+	let mut rng = WyRand::new_seed(42);
+	let mut deck = [1, 2, 3];
+	let (hand, rest) = rng.partial_shuffle(&mut deck, 0);
+	assert!(hand.is_empty());
+	assert_eq!(rest, [1, 2, 3]);
+	let mut empty: [u8; 0] = [];
+	let (hand, rest) = rng.partial_shuffle(&mut empty, 0);
+	assert!(hand.is_empty() && rest.is_empty());
+	let (hand, rest) = rng.partial_shuffle(&mut empty, 5);
+	assert!(hand.is_empty() && rest.is_empty());
+	// None of those needed any randomness.
+	assert_eq!(rng, WyRand::new_seed(42));
+}
+
+#[test]
+fn partial_shuffle_is_an_ordered_sample() {
+	// Each of the 5 * 4 ordered pairs of 2 out of 5 should come up equally often.
+	let mut rng = WyRand::new_seed(42);
+	let mut counts = [[0u32; 5]; 5];
+	for _ in 0..100000 {
+		let mut items = [0, 1, 2, 3, 4];
+		let (picked, _) = rng.partial_shuffle(&mut items, 2);
+		counts[picked[0]][picked[1]] += 1;
+	}
+	for (first, row) in counts.iter().enumerate() {
+		for (second, &count) in row.iter().enumerate() {
+			if first == second {
+				assert_eq!(count, 0);
+			} else {
+				assert!(count.abs_diff(5000) < 300, "{:?}", counts);
+			}
+		}
+	}
+}
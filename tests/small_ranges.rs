@@ -0,0 +1,77 @@
+use nanorand::{RandomRange, WyRand, RNG};
+
+#[derive(Clone)]
+struct CountingRng<R: RNG> {
+	inner: R,
+	calls: usize,
+}
+
+impl<R: RNG> RNG for CountingRng<R> {
+	type Output = R::Output;
+	const OUTPUT_SIZE: usize = R::OUTPUT_SIZE;
+
+	fn rand(&mut self) -> Self::Output {
+		self.calls += 1;
+		self.inner.rand()
+	}
+}
+
+#[derive(Clone)]
+struct Fixed([u8; 8]);
+
+impl RNG for Fixed {
+	type Output = [u8; 8];
+	const OUTPUT_SIZE: usize = 8;
+
+	fn rand(&mut self) -> Self::Output {
+		self.0
+	}
+}
+
+#[test]
+fn rejected_candidates_reuse_the_output() {
+	// For 0..3, Lemire's method rejects exactly one u8: zero.
+	let mut rng = CountingRng {
+		inner: Fixed([0, 0, 0, 0, 0, 0, 0, 0x80]),
+		calls: 0,
+	};
+	assert_eq!(u8::random_range(&mut rng, 0, 3), 1);
+	assert_eq!(rng.calls, 1);
+
+	// About 22% of u8s are rejected for 0..200, and 39% of u16s for 0..40000,
+	// which used to mean pulling in a whole new output each time (around 29000 calls here).
+	let mut rng = CountingRng {
+		inner: WyRand::new_seed(42),
+		calls: 0,
+	};
+	for _ in 0..10000 {
+		assert!(u8::random_range(&mut rng, 0, 200) < 200);
+		assert!(u16::random_range(&mut rng, 0, 40000) < 40000);
+	}
+	assert!(rng.calls < 20500, "{} calls", rng.calls);
+}
+
+#[test]
+fn power_of_two_ranges() {
+	let mut rng = CountingRng {
+		inner: WyRand::new_seed(42),
+		calls: 0,
+	};
+	let mut counts = [0u32; 4];
+	for _ in 0..40000 {
+		counts[rng.generate_range(0_u8..4) as usize] += 1;
+		assert_eq!(rng.generate_range(9_u32..10), 9);
+		assert!(rng.generate_range(16_u64..32) >= 16);
+	}
+	assert_eq!(rng.calls, 120000);
+	assert!(
+		counts.iter().all(|&c| (9500..10500).contains(&c)),
+		"{:?}",
+		counts
+	);
+
+	// The top bits are used, just like the widening multiply would.
+	let mut rng = Fixed([0, 0, 0, 0, 0, 0, 0, 0xc0]);
+	assert_eq!(u64::random_range(&mut rng, 0, 4), 3);
+	assert_eq!(u8::random_range(&mut rng, 0, 4), 0);
+}
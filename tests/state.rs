@@ -0,0 +1,51 @@
+use nanorand::{Pcg64, WyRand, Xoroshiro128, RNG};
+
+macro_rules! assert_resumes {
+	($rng:expr, $ty:ty) => {{
+		let mut rng = $rng;
+		for _ in 0..100 {
+			rng.generate::<u64>();
+		}
+		let state = rng.state();
+		let mut resumed = <$ty>::from_state(state);
+		assert_eq!(resumed.state(), state);
+		for _ in 0..1000 {
+			assert_eq!(resumed.generate::<u64>(), rng.generate::<u64>());
+		}
+	}};
+}
+
+#[test]
+fn resume_mid_stream() {
+	assert_resumes!(WyRand::new_seed(42), WyRand);
+	assert_resumes!(Pcg64::new_seed(42), Pcg64);
+	assert_resumes!(Xoroshiro128::new_seed(42), Xoroshiro128);
+}
+
+#[test]
+fn state_changes_as_rng_advances() {
+	let mut rng = Xoroshiro128::new_seed(42);
+	let before = rng.state();
+	rng.generate::<u64>();
+	assert_ne!(rng.state(), before);
+}
+
+#[test]
+#[cfg(not(feature = "native-endian"))]
+fn state_layout() {
+	assert_eq!(
+		WyRand::new_seed(0x0102030405060708).state(),
+		[8, 7, 6, 5, 4, 3, 2, 1]
+	);
+	let xoroshiro = Xoroshiro128::from_state([1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+	assert_eq!(
+		xoroshiro.state(),
+		[1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]
+	);
+}
+
+#[test]
+#[should_panic]
+fn zero_xoroshiro_state() {
+	Xoroshiro128::from_state([0; 16]);
+}
@@ -0,0 +1,160 @@
+use nanorand::{
+	adapter::{BufferedRng, CombinedRng, CountingRng, ReseedingRng},
+	DynRng, RandError, SeedableRNG, WyRand, RNG,
+};
+
+/// A generator that fails on every third call, like a hardware generator running dry.
+struct Flaky {
+	rng: WyRand,
+	calls: u32,
+}
+
+impl Flaky {
+	fn new() -> Self {
+		Self {
+			rng: WyRand::new_seed(42),
+			calls: 0,
+		}
+	}
+}
+
+impl RNG for Flaky {
+	type Output = [u8; 8];
+	const OUTPUT_SIZE: usize = 8;
+
+	fn rand(&mut self) -> Self::Output {
+		self.try_rand().expect("Flaky failed")
+	}
+
+	fn try_rand(&mut self) -> Result<Self::Output, RandError> {
+		self.calls += 1;
+		if self.calls % 3 == 0 {
+			Err(RandError::new("Flaky"))
+		} else {
+			Ok(self.rng.rand())
+		}
+	}
+
+	fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RandError> {
+		for chunk in dest.chunks_mut(Self::OUTPUT_SIZE) {
+			let bytes = self.try_rand()?;
+			chunk.copy_from_slice(&bytes[..chunk.len()]);
+		}
+		Ok(())
+	}
+}
+
+impl SeedableRNG for Flaky {
+	type Seed = [u8; 8];
+
+	fn from_seed(seed: Self::Seed) -> Self {
+		Self {
+			rng: WyRand::from_seed(seed),
+			calls: 0,
+		}
+	}
+}
+
+#[test]
+fn errors_propagate() {
+	let mut rng = Flaky::new();
+	let mut expected = WyRand::new_seed(42);
+	assert_eq!(rng.try_generate::<u64>(), Ok(expected.generate::<u64>()));
+	assert_eq!(rng.try_generate::<u32>(), Ok(expected.generate::<u32>()));
+	let err = rng.try_generate::<u64>().unwrap_err();
+	assert_eq!(err, RandError::new("Flaky"));
+	assert_eq!(err.generator(), "Flaky");
+	assert_eq!(err.to_string(), "Flaky failed to generate random bytes");
+
+	assert!(rng.try_generate::<u128>().is_ok());
+	assert!(rng.try_generate::<bool>().is_err());
+	assert!(rng.try_generate::<f64>().is_ok());
+	// A u128 takes two outputs, and the second of these fails.
+	assert!(rng.try_generate::<u128>().is_err());
+}
+
+#[test]
+fn try_generate_matches_generate() {
+	fn check<T>()
+	where
+		T: nanorand::RandomGen<WyRand>
+			+ nanorand::TryRandomGen<WyRand>
+			+ PartialEq
+			+ core::fmt::Debug,
+	{
+		let mut a = WyRand::new_seed(7);
+		let mut b = WyRand::new_seed(7);
+		for _ in 0..100 {
+			assert_eq!(a.try_generate::<T>().unwrap(), b.generate::<T>());
+		}
+	}
+
+	check::<u8>();
+	check::<i16>();
+	check::<u32>();
+	check::<i64>();
+	check::<u128>();
+	check::<usize>();
+	check::<bool>();
+	check::<char>();
+	check::<f32>();
+	check::<f64>();
+}
+
+#[test]
+fn default_try_rand_never_fails() {
+	let mut a = WyRand::new_seed(7);
+	let mut b = WyRand::new_seed(7);
+	assert_eq!(a.try_rand(), Ok(b.rand()));
+	let mut bytes = [0u8; 20];
+	a.try_fill_bytes(&mut bytes).unwrap();
+	assert_eq!(bytes, b.generate_bytes::<20>());
+	// Through a reference, too.
+	fn lent(mut rng: impl RNG) -> Result<u64, RandError> {
+		rng.try_generate()
+	}
+	assert_eq!(lent(&mut a), Ok(b.generate::<u64>()));
+}
+
+/// Check that `rng`, wrapping a [`Flaky`], returns its errors from the fallible methods, rather than panicking.
+fn check_wrapper_errors<R: RNG>(mut rng: R) {
+	let results: Vec<bool> = (0..6).map(|_| rng.try_rand().is_ok()).collect();
+	assert!(results.contains(&false), "{:?}", results);
+	let mut bytes = [0u8; 64];
+	assert_eq!(rng.try_fill_bytes(&mut bytes), Err(RandError::new("Flaky")));
+	let results: Vec<bool> = (0..6).map(|_| rng.try_generate::<u64>().is_ok()).collect();
+	assert!(results.contains(&false), "{:?}", results);
+	assert!(results.contains(&true), "{:?}", results);
+}
+
+#[test]
+fn adapters_forward_errors() {
+	check_wrapper_errors(BufferedRng::new(Flaky::new()));
+	check_wrapper_errors(CountingRng::new(Flaky::new()));
+	check_wrapper_errors(CombinedRng::new(Flaky::new(), WyRand::new_seed(1)));
+	check_wrapper_errors(CombinedRng::new(WyRand::new_seed(1), Flaky::new()));
+	check_wrapper_errors(ReseedingRng::new(Flaky::new(), usize::MAX));
+	check_wrapper_errors(&mut Flaky::new());
+
+	let mut flaky = Flaky::new();
+	check_wrapper_errors(&mut flaky as &mut dyn DynRng);
+	check_wrapper_errors(Box::new(Flaky::new()) as Box<dyn DynRng>);
+}
+
+#[test]
+fn adapters_match_their_infallible_methods() {
+	let mut a = BufferedRng::new(WyRand::new_seed(7));
+	let mut b = BufferedRng::new(WyRand::new_seed(7));
+	for _ in 0..10 {
+		assert_eq!(a.try_generate::<u16>(), Ok(b.generate::<u16>()));
+	}
+	let mut a = CountingRng::new(WyRand::new_seed(7));
+	let mut b = CountingRng::new(WyRand::new_seed(7));
+	let mut bytes = [0u8; 20];
+	a.try_fill_bytes(&mut bytes).unwrap();
+	assert_eq!(bytes, b.generate_bytes::<20>());
+	assert_eq!(a.calls(), b.calls());
+	let mut a = CombinedRng::new(WyRand::new_seed(7), WyRand::new_seed(8));
+	let mut b = CombinedRng::new(WyRand::new_seed(7), WyRand::new_seed(8));
+	assert_eq!(a.try_rand(), Ok(b.rand()));
+}
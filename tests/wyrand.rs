@@ -1,4 +1,4 @@
-use nanorand::{WyRand, RNG};
+use nanorand::{hash_u64, WyRand, RNG};
 
 #[test]
 fn wyrand_test() {
@@ -7,3 +7,150 @@ fn wyrand_test() {
 
 	assert_ne!(rng.rand().to_vec(), vec![0; std::mem::size_of::<u64>()])
 }
+
+#[test]
+#[cfg(not(feature = "debug-state"))]
+fn wyrand_debug_is_opaque() {
+	let rng = WyRand::new_seed(1234567890);
+	let debug = format!("{:?}", rng);
+	assert_eq!(debug, "WyRand { .. }");
+	assert!(!debug.contains("1234567890"));
+}
+
+#[test]
+#[cfg(feature = "debug-state")]
+fn wyrand_debug_state() {
+	let rng = WyRand::new_seed(1234567890);
+	assert_eq!(format!("{:?}", rng), "WyRand { seed: 1234567890 }");
+}
+
+#[test]
+fn hash_u64_matches_first_output() {
+	for key in [0, 1, 42, u64::MAX].iter().copied() {
+		assert_eq!(hash_u64(key), WyRand::new_seed(key).generate::<u64>());
+		assert_eq!(hash_u64(key), hash_u64(key));
+	}
+	assert_ne!(hash_u64(0), 0);
+}
+
+#[test]
+fn hash_u64_avalanche() {
+	// Flipping any single input bit should flip about half of the output bits.
+	let mut flipped = 0;
+	let mut trials = 0;
+	for key in 0..1000_u64 {
+		let key = key.wrapping_mul(0x9e3779b97f4a7c15);
+		for bit in 0..64 {
+			flipped += (hash_u64(key) ^ hash_u64(key ^ 1 << bit)).count_ones() as u64;
+			trials += 1;
+		}
+	}
+	let average = flipped as f64 / trials as f64;
+	assert!(
+		(31.0..33.0).contains(&average),
+		"{} bits flipped on average",
+		average
+	);
+
+	// The same goes for neighbouring keys, such as adjacent coordinates.
+	let flipped: u64 = (0..10000_u64)
+		.map(|key| (hash_u64(key) ^ hash_u64(key + 1)).count_ones() as u64)
+		.sum();
+	let average = flipped as f64 / 10000.0;
+	assert!(
+		(31.0..33.0).contains(&average),
+		"{} bits flipped on average",
+		average
+	);
+}
+
+#[test]
+fn derive_is_reproducible() {
+	let parent = WyRand::new_seed(42);
+	let mut child = parent.derive(b"player 1");
+	let mut again = parent.derive(b"player 1");
+	for _ in 0..100 {
+		assert_eq!(child.generate::<u64>(), again.generate::<u64>());
+	}
+	// The parent isn't advanced.
+	assert_eq!(parent, WyRand::new_seed(42));
+}
+
+#[test]
+fn derive_labels_diverge() {
+	let parent = WyRand::new_seed(42);
+	let labels: [&[u8]; 6] = [b"", b"a", b"a\0", b"b", b"player 1", b"player 2"];
+	let children: Vec<u64> = labels
+		.iter()
+		.map(|label| parent.derive(label).generate())
+		.collect();
+	for (i, a) in children.iter().enumerate() {
+		for b in &children[i + 1..] {
+			assert_ne!(a, b);
+		}
+	}
+	assert_ne!(
+		WyRand::new_seed(1).derive(b"a").generate::<u64>(),
+		WyRand::new_seed(2).derive(b"a").generate::<u64>()
+	);
+	assert_ne!(
+		parent.derive(b"a").generate::<u64>(),
+		parent.clone().generate::<u64>()
+	);
+}
+
+#[test]
+fn derive_never_collapses() {
+	// A parent whose state is wyrand's first constant, which `new_seed(0)` reaches after one output.
+	let mut parent = WyRand::new_seed(0);
+	parent.generate::<u64>();
+	assert_ne!(parent.derive(b"a"), parent.derive(b"b"));
+	assert_ne!(parent.derive(b"goblin"), parent.derive(b"troll"));
+
+	// A chunk equal to wyrand's second constant mustn't wipe out the parent.
+	let label = 0xe7037ed1a0b428db_u64.to_le_bytes();
+	assert_ne!(
+		WyRand::new_seed(1).derive(&label),
+		WyRand::new_seed(2).derive(&label)
+	);
+	assert_ne!(
+		WyRand::new_seed(1).derive(&[&label[..], b"a"].concat()),
+		WyRand::new_seed(1).derive(&[&label[..], b"b"].concat())
+	);
+}
+
+#[test]
+fn passphrase_is_reproducible() {
+	let mut rng = WyRand::from_passphrase("my-seed-2024");
+	let mut again = WyRand::from_passphrase("my-seed-2024");
+	for _ in 0..100 {
+		assert_eq!(rng.generate::<u64>(), again.generate::<u64>());
+	}
+	// Pinned, so a change to the hashing, or a dependence on the platform's endianness, shows up here.
+	assert_eq!(
+		WyRand::from_passphrase("my-seed-2024").generate::<u64>(),
+		0x6b037716767f1b3e
+	);
+	assert_eq!(
+		WyRand::from_passphrase("my-seed-2024"),
+		WyRand::new_seed_str("my-seed-2024")
+	);
+}
+
+#[test]
+fn passphrases_diverge() {
+	let phrases = [
+		"",
+		"my-seed-2024",
+		"my-seed-2025",
+		"My-seed-2024",
+		"my-seed-2024\0",
+	];
+	let firsts: Vec<u64> = phrases
+		.iter()
+		.map(|phrase| WyRand::from_passphrase(phrase).generate::<u64>())
+		.collect();
+	for (idx, first) in firsts.iter().enumerate() {
+		assert!(!firsts[idx + 1..].contains(first), "{:?}", firsts);
+	}
+}
@@ -0,0 +1,101 @@
+use nanorand::{SeedableRNG, Xoroshiro128, RNG};
+
+fn from_state(s0: u64, s1: u64) -> Xoroshiro128 {
+	let mut seed = [0u8; 16];
+	let state = (s1 as u128) << 64 | s0 as u128;
+	if cfg!(feature = "native-endian") {
+		seed.copy_from_slice(&state.to_ne_bytes());
+	} else {
+		seed.copy_from_slice(&state.to_le_bytes());
+	}
+	Xoroshiro128::from_seed(seed)
+}
+
+fn output(bytes: [u8; 8]) -> u64 {
+	if cfg!(feature = "native-endian") {
+		u64::from_ne_bytes(bytes)
+	} else {
+		u64::from_le_bytes(bytes)
+	}
+}
+
+#[test]
+fn xoroshiro_reference_output() {
+	// Outputs of the reference implementation, with its state set to `{1, 2}`.
+	let expected = [
+		0x0000000000060001,
+		0x000260c000660007,
+		0x180acc04718606d3,
+		0x9e226d35036fc4c7,
+		0x849bc9ac6b960be4,
+		0x31c5870fc130361b,
+	];
+	let mut rng = from_state(1, 2);
+	for &n in expected.iter() {
+		assert_eq!(output(rng.rand()), n);
+	}
+}
+
+#[test]
+fn xoroshiro_splitmix_seed() {
+	// The reference implementation, seeded with two SplitMix64 outputs starting from 42.
+	let expected = [
+		0xe88af6caef1d3c23,
+		0x54a303b2a5a54931,
+		0xf370812ccd646345,
+		0x345839c63f9abb35,
+	];
+	let mut rng = Xoroshiro128::new_seed(42);
+	for &n in expected.iter() {
+		assert_eq!(output(rng.rand()), n);
+	}
+}
+
+#[test]
+fn xoroshiro_jump() {
+	let expected = [
+		0x6115ff4c07d8c03e,
+		0xf4564a51c7eab4b9,
+		0xfd85cda8113be346,
+		0x16ad915520f57cdd,
+	];
+	let mut rng = from_state(1, 2);
+	rng.jump();
+	for &n in expected.iter() {
+		assert_eq!(output(rng.rand()), n);
+	}
+}
+
+#[test]
+fn xoroshiro_long_jump() {
+	let expected = [
+		0xbb077da55888837c,
+		0x3fd58ef899113160,
+		0x851ed84070f6f99c,
+		0xe38daa293a42cb2d,
+	];
+	let mut rng = from_state(1, 2);
+	rng.long_jump();
+	for &n in expected.iter() {
+		assert_eq!(output(rng.rand()), n);
+	}
+}
+
+#[test]
+fn jumps_commute_with_generating() {
+	// Jumping and then generating lands in the same place as generating and then jumping,
+	// as both just move along the same sequence.
+	let mut jumped_first = Xoroshiro128::new_seed(42);
+	let mut generated_first = Xoroshiro128::new_seed(42);
+	jumped_first.jump();
+	jumped_first.long_jump();
+	for _ in 0..10 {
+		jumped_first.rand();
+		generated_first.rand();
+	}
+	generated_first.long_jump();
+	generated_first.jump();
+	for _ in 0..100 {
+		assert_eq!(jumped_first.rand(), generated_first.rand());
+	}
+}
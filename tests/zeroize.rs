@@ -0,0 +1,37 @@
+#![cfg(all(feature = "zeroize", feature = "chacha"))]
+
+use core::mem::ManuallyDrop;
+use nanorand::ChaCha;
+use zeroize::Zeroize;
+
+fn zeroed() -> ChaCha {
+	let mut rng = ChaCha::new(20);
+	rng.zeroize();
+	rng
+}
+
+#[test]
+fn zeroize_clears_key() {
+	let mut rng = ChaCha::new_key(20, [0xAB; 32], [0xCD; 16]);
+	rng.zeroize();
+	// Keys pulled from different places look the same once zeroed.
+	assert_eq!(rng, zeroed());
+	// Nothing is left to generate a keystream from.
+	let mut data = [0x55; 128];
+	rng.apply_keystream(&mut data);
+	assert_eq!(data, [0x55; 128]);
+}
+
+#[test]
+fn drop_clears_key() {
+	let mut rng = ManuallyDrop::new(ChaCha::new_key(20, [0xAB; 32], [0xCD; 16]));
+	rng.apply_keystream(&mut [0; 10]);
+	let ptr: *mut ChaCha = &mut *rng;
+	// Safety: dropping in place leaves the memory alive, and ChaCha is just integers, so what `Drop` left behind can be read.
+	// The copy is never dropped, as it's wrapped in `ManuallyDrop` too.
+	let leftover = unsafe {
+		core::ptr::drop_in_place(ptr);
+		ManuallyDrop::new(core::ptr::read(ptr))
+	};
+	assert!(*leftover == zeroed());
+}
@@ -0,0 +1,51 @@
+#![cfg(all(feature = "zeroize", not(nanorand_test_entropy)))]
+
+use nanorand::entropy::try_checked_entropy_from_system;
+use std::{
+	alloc::{GlobalAlloc, Layout, System},
+	sync::atomic::{AtomicUsize, Ordering},
+};
+use zeroize::Zeroize;
+
+// An odd size, so nothing else in the test harness allocates blocks of it.
+const AMT: usize = 4099;
+
+static FREED: AtomicUsize = AtomicUsize::new(0);
+static FREED_DIRTY: AtomicUsize = AtomicUsize::new(0);
+
+/// Checks every freed block of `AMT` bytes has been zeroed.
+struct CheckingAlloc;
+
+unsafe impl GlobalAlloc for CheckingAlloc {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		System.alloc(layout)
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		if layout.size() == AMT {
+			FREED.fetch_add(1, Ordering::SeqCst);
+			if core::slice::from_raw_parts(ptr, AMT)
+				.iter()
+				.any(|&byte| byte != 0)
+			{
+				FREED_DIRTY.fetch_add(1, Ordering::SeqCst);
+			}
+		}
+		System.dealloc(ptr, layout)
+	}
+}
+
+#[global_allocator]
+static ALLOC: CheckingAlloc = CheckingAlloc;
+
+#[test]
+fn checked_entropy_zeroes_discarded_sample() {
+	let mut entropy = try_checked_entropy_from_system(AMT).unwrap();
+	assert_eq!(entropy.len(), AMT);
+	entropy.zeroize();
+	drop(entropy);
+
+	// The second sample only exists to compare against, and is thrown away inside.
+	assert!(FREED.load(Ordering::SeqCst) >= 2);
+	assert_eq!(FREED_DIRTY.load(Ordering::SeqCst), 0);
+}